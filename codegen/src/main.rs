@@ -50,6 +50,9 @@ fn main() {
         let firmware = match firmware {
             "Betaflight" => "Betaflight",
             "INAV" => "Inav",
+            "EmuFlight" => "EmuFlight",
+            "Rotorflight" => "Rotorflight",
+            "Cleanflight" => "Cleanflight",
             _ => panic!("invalid firmware: `{firmware}`"),
         };
 
@@ -62,13 +65,13 @@ fn main() {
         def.add_data(fw_version, data);
     }
 
-    for (name, def) in type_defs {
+    for (name, def) in &type_defs {
         let mut out_path = out_dir.clone();
         out_path.push(name);
         out_path.set_extension("rs");
         let mut out = File::create(&out_path).unwrap();
 
-        let tokens = def.expand();
+        let tokens = def.expand(&type_defs);
         let src = rustfmt(&tokens.to_string());
         out.write_all(src.as_bytes()).unwrap();
     }
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Deserialize;
+
+use super::{str_to_ident, TypeDef};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DebugFields {
+    name: String,
+    doc: String,
+    /// The name of the enum type def for the debug modes
+    mode: String,
+    /// Each debug mode, and every distinct set of fields it has along with the
+    /// firmwares that use it
+    #[serde(default)]
+    data: HashMap<String, Vec<(Fields, Vec<String>)>>,
+}
+
+/// The fields for `debug[0]`, `debug[1]`, etc, or `None` for unknown fields
+type Fields = Vec<Option<Field>>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Field {
+    name: String,
+    #[serde(default)]
+    unit: Option<FieldUnit>,
+    /// Multiplied with the raw value to get a value in `unit`
+    #[serde(default = "default_scale")]
+    scale: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum FieldUnit {
+    /// Degrees per second
+    Rotation,
+    /// Hertz
+    Frequency,
+    Percent,
+    Microseconds,
+}
+
+const fn default_scale() -> f64 {
+    1.
+}
+
+impl DebugFields {
+    pub fn add_data(&mut self, firmware: String, new_data: HashMap<String, Fields>) {
+        for (mode, fields) in new_data {
+            let sets = self.data.entry(mode).or_default();
+
+            if let Some((_, firmwares)) = sets.iter_mut().find(|(f, _)| *f == fields) {
+                let i = firmwares.partition_point(|s| s < &firmware);
+                firmwares.insert(i, firmware.clone());
+            } else {
+                sets.push((fields, vec![firmware.clone()]));
+            }
+        }
+    }
+
+    pub fn expand(&self, type_defs: &HashMap<String, TypeDef>) -> TokenStream {
+        let Some(TypeDef::Enum { r#enum: mode }) = type_defs.get(&self.mode) else {
+            panic!("`{}` is not an enum", self.mode);
+        };
+
+        let name = str_to_ident(&self.name);
+        let mode_name = mode.name();
+        let doc = self.doc.lines();
+
+        let mut arms = Vec::new();
+        for (official, sets) in &self.data {
+            let variant = mode.variant(official);
+
+            for (fields, firmwares) in sets {
+                let key = (variant.to_string(), firmwares[0].clone());
+                let firmwares = firmwares.iter().map(|fw| str_to_ident(fw));
+                let fields = fields
+                    .iter()
+                    .map(|field| field.as_ref().map_or_else(|| quote!(None), Field::expand));
+
+                arms.push((
+                    key,
+                    quote!((#mode_name::#variant, #(#firmwares)|*) => &[#(#fields),*]),
+                ));
+            }
+        }
+        arms.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        let arms = arms.into_iter().map(|(_, tokens)| tokens);
+
+        quote! {
+            #( #[doc = #doc] )*
+            #[allow(unused_qualifications, clippy::enum_glob_use, clippy::match_same_arms, clippy::unseparated_literal_suffix)]
+            pub(crate) const fn #name(mode: #mode_name, fw: crate::headers::InternalFirmware) -> &'static [Option<DebugField>] {
+                use crate::headers::InternalFirmware::*;
+                match (mode, fw) {
+                    #(#arms,)*
+                    _ => &[],
+                }
+            }
+        }
+    }
+}
+
+impl Field {
+    fn expand(&self) -> TokenStream {
+        let name = &self.name;
+        let scale = self.scale;
+        let unit = match self.unit {
+            Some(FieldUnit::Rotation) => quote!(Rotation),
+            Some(FieldUnit::Frequency) => quote!(Frequency),
+            Some(FieldUnit::Percent) => quote!(Ratio),
+            Some(FieldUnit::Microseconds) => quote!(Duration),
+            None => quote!(Unitless),
+        };
+
+        quote! {
+            Some(DebugField {
+                name: #name,
+                unit: crate::frame::MainUnit::#unit,
+                scale: #scale,
+            })
+        }
+    }
+}
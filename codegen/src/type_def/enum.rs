@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use heck::ToUpperCamelCase as _;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use serde::Deserialize;
@@ -53,10 +54,20 @@ pub enum #name {
         }
     }
 
-    fn name(&self) -> Ident {
+    pub(super) fn name(&self) -> Ident {
         str_to_ident(&self.name)
     }
 
+    /// Returns the name of the variant for an official name.
+    pub(super) fn variant(&self, official: &str) -> Ident {
+        let rust = self
+            .rename
+            .get(official)
+            .cloned()
+            .unwrap_or_else(|| official.to_upper_camel_case());
+        str_to_ident(&rust)
+    }
+
     fn expand_new(&self, data: &AugmentedData<'_>) -> TokenStream {
         let name = self.name();
 
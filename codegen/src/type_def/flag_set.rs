@@ -73,7 +73,7 @@ fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                 }
             }
 
-            #[cfg(feature = "_serde")]
+            #[cfg(feature = "serde")]
             #[allow(clippy::cast_possible_truncation)]
             impl ::serde::Serialize for #name {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -92,6 +92,35 @@ fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
                     seq.end()
                 }
             }
+
+            /// Only the flags are serialized, so this picks a firmware that
+            /// supports all of them, trying the newest versions first.
+            #[cfg(feature = "serde")]
+            #[allow(unused_qualifications)]
+            impl<'de> ::serde::Deserialize<'de> for #name {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    let flags = <::alloc::vec::Vec<#flag_name>>::deserialize(deserializer)?;
+
+                    crate::headers::InternalFirmware::ALL
+                        .iter()
+                        .rev()
+                        .find_map(|&firmware| {
+                            let raw = flags.iter().try_fold(0_u32, |raw, flag| {
+                                flag.to_bit(firmware).map(|bit| raw | (1 << bit))
+                            })?;
+
+                            Some(Self::new(raw, firmware))
+                        })
+                        .ok_or_else(|| {
+                            <D::Error as serde::de::Error>::custom(
+                                "no supported firmware has all flags",
+                            )
+                        })
+                }
+            }
         }
     }
 }
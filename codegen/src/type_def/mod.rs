@@ -1,3 +1,4 @@
+mod debug_fields;
 mod r#enum;
 mod flag_set;
 
@@ -8,6 +9,7 @@
 use quote::{format_ident, quote};
 use serde::Deserialize;
 
+use self::debug_fields::DebugFields;
 use self::flag_set::{FlagSet, Flags};
 use self::r#enum::Enum;
 
@@ -16,10 +18,11 @@
 pub enum TypeDef {
     Flags { set: FlagSet, flags: Flags },
     Enum { r#enum: Enum },
+    DebugFields { debug: DebugFields },
 }
 
 impl TypeDef {
-    pub fn expand(&self) -> TokenStream {
+    pub fn expand(&self, type_defs: &HashMap<String, Self>) -> TokenStream {
         match self {
             Self::Flags { set, flags } => {
                 let mut tokens = set.expand(&flags.name);
@@ -27,17 +30,26 @@ pub fn expand(&self) -> TokenStream {
                 tokens
             }
             Self::Enum { r#enum } => r#enum.expand(),
+            Self::DebugFields { debug } => debug.expand(type_defs),
         }
     }
 
-    pub fn add_data(&mut self, firmware: String, new_data: HashMap<String, u32>) {
-        let (Self::Flags {
-            flags: Flags { data, .. },
-            ..
-        }
-        | Self::Enum {
-            r#enum: Enum { data, .. },
-        }) = self;
+    pub fn add_data(&mut self, firmware: String, new_data: serde_yaml::Value) {
+        let data = match self {
+            Self::Flags {
+                flags: Flags { data, .. },
+                ..
+            }
+            | Self::Enum {
+                r#enum: Enum { data, .. },
+            } => data,
+            Self::DebugFields { debug } => {
+                debug.add_data(firmware, serde_yaml::from_value(new_data).unwrap());
+                return;
+            }
+        };
+
+        let new_data: HashMap<String, u32> = serde_yaml::from_value(new_data).unwrap();
 
         for (official, index) in new_data {
             let indices = data.entry(official).or_default();
@@ -53,7 +65,9 @@ fn flag_docs(doc: &str) -> String {
 }
 
 fn quote_attrs(doc: &str, attrs: &[String], serde: bool) -> TokenStream {
-    let serde = serde.then_some(quote!(#[cfg_attr(feature = "_serde", derive(serde::Serialize))]));
+    let serde = serde.then_some(
+        quote!(#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]),
+    );
     let doc = doc.lines();
     let attrs = attrs
         .iter()
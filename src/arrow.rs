@@ -0,0 +1,291 @@
+//! Conversion of decoded logs into Apache Arrow [`RecordBatch`]es, which can
+//! then be written as Parquet files using [`write_parquet`] if the `parquet`
+//! feature is enabled.
+//!
+//! Each column of the main, slow and GPS batches stores its [`Unit`] and
+//! signedness as field metadata under the `unit` and `signed` keys. Columns
+//! converted into floats also store the unit of their values, such as `rad/s`,
+//! under the `si_unit` key. Every header in the log is stored as schema
+//! metadata.
+//!
+//! ```
+//! use blackbox_log::arrow::RecordBatches;
+//! use blackbox_log::FilterSet;
+//!
+//! # let file = b"...";
+//! # for headers in blackbox_log::File::new(file).iter() {
+//! #     let headers = headers.unwrap();
+//! let batches = RecordBatches::new(&headers, &FilterSet::default()).unwrap();
+//! println!("{} main frames", batches.main.num_rows());
+//! # }
+//! ```
+
+use alloc::borrow::ToOwned as _;
+use alloc::string::{String, ToString as _};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use std::collections::HashMap;
+
+use arrow_array::{ArrayRef, Float64Array, Int32Array, RecordBatch, UInt32Array, UInt64Array};
+use arrow_schema::{ArrowError, DataType, Field, Schema};
+
+use crate::columns::{ColumnValues, Columns, FrameColumns};
+use crate::{FilterSet, Headers, Unit};
+
+/// The name of the column holding the time of each frame, which is always
+/// first.
+pub const TIME_COLUMN: &str = "time";
+
+/// The frames of a log converted into one [`RecordBatch`] for each kind of
+/// frame.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RecordBatches {
+    pub main: RecordBatch,
+    pub slow: RecordBatch,
+    /// `None` if the log cannot contain GPS frames.
+    pub gps: Option<RecordBatch>,
+}
+
+impl RecordBatches {
+    /// Decodes the whole log, converting fields with a physical unit into
+    /// floats. See [`Columns::new`].
+    pub fn new(headers: &Headers, filters: &FilterSet) -> Result<Self, ArrowError> {
+        Self::from_columns(headers, Columns::new(headers, filters))
+    }
+
+    /// Decodes the whole log, leaving every field as its raw integer value.
+    /// See [`Columns::new_raw`].
+    pub fn new_raw(headers: &Headers, filters: &FilterSet) -> Result<Self, ArrowError> {
+        Self::from_columns(headers, Columns::new_raw(headers, filters))
+    }
+
+    /// Converts already decoded columns. `headers` must be from the same log.
+    pub fn from_columns(headers: &Headers, columns: Columns) -> Result<Self, ArrowError> {
+        let metadata = headers
+            .iter_raw()
+            .map(|(name, value)| (name.to_owned(), value.to_owned()))
+            .collect::<HashMap<_, _>>();
+
+        Ok(Self {
+            main: record_batch(columns.main, metadata.clone())?,
+            slow: record_batch(columns.slow, metadata.clone())?,
+            gps: columns
+                .gps
+                .map(|gps| record_batch(gps, metadata))
+                .transpose()?,
+        })
+    }
+}
+
+fn record_batch<U: Copy + Into<Unit>>(
+    columns: FrameColumns<U>,
+    metadata: HashMap<String, String>,
+) -> Result<RecordBatch, ArrowError> {
+    let len = columns.fields.len() + 1;
+    let mut fields = Vec::with_capacity(len);
+    let mut arrays: Vec<ArrayRef> = Vec::with_capacity(len);
+
+    fields.push(
+        Field::new(TIME_COLUMN, DataType::UInt64, false)
+            .with_metadata(field_metadata("microseconds", false)),
+    );
+    arrays.push(Arc::new(UInt64Array::from(columns.time)));
+
+    for column in columns.fields {
+        let (data_type, array): (_, ArrayRef) = match column.values {
+            ColumnValues::Float(values) => {
+                (DataType::Float64, Arc::new(Float64Array::from(values)))
+            }
+            ColumnValues::Unsigned(values) => {
+                (DataType::UInt32, Arc::new(UInt32Array::from(values)))
+            }
+            ColumnValues::Signed(values) => (DataType::Int32, Arc::new(Int32Array::from(values))),
+        };
+
+        let unit = column.def.unit.into();
+        let mut metadata = field_metadata(unit_name(unit), column.def.signed);
+        if data_type == DataType::Float64 {
+            if let Some(si_unit) = si_unit_name(unit) {
+                metadata.insert("si_unit".to_owned(), si_unit.to_owned());
+            }
+        }
+
+        fields.push(Field::new(column.def.name, data_type, false).with_metadata(metadata));
+        arrays.push(array);
+    }
+
+    let schema = Schema::new_with_metadata(fields, metadata);
+    RecordBatch::try_new(Arc::new(schema), arrays)
+}
+
+fn field_metadata(unit: &str, signed: bool) -> HashMap<String, String> {
+    HashMap::from([
+        ("unit".to_owned(), unit.to_owned()),
+        ("signed".to_owned(), signed.to_string()),
+    ])
+}
+
+const fn unit_name(unit: Unit) -> &'static str {
+    match unit {
+        Unit::Amperage => "amperage",
+        Unit::Voltage => "voltage",
+        Unit::Acceleration => "acceleration",
+        Unit::Rotation => "rotation",
+        Unit::Frequency => "frequency",
+        Unit::Motor => "motor",
+        Unit::Stick => "stick",
+        Unit::Ratio => "ratio",
+        Unit::Duration => "duration",
+        Unit::FlightMode => "flight_mode",
+        Unit::State => "state",
+        Unit::FailsafePhase => "failsafe_phase",
+        Unit::GpsCoordinate => "gps_coordinate",
+        Unit::Altitude => "altitude",
+        Unit::Velocity => "velocity",
+        Unit::GpsHeading => "gps_heading",
+        Unit::Boolean => "boolean",
+        Unit::Unitless => "unitless",
+    }
+}
+
+/// Returns the unit of a column converted into [`ColumnValues::Float`], or
+/// `None` if it is never converted.
+const fn si_unit_name(unit: Unit) -> Option<&'static str> {
+    match unit {
+        Unit::Amperage => Some("A"),
+        Unit::Voltage => Some("V"),
+        Unit::Acceleration => Some("m/s^2"),
+        Unit::Rotation => Some("rad/s"),
+        Unit::Frequency => Some("Hz"),
+        Unit::Motor | Unit::Stick | Unit::Ratio => Some("1"),
+        Unit::Duration => Some("s"),
+        Unit::GpsCoordinate | Unit::GpsHeading => Some("deg"),
+        Unit::Altitude => Some("m"),
+        Unit::Velocity => Some("m/s"),
+        Unit::FlightMode | Unit::State | Unit::FailsafePhase | Unit::Boolean | Unit::Unitless => {
+            None
+        }
+    }
+}
+
+/// Writes a single [`RecordBatch`] as a complete Parquet file.
+///
+/// The schema metadata, which includes every header of the log, is also
+/// written as the file's key/value metadata.
+#[cfg(feature = "parquet")]
+pub fn write_parquet<W: std::io::Write + Send>(
+    writer: W,
+    batch: &RecordBatch,
+) -> Result<(), parquet::errors::ParquetError> {
+    use parquet::arrow::ArrowWriter;
+    use parquet::file::metadata::KeyValue;
+    use parquet::file::properties::WriterProperties;
+
+    let metadata = batch
+        .schema()
+        .metadata()
+        .iter()
+        .map(|(key, value)| KeyValue::new(key.clone(), value.clone()))
+        .collect();
+    let properties = WriterProperties::builder()
+        .set_key_value_metadata(Some(metadata))
+        .build();
+
+    let mut writer = ArrowWriter::try_new(writer, batch.schema(), Some(properties))?;
+    writer.write(batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::{FrameDef as _, MainUnit};
+
+    static LOG: &[u8] = include_bytes!("../tests/logs/error-recovery.bbl");
+
+    #[test]
+    fn schema_from_frame_defs() {
+        let headers = Headers::parse(LOG).unwrap();
+        let columns = Columns::new(&headers, &FilterSet::default());
+        let main_len = columns.main.len();
+        let batches = RecordBatches::from_columns(&headers, columns).unwrap();
+
+        let schema = batches.main.schema();
+        assert_eq!(main_len, batches.main.num_rows());
+        assert_eq!(
+            Some(&headers.firmware_revision().to_owned()),
+            schema.metadata().get("Firmware revision")
+        );
+
+        let mut fields = schema.fields().iter();
+        assert_eq!(TIME_COLUMN, fields.next().unwrap().name());
+        for (field, def) in fields.zip(headers.main_frame_def().iter()) {
+            assert_eq!(def.name, field.name());
+            assert_eq!(
+                Some(unit_name(def.unit.into())),
+                field.metadata().get("unit").map(String::as_str)
+            );
+
+            let expected = match (def.unit, def.signed) {
+                (MainUnit::Unitless, true) => DataType::Int32,
+                (MainUnit::Unitless, false) => DataType::UInt32,
+                _ => DataType::Float64,
+            };
+            assert_eq!(&expected, field.data_type(), "{}", def.name);
+            assert_eq!(
+                expected == DataType::Float64,
+                field.metadata().contains_key("si_unit"),
+                "{}",
+                def.name
+            );
+        }
+
+        let gyro = schema.field_with_name("gyroADC[0]").unwrap();
+        assert_eq!(
+            Some("rad/s"),
+            gyro.metadata().get("si_unit").map(String::as_str)
+        );
+
+        let raw = RecordBatches::new_raw(&headers, &FilterSet::default()).unwrap();
+        assert!(raw.main.schema().fields().iter().all(|field| {
+            field.data_type() != &DataType::Float64 && !field.metadata().contains_key("si_unit")
+        }));
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn parquet_metadata() {
+        use alloc::format;
+
+        use parquet::file::reader::{FileReader as _, SerializedFileReader};
+
+        let headers = Headers::parse(LOG).unwrap();
+        let batches = RecordBatches::new(&headers, &FilterSet::default()).unwrap();
+
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let name = format!(
+            "blackbox-log-parquet-metadata-{}-{nanos}.parquet",
+            std::process::id()
+        );
+        let path = std::env::temp_dir().join(name);
+        write_parquet(std::fs::File::create(&path).unwrap(), &batches.main).unwrap();
+
+        let reader = SerializedFileReader::new(std::fs::File::open(&path).unwrap()).unwrap();
+        let metadata = reader.metadata().file_metadata();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(Ok(batches.main.num_rows()), metadata.num_rows().try_into());
+        let revision = metadata
+            .key_value_metadata()
+            .unwrap()
+            .iter()
+            .find(|kv| kv.key == "Firmware revision")
+            .and_then(|kv| kv.value.as_deref());
+        assert_eq!(Some(headers.firmware_revision()), revision);
+    }
+}
@@ -0,0 +1,410 @@
+//! Decoding a whole log into one array per field.
+//!
+//! ```
+//! use blackbox_log::columns::{ColumnValues, Columns};
+//! use blackbox_log::FilterSet;
+//!
+//! # let file = b"...";
+//! # for headers in blackbox_log::File::new(file).iter() {
+//! #     let headers = headers.unwrap();
+//! let columns = Columns::new(&headers, &FilterSet::default());
+//!
+//! if let Some(gyro) = columns.main.get("gyroADC[0]") {
+//!     if let ColumnValues::Float(values) = &gyro.values {
+//!         // Angular velocity in radians per second
+//!         let max = values.iter().copied().fold(f64::NAN, f64::max);
+//!     }
+//! }
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::data::{ParserEvent, Stats};
+use crate::frame::{
+    FieldDef, Frame, FrameDef, GpsUnit, GpsValue, MainConversion, MainUnit, MainValue, SlowUnit,
+};
+use crate::headers::DebugField;
+use crate::units::prelude::*;
+use crate::units::{self, MotorOutput, UnitContext};
+use crate::{FilterSet, Headers};
+
+/// Every field of every frame in a log, with one array per field.
+///
+/// Events are not included. Use a [`DataParser`][crate::DataParser] if those
+/// are needed.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Columns<'data> {
+    pub main: FrameColumns<'data, MainUnit>,
+    pub slow: FrameColumns<'data, SlowUnit>,
+    /// `None` if the log cannot contain GPS frames.
+    pub gps: Option<FrameColumns<'data, GpsUnit>>,
+    pub stats: Stats,
+}
+
+/// The fields of one kind of frame, with one row per frame.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct FrameColumns<'data, U> {
+    /// The raw microsecond counter since power on for each frame. See
+    /// [`MainFrame::time_raw`][crate::frame::MainFrame::time_raw].
+    ///
+    /// Slow frames do not include a time, so this is the time of the last main
+    /// frame before each one, or `0` if there was none.
+    pub time: Vec<u64>,
+    /// Only the fields that satisfy the filter, in the same order as the frame
+    /// definition.
+    pub fields: Vec<Column<'data, U>>,
+}
+
+/// The values of a single field.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Column<'data, U> {
+    pub def: FieldDef<'data, U>,
+    pub values: ColumnValues,
+}
+
+/// The values of a single field, either converted into floats or left as the
+/// raw integers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnValues {
+    /// Values converted into SI units, except for GPS coordinates and headings,
+    /// which are in degrees.
+    Float(Vec<f64>),
+    Unsigned(Vec<u32>),
+    Signed(Vec<i32>),
+}
+
+impl<'data> Columns<'data> {
+    /// Decodes the whole log, converting every field with a physical unit into
+    /// floats. Fields without one, including all flags, are left as raw
+    /// integers.
+    pub fn new(headers: &Headers<'data>, filters: &FilterSet) -> Self {
+        Self::decode(headers, filters, true)
+    }
+
+    /// Decodes the whole log, leaving every field as its raw integer value. See
+    /// [`Frame::get_raw`].
+    pub fn new_raw(headers: &Headers<'data>, filters: &FilterSet) -> Self {
+        Self::decode(headers, filters, false)
+    }
+
+    fn decode(headers: &Headers<'data>, filters: &FilterSet, convert: bool) -> Self {
+        let units = UnitContext::new(headers);
+        let mut parser = headers.data_parser_with_filters(filters);
+
+        let mut main = Builder::new(&parser.main_frame_def(), |def| {
+            convert
+                .then(|| main_conversion(def, headers.debug_fields(), &units))
+                .flatten()
+        });
+        let mut slow = Builder::new(&parser.slow_frame_def(), |_| None);
+        let mut gps = parser.gps_frame_def().map(|def| {
+            Builder::new(&def, |def| {
+                convert
+                    .then(|| gps_scale(def.unit, def.signed).map(Conversion::Scale))
+                    .flatten()
+            })
+        });
+
+        let mut last_time = 0;
+        while let Some(event) = parser.next() {
+            match event {
+                ParserEvent::Event(_) => {}
+                ParserEvent::Main(frame) => {
+                    last_time = frame.time_raw();
+                    main.push(last_time, &frame);
+                }
+                ParserEvent::Slow(frame) => slow.push(last_time, &frame),
+                ParserEvent::Gps(frame) => {
+                    if let Some(gps) = &mut gps {
+                        gps.push(frame.time_raw(), &frame);
+                    }
+                }
+            }
+        }
+
+        Self {
+            main: main.finish(),
+            slow: slow.finish(),
+            gps: gps.map(Builder::finish),
+            stats: parser.stats().clone(),
+        }
+    }
+}
+
+impl<'data, U> FrameColumns<'data, U> {
+    /// Returns the number of frames.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.time.len()
+    }
+
+    /// Returns `true` if no frames were found.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.time.is_empty()
+    }
+
+    /// Returns the column for a field by its name.
+    pub fn get(&self, name: &str) -> Option<&Column<'data, U>> {
+        self.fields.iter().find(|column| column.def.name == name)
+    }
+}
+
+impl ColumnValues {
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Float(values) => values.len(),
+            Self::Unsigned(values) => values.len(),
+            Self::Signed(values) => values.len(),
+        }
+    }
+
+    /// Returns `true` if there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Collects the columns for one kind of frame while decoding.
+struct Builder<'data, U> {
+    time: Vec<u64>,
+    fields: Vec<Column<'data, U>>,
+    /// How to convert each field into [`ColumnValues::Float`], if it should be
+    /// converted
+    conversions: Vec<Option<Conversion>>,
+}
+
+/// How to convert a raw value into [`ColumnValues::Float`].
+#[derive(Debug, Clone, Copy)]
+enum Conversion {
+    /// Multiply by a constant factor
+    Scale(f64),
+    /// Convert a motor output into a fraction of full throttle
+    Motor(MotorOutput),
+    /// Convert `rcCommand` into stick deflection for an axis
+    Stick(usize),
+}
+
+impl Conversion {
+    fn apply(self, raw: f64) -> f64 {
+        match self {
+            Self::Scale(scale) => raw * scale,
+            Self::Motor(output) => output.fraction(raw),
+            Self::Stick(axis) => units::new::stick_deflection(raw, axis),
+        }
+    }
+}
+
+impl<'data, U: Copy> Builder<'data, U> {
+    fn new<D: FrameDef<'data, Unit = U>>(
+        def: &D,
+        conversion: impl Fn(&FieldDef<'data, U>) -> Option<Conversion>,
+    ) -> Self {
+        let (fields, conversions) = def
+            .iter()
+            .map(|def| {
+                let conversion = conversion(&def);
+                let values = match (conversion, def.signed) {
+                    (Some(_), _) => ColumnValues::Float(Vec::new()),
+                    (None, true) => ColumnValues::Signed(Vec::new()),
+                    (None, false) => ColumnValues::Unsigned(Vec::new()),
+                };
+
+                (Column { def, values }, conversion)
+            })
+            .unzip();
+
+        Self {
+            time: Vec::new(),
+            fields,
+            conversions,
+        }
+    }
+
+    fn push<F: Frame>(&mut self, time: u64, frame: &F) {
+        self.time.push(time);
+
+        let columns = self.fields.iter_mut().zip(&self.conversions);
+        for ((column, conversion), raw) in columns.zip(frame.iter_raw()) {
+            match &mut column.values {
+                ColumnValues::Float(values) => {
+                    let raw = if column.def.signed {
+                        f64::from(raw.cast_signed())
+                    } else {
+                        f64::from(raw)
+                    };
+
+                    values.push(conversion.map_or(raw, |conversion| conversion.apply(raw)));
+                }
+                ColumnValues::Unsigned(values) => values.push(raw),
+                ColumnValues::Signed(values) => values.push(raw.cast_signed()),
+            }
+        }
+    }
+
+    fn finish(self) -> FrameColumns<'data, U> {
+        FrameColumns {
+            time: self.time,
+            fields: self.fields,
+        }
+    }
+}
+
+/// Returns how to convert a raw main field value into SI units, or `None` if it
+/// does not have any.
+fn main_conversion(
+    def: &FieldDef<'_, MainUnit>,
+    debug: &[Option<DebugField>],
+    units: &UnitContext,
+) -> Option<Conversion> {
+    let conversion = MainConversion::from_name(def.name, debug);
+    match conversion {
+        MainConversion::Unit(MainUnit::Motor) => {
+            return units.motor_output.map(Conversion::Motor);
+        }
+        MainConversion::Stick(axis) => return Some(Conversion::Stick(axis)),
+        MainConversion::Unit(_) | MainConversion::Setpoint | MainConversion::Debug(_) => {}
+    }
+
+    let scale = match MainValue::new(1, conversion, def.signed, units) {
+        MainValue::Amperage(current) => Some(current.get::<ampere>()),
+        MainValue::Voltage(voltage) => Some(voltage.get::<volt>()),
+        MainValue::Acceleration(accel) => Some(accel.get::<mps2>()),
+        MainValue::Rotation(rotation) => Some(rotation.get::<radian_per_second>()),
+        MainValue::Frequency(frequency) => Some(frequency.get::<hertz>()),
+        MainValue::Ratio(fraction) => Some(fraction.get::<ratio>()),
+        MainValue::Duration(duration) => Some(duration.get::<second>()),
+        MainValue::Motor(_)
+        | MainValue::Stick(_)
+        | MainValue::Unsigned(_)
+        | MainValue::Signed(_) => None,
+    };
+
+    scale.map(Conversion::Scale)
+}
+
+/// Returns the factor to convert a raw GPS field value into SI units, or
+/// degrees for coordinates and headings.
+fn gps_scale(unit: GpsUnit, signed: bool) -> Option<f64> {
+    match GpsValue::new(1, unit, signed) {
+        GpsValue::Coordinate(degrees) | GpsValue::Heading(degrees) => Some(degrees),
+        GpsValue::Altitude(altitude) => Some(altitude.get::<meter>()),
+        GpsValue::Velocity(velocity) => Some(velocity.get::<meter_per_second>()),
+        GpsValue::Unsigned(_) | GpsValue::Signed(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::Filter;
+
+    static LOG: &[u8] = include_bytes!("../tests/logs/error-recovery.bbl");
+
+    fn si(value: MainValue) -> Option<f64> {
+        match value {
+            MainValue::Amperage(current) => Some(current.get::<ampere>()),
+            MainValue::Voltage(voltage) => Some(voltage.get::<volt>()),
+            MainValue::Acceleration(accel) => Some(accel.get::<mps2>()),
+            MainValue::Rotation(rotation) => Some(rotation.get::<radian_per_second>()),
+            MainValue::Frequency(frequency) => Some(frequency.get::<hertz>()),
+            MainValue::Motor(motor) => Some(motor.get::<ratio>()),
+            MainValue::Stick(stick) => Some(stick.deflection),
+            MainValue::Ratio(fraction) => Some(fraction.get::<ratio>()),
+            MainValue::Duration(duration) => Some(duration.get::<second>()),
+            MainValue::Unsigned(_) | MainValue::Signed(_) => None,
+        }
+    }
+
+    #[test]
+    fn matches_data_parser() {
+        let headers = Headers::parse(LOG).unwrap();
+        let filters = FilterSet {
+            main: Filter::OnlyFields(["vbatLatest", "gyroADC", "motor", "rcCommand"].into()),
+            ..FilterSet::default()
+        };
+
+        let columns = Columns::new(&headers, &filters);
+        let raw_columns = Columns::new_raw(&headers, &filters);
+        assert!(!columns.main.is_empty());
+        assert!(columns
+            .main
+            .fields
+            .iter()
+            .any(|column| matches!(column.values, ColumnValues::Float(_))));
+
+        let mut parser = headers.data_parser_with_filters(&filters);
+        assert_eq!(parser.main_frame_def().len(), columns.main.fields.len());
+
+        let (mut main, mut slow) = (0, 0);
+        while let Some(event) = parser.next() {
+            match event {
+                ParserEvent::Main(frame) => {
+                    assert_eq!(frame.time_raw(), columns.main.time[main]);
+
+                    for (i, value) in frame.iter().enumerate() {
+                        let raw = frame.get_raw(i).unwrap();
+
+                        match (&columns.main.fields[i].values, si(value)) {
+                            (ColumnValues::Float(values), Some(expected)) => {
+                                let diff = (values[main] - expected).abs();
+                                assert!(diff <= expected.abs() * 1e-12, "{value:?}");
+                            }
+                            (ColumnValues::Unsigned(values), None) => {
+                                assert_eq!(raw, values[main]);
+                            }
+                            (ColumnValues::Signed(values), None) => {
+                                assert_eq!(raw.cast_signed(), values[main]);
+                            }
+                            (values, _) => panic!("{values:?} does not match {value:?}"),
+                        }
+
+                        let expected = match &raw_columns.main.fields[i].values {
+                            ColumnValues::Unsigned(values) => values[main],
+                            ColumnValues::Signed(values) => values[main].cast_unsigned(),
+                            ColumnValues::Float(_) => panic!("raw column was converted"),
+                        };
+                        assert_eq!(expected, raw);
+                    }
+
+                    main += 1;
+                }
+                ParserEvent::Slow(frame) => {
+                    let values =
+                        raw_columns
+                            .slow
+                            .fields
+                            .iter()
+                            .map(|column| match &column.values {
+                                ColumnValues::Unsigned(values) => values[slow],
+                                ColumnValues::Signed(values) => values[slow].cast_unsigned(),
+                                ColumnValues::Float(_) => panic!("slow column was converted"),
+                            });
+                    assert!(frame.iter_raw().eq(values));
+
+                    slow += 1;
+                }
+                ParserEvent::Event(_) | ParserEvent::Gps(_) => {}
+            }
+        }
+
+        assert_eq!(main, columns.main.len());
+        assert_eq!(slow, columns.slow.len());
+        assert_eq!(
+            vec![main; columns.main.fields.len()],
+            columns
+                .main
+                .fields
+                .iter()
+                .map(|column| column.values.len())
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,582 @@
+//! Flight controller configuration decoded from the headers of a log.
+//!
+//! Most tuning settings are only written as headers, so they are not used
+//! while parsing and end up in [`Headers::unknown`]. [`Headers::config`]
+//! decodes the common ones into a [`Config`], taking into account the different
+//! header names and value formats used by each firmware.
+//!
+//! All values are left in the units used by the firmware.
+//!
+//! ```
+//! # let file = b"...";
+//! # for headers in blackbox_log::File::new(file).iter() {
+//! #     let headers = headers.unwrap();
+//! let config = headers.config();
+//!
+//! if let Some(roll) = config.pids.roll {
+//!     println!("roll: P = {}, I = {}, D = {}", roll.p, roll.i, roll.d);
+//! }
+//!
+//! for (header, value) in config.unknown() {
+//!     println!("not decoded: {header} = {value}");
+//! }
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+
+use hashbrown::HashMap;
+
+use crate::headers::InternalFirmware;
+use crate::Headers;
+
+include_generated!("filter_type");
+include_generated!("iterm_relax");
+include_generated!("iterm_relax_type");
+include_generated!("rates_type");
+
+/// Settings decoded from the headers of a log.
+///
+/// Every setting is `None` if its header is missing or could not be parsed.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Config<'data> {
+    pub pids: Pids,
+    pub rates: Rates,
+    pub gyro: GyroFilters,
+    pub dterm: DtermFilters,
+    pub dyn_notch: DynNotch,
+    pub rpm_filter: RpmFilter,
+    /// Throttle PID attenuation, as a percentage.
+    pub tpa_rate: Option<u16>,
+    /// The throttle value where TPA starts, in microseconds.
+    pub tpa_breakpoint: Option<u16>,
+    pub anti_gravity_gain: Option<u16>,
+    pub iterm_relax: Option<ItermRelax>,
+    pub iterm_relax_type: Option<ItermRelaxType>,
+    pub iterm_relax_cutoff: Option<u16>,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    consumed: Vec<&'data str>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    unknown: HashMap<&'data str, &'data str>,
+}
+
+/// PID gains for each axis.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Pids {
+    pub roll: Option<Pid>,
+    pub pitch: Option<Pid>,
+    pub yaw: Option<Pid>,
+    /// Gains for the self-leveling modes.
+    pub level: Option<Pid>,
+    /// Feedforward gains for roll, pitch and yaw.
+    pub feedforward: Option<[u16; 3]>,
+    /// The minimum D gains for roll, pitch and yaw when D max is enabled.
+    ///
+    /// Only Betaflight logs this.
+    pub d_min: Option<[u16; 3]>,
+}
+
+/// The gains of a single PID controller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pid {
+    pub p: u16,
+    pub i: u16,
+    pub d: u16,
+}
+
+/// Stick rates. Arrays are ordered roll, pitch, yaw.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Rates {
+    /// Only Betaflight logs this.
+    pub kind: Option<RatesType>,
+    pub rc_rate: Option<[u16; 3]>,
+    pub expo: Option<[u16; 3]>,
+    /// Super rates for Betaflight, or the maximum rate in tens of degrees per
+    /// second for INAV.
+    pub rate: Option<[u16; 3]>,
+    /// The maximum rate in degrees per second.
+    ///
+    /// Only Betaflight logs this.
+    pub rate_limit: Option<[u16; 3]>,
+    pub throttle_mid: Option<u16>,
+    pub throttle_expo: Option<u16>,
+}
+
+/// A lowpass filter stage.
+///
+/// A `static_hz` of `0` means the filter is disabled, unless it has a
+/// `dynamic_hz` range.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Lowpass {
+    pub kind: Option<FilterType>,
+    pub static_hz: Option<u16>,
+    /// The `(min, max)` cutoff of a dynamic filter. A `min` of `0` means it is
+    /// disabled.
+    pub dynamic_hz: Option<(u16, u16)>,
+}
+
+/// Lowpass filters applied to the gyro.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct GyroFilters {
+    pub lpf1: Lowpass,
+    pub lpf2: Lowpass,
+}
+
+/// Lowpass filters applied to the D term.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct DtermFilters {
+    pub lpf1: Lowpass,
+    pub lpf2: Lowpass,
+    pub yaw_lowpass_hz: Option<u16>,
+}
+
+/// The dynamic notch filter, which tracks peaks in the gyro spectrum.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct DynNotch {
+    /// Only Betaflight 4.3+ logs this.
+    pub count: Option<u16>,
+    /// Only Betaflight 4.2 logs this.
+    pub width_percent: Option<u16>,
+    pub q: Option<u16>,
+    pub min_hz: Option<u16>,
+    /// Only Betaflight logs this.
+    pub max_hz: Option<u16>,
+}
+
+/// The gyro RPM filter, which uses motor speeds from bidirectional DShot.
+///
+/// Only Betaflight logs this.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct RpmFilter {
+    /// The number of harmonics filtered for each motor. `0` means the filter
+    /// is disabled.
+    pub harmonics: Option<u16>,
+    pub q: Option<u16>,
+    pub min_hz: Option<u16>,
+    /// Only Betaflight 4.3+ logs this.
+    pub fade_range_hz: Option<u16>,
+    pub lpf_hz: Option<u16>,
+}
+
+impl<'data> Config<'data> {
+    pub(crate) fn new(headers: &Headers<'data>) -> Self {
+        let mut remaining = Remaining {
+            unknown: headers.unknown().clone(),
+            consumed: Vec::new(),
+        };
+
+        let firmware = headers.internal_firmware;
+        // EmuFlight and Rotorflight are forks of Betaflight and use its header names
+        let mut config = if firmware.is_inav() {
+            inav(&mut remaining, firmware)
+        } else {
+            betaflight(&mut remaining, firmware)
+        };
+
+        config.consumed = remaining.consumed;
+        config.unknown = remaining.unknown;
+        config
+    }
+
+    /// Returns the names of every header used to decode the config.
+    #[inline]
+    pub fn consumed(&self) -> &[&'data str] {
+        &self.consumed
+    }
+
+    /// Returns the headers from [`Headers::unknown`] that are still unknown
+    /// after decoding the config.
+    #[inline]
+    pub fn unknown(&self) -> &HashMap<&'data str, &'data str> {
+        &self.unknown
+    }
+}
+
+fn betaflight<'data>(headers: &mut Remaining<'data>, firmware: InternalFirmware) -> Config<'data> {
+    let before_4_3 = firmware
+        .betaflight_base()
+        .is_none_or(|base| base < InternalFirmware::Betaflight4_3);
+    let filter = |raw: &str| FilterType::new(raw.parse().ok()?, firmware);
+    let lowpass = |headers: &mut Remaining<'data>,
+                   kind: &str,
+                   static_hz: &str,
+                   dynamic_hz: Option<&str>| Lowpass {
+        kind: headers.take(kind, filter),
+        static_hz: headers.take(static_hz, parse),
+        dynamic_hz: dynamic_hz.and_then(|name| headers.take(name, parse_pair)),
+    };
+
+    let pids = Pids {
+        roll: headers.take("rollPID", parse_pid),
+        pitch: headers.take("pitchPID", parse_pid),
+        yaw: headers.take("yawPID", parse_pid),
+        level: headers.take("levelPID", parse_pid),
+        feedforward: headers.take(
+            if before_4_3 {
+                "feedforward_weight"
+            } else {
+                "ff_weight"
+            },
+            parse_axes,
+        ),
+        d_min: headers.take("d_min", parse_axes),
+    };
+
+    let rates = Rates {
+        kind: headers.take("rates_type", |raw| {
+            RatesType::new(raw.parse().ok()?, firmware)
+        }),
+        rc_rate: headers.take("rc_rates", parse_axes),
+        expo: headers.take("rc_expo", parse_axes),
+        rate: headers.take("rates", parse_axes),
+        rate_limit: headers.take("rate_limits", parse_axes),
+        throttle_mid: headers.take("thr_mid", parse),
+        throttle_expo: headers.take("thr_expo", parse),
+    };
+
+    let (gyro, dterm) = if before_4_3 {
+        let gyro = GyroFilters {
+            lpf1: lowpass(
+                headers,
+                "gyro_lowpass_type",
+                "gyro_lowpass_hz",
+                Some("gyro_lowpass_dyn_hz"),
+            ),
+            lpf2: lowpass(headers, "gyro_lowpass2_type", "gyro_lowpass2_hz", None),
+        };
+
+        let dterm = DtermFilters {
+            lpf1: lowpass(
+                headers,
+                "dterm_filter_type",
+                "dterm_lowpass_hz",
+                Some("dterm_lowpass_dyn_hz"),
+            ),
+            lpf2: lowpass(headers, "dterm_filter2_type", "dterm_lowpass2_hz", None),
+            yaw_lowpass_hz: headers.take("yaw_lowpass_hz", parse),
+        };
+
+        (gyro, dterm)
+    } else {
+        let gyro = GyroFilters {
+            lpf1: lowpass(
+                headers,
+                "gyro_lpf1_type",
+                "gyro_lpf1_static_hz",
+                Some("gyro_lpf1_dyn_hz"),
+            ),
+            lpf2: lowpass(headers, "gyro_lpf2_type", "gyro_lpf2_static_hz", None),
+        };
+
+        let dterm = DtermFilters {
+            lpf1: lowpass(
+                headers,
+                "dterm_lpf1_type",
+                "dterm_lpf1_static_hz",
+                Some("dterm_lpf1_dyn_hz"),
+            ),
+            lpf2: lowpass(headers, "dterm_lpf2_type", "dterm_lpf2_static_hz", None),
+            yaw_lowpass_hz: headers.take("yaw_lowpass_hz", parse),
+        };
+
+        (gyro, dterm)
+    };
+
+    let dyn_notch = DynNotch {
+        count: headers.take("dyn_notch_count", parse),
+        width_percent: headers.take("dyn_notch_width_percent", parse),
+        q: headers.take("dyn_notch_q", parse),
+        min_hz: headers.take("dyn_notch_min_hz", parse),
+        max_hz: headers.take("dyn_notch_max_hz", parse),
+    };
+
+    let rpm_filter = if before_4_3 {
+        RpmFilter {
+            harmonics: headers.take("gyro_rpm_notch_harmonics", parse),
+            q: headers.take("gyro_rpm_notch_q", parse),
+            min_hz: headers.take("gyro_rpm_notch_min", parse),
+            fade_range_hz: None,
+            lpf_hz: headers.take("rpm_notch_lpf", parse),
+        }
+    } else {
+        RpmFilter {
+            harmonics: headers.take("rpm_filter_harmonics", parse),
+            q: headers.take("rpm_filter_q", parse),
+            min_hz: headers.take("rpm_filter_min_hz", parse),
+            fade_range_hz: headers.take("rpm_filter_fade_range_hz", parse),
+            lpf_hz: headers.take("rpm_filter_lpf_hz", parse),
+        }
+    };
+
+    Config {
+        pids,
+        rates,
+        gyro,
+        dterm,
+        dyn_notch,
+        rpm_filter,
+        tpa_rate: headers.take("tpa_rate", parse),
+        tpa_breakpoint: headers.take("tpa_breakpoint", parse),
+        anti_gravity_gain: headers.take("anti_gravity_gain", parse),
+        iterm_relax: headers.take("iterm_relax", |raw| {
+            ItermRelax::new(raw.parse().ok()?, firmware)
+        }),
+        iterm_relax_type: headers.take("iterm_relax_type", |raw| {
+            ItermRelaxType::new(raw.parse().ok()?, firmware)
+        }),
+        iterm_relax_cutoff: headers.take("iterm_relax_cutoff", parse),
+        ..Config::default()
+    }
+}
+
+fn inav<'data>(headers: &mut Remaining<'data>, firmware: InternalFirmware) -> Config<'data> {
+    let filter = |raw: &str| FilterType::new(raw.parse().ok()?, firmware);
+
+    // INAV logs `P,I,D,FF` for each axis
+    let roll = headers.take("rollPID", parse_inav_pid);
+    let pitch = headers.take("pitchPID", parse_inav_pid);
+    let yaw = headers.take("yawPID", parse_inav_pid);
+    let feedforward = roll
+        .zip(pitch)
+        .zip(yaw)
+        .map(|((roll, pitch), yaw)| [roll.1, pitch.1, yaw.1]);
+
+    let pids = Pids {
+        roll: roll.map(|(pid, _)| pid),
+        pitch: pitch.map(|(pid, _)| pid),
+        yaw: yaw.map(|(pid, _)| pid),
+        level: headers.take("levelPID", parse_pid),
+        feedforward,
+        d_min: None,
+    };
+
+    let expo = headers.take("rc_expo", parse::<u16>);
+    let yaw_expo = headers.take("rc_yaw_expo", parse::<u16>);
+    let rates = Rates {
+        kind: None,
+        rc_rate: headers.take("rc_rate", |raw| parse(raw).map(|rate| [rate; 3])),
+        expo: expo.zip(yaw_expo).map(|(expo, yaw)| [expo, expo, yaw]),
+        rate: headers.take("rates", parse_axes),
+        rate_limit: None,
+        throttle_mid: headers.take("thr_mid", parse),
+        throttle_expo: headers.take("thr_expo", parse),
+    };
+
+    let gyro = GyroFilters {
+        lpf1: Lowpass {
+            kind: headers.take("gyro_lpf_type", filter),
+            static_hz: headers.take("gyro_lpf_hz", parse),
+            dynamic_hz: None,
+        },
+        lpf2: Lowpass::default(),
+    };
+
+    let dterm = DtermFilters {
+        lpf1: Lowpass {
+            kind: headers.take("dterm_lpf_type", filter),
+            static_hz: headers.take("dterm_lpf_hz", parse),
+            dynamic_hz: None,
+        },
+        lpf2: Lowpass {
+            kind: headers.take("dterm_lpf2_type", filter),
+            static_hz: headers.take("dterm_lpf2_hz", parse),
+            dynamic_hz: None,
+        },
+        yaw_lowpass_hz: headers.take("yaw_lpf_hz", parse),
+    };
+
+    let dyn_notch = DynNotch {
+        q: headers.take("dynamicGyroNotchQ", parse),
+        min_hz: headers.take("dynamicGyroNotchMinHz", parse),
+        ..DynNotch::default()
+    };
+
+    Config {
+        pids,
+        rates,
+        gyro,
+        dterm,
+        dyn_notch,
+        tpa_rate: headers.take("dynThrPID", parse),
+        tpa_breakpoint: headers.take("tpa_breakpoint", parse),
+        ..Config::default()
+    }
+}
+
+/// The headers not yet used to decode the config.
+struct Remaining<'data> {
+    unknown: HashMap<&'data str, &'data str>,
+    consumed: Vec<&'data str>,
+}
+
+impl<'data> Remaining<'data> {
+    /// Parses the value of `header`, marking it as consumed only if `parse`
+    /// succeeds.
+    fn take<T>(&mut self, header: &str, parse: impl FnOnce(&'data str) -> Option<T>) -> Option<T> {
+        let value = parse(self.unknown.get(header)?)?;
+
+        let (header, _) = self.unknown.remove_entry(header)?;
+        self.consumed.push(header);
+        Some(value)
+    }
+}
+
+fn parse<T: core::str::FromStr>(raw: &str) -> Option<T> {
+    raw.trim().parse().ok()
+}
+
+/// Parses exactly `N` comma separated values.
+fn parse_list<const N: usize>(raw: &str) -> Option<[u16; N]> {
+    let mut values = [0; N];
+    let mut parts = raw.split(',');
+
+    for value in &mut values {
+        *value = parse(parts.next()?)?;
+    }
+
+    parts.next().is_none().then_some(values)
+}
+
+fn parse_axes(raw: &str) -> Option<[u16; 3]> {
+    parse_list(raw)
+}
+
+fn parse_pair(raw: &str) -> Option<(u16, u16)> {
+    parse_list(raw).map(|[min, max]| (min, max))
+}
+
+fn parse_pid(raw: &str) -> Option<Pid> {
+    parse_list(raw).map(|[p, i, d]| Pid { p, i, d })
+}
+
+fn parse_inav_pid(raw: &str) -> Option<(Pid, u16)> {
+    parse_list(raw).map(|[p, i, d, ff]| (Pid { p, i, d }, ff))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::writer::{write_log, Encoding, FieldSpec, HeaderSpec, Predictor};
+
+    #[test]
+    fn betaflight_4_2() {
+        let log = include_bytes!("../tests/logs/error-recovery.bbl");
+        let headers = Headers::parse(log).unwrap();
+        let config = headers.config();
+
+        let pids = &config.pids;
+        assert_eq!(
+            Some(Pid {
+                p: 50,
+                i: 102,
+                d: 36
+            }),
+            pids.roll
+        );
+        assert_eq!(
+            Some(Pid {
+                p: 54,
+                i: 108,
+                d: 0
+            }),
+            pids.yaw
+        );
+        assert_eq!(Some([97, 103, 97]), pids.feedforward);
+        assert_eq!(Some([0, 0, 0]), pids.d_min);
+
+        assert_eq!(Some(RatesType::Betaflight), config.rates.kind);
+        assert_eq!(Some([70; 3]), config.rates.rc_rate);
+        assert_eq!(Some([1998; 3]), config.rates.rate_limit);
+
+        let lpf1 = config.gyro.lpf1;
+        assert_eq!(Some(FilterType::Pt1), lpf1.kind);
+        assert_eq!(Some(200), lpf1.static_hz);
+        assert_eq!(Some((200, 500)), lpf1.dynamic_hz);
+        assert_eq!(Some((70, 170)), config.dterm.lpf1.dynamic_hz);
+        assert_eq!(None, config.dterm.lpf2.dynamic_hz);
+
+        assert_eq!(Some(0), config.dyn_notch.width_percent);
+        assert_eq!(None, config.dyn_notch.count);
+        assert_eq!(Some(3), config.rpm_filter.harmonics);
+        assert_eq!(Some(65), config.tpa_rate);
+        assert_eq!(Some(ItermRelax::Rp), config.iterm_relax);
+        assert_eq!(Some(ItermRelaxType::Setpoint), config.iterm_relax_type);
+
+        for header in config.consumed() {
+            assert!(headers.unknown().contains_key(header));
+            assert!(!config.unknown().contains_key(header));
+        }
+        assert_eq!(
+            headers.unknown().len(),
+            config.consumed().len() + config.unknown().len()
+        );
+        assert!(config.unknown().contains_key("dterm_rpm_notch_q"));
+    }
+
+    #[test]
+    fn inav() {
+        let mut spec = HeaderSpec::new("INAV 7.1.2 (ab3aeb4e) MATEKF405");
+        spec.slow = vec![FieldSpec {
+            name: "flightModeFlags",
+            signed: false,
+            predictor: Predictor::Zero,
+            encoding: Encoding::Variable,
+        }];
+        spec.other = vec![
+            ("motor_pwm_protocol", "0"),
+            ("rollPID", "40,50,30,60"),
+            ("pitchPID", "44,55,33,66"),
+            ("yawPID", "35,45,0,70"),
+            ("rc_rate", "100"),
+            ("rc_expo", "70"),
+            ("rc_yaw_expo", "20"),
+            ("rates", "70,70,60"),
+            ("gyro_lpf_type", "2"),
+            ("gyro_lpf_hz", "x"),
+            ("dynThrPID", "20"),
+        ];
+
+        let log = write_log(&spec, |_| {});
+        let headers = Headers::parse(&log).unwrap();
+        let config = headers.config();
+
+        assert_eq!(
+            Some(Pid {
+                p: 40,
+                i: 50,
+                d: 30
+            }),
+            config.pids.roll
+        );
+        assert_eq!(Some([60, 66, 70]), config.pids.feedforward);
+        assert_eq!(None, config.pids.d_min);
+        assert_eq!(Some([100; 3]), config.rates.rc_rate);
+        assert_eq!(Some([70, 70, 20]), config.rates.expo);
+        assert_eq!(Some(FilterType::Pt2), config.gyro.lpf1.kind);
+        assert_eq!(None, config.gyro.lpf1.static_hz);
+        assert_eq!(Some(20), config.tpa_rate);
+
+        assert!(!config.consumed().contains(&"gyro_lpf_hz"));
+        assert_eq!(Some(&"x"), config.unknown().get("gyro_lpf_hz"));
+    }
+}
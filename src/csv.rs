@@ -0,0 +1,452 @@
+//! Export of logs in the same CSV layout as `blackbox_decode`.
+//!
+//! Like `blackbox_decode`, this writes up to three files for each log:
+//!
+//! - The main CSV, with one row per main frame. The `time (us)` column follows
+//!   `loopIteration`, and the fields of the most recent slow frame are appended
+//!   to every row.
+//! - The `.event` file, with one JSON object per line for each event.
+//! - The `.gps.csv` file, with one row per GPS frame.
+//!
+//! Columns that `blackbox_decode` computes from other fields, such as
+//! `energyCumulative (mAh)`, are not included.
+//!
+//! ```no_run
+//! use std::fs::File;
+//!
+//! use blackbox_log::csv::{self, CsvOptions};
+//!
+//! # let file = b"...";
+//! # for headers in blackbox_log::File::new(file).iter() {
+//! #     let headers = headers.unwrap();
+//! let mut main = File::create("log.01.csv")?;
+//! let mut events = File::create("log.01.event")?;
+//! let mut gps = File::create("log.01.gps.csv")?;
+//!
+//! csv::write(
+//!     &headers,
+//!     &CsvOptions::default(),
+//!     &mut main,
+//!     Some(&mut events),
+//!     Some(&mut gps),
+//! )?;
+//! # }
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use alloc::borrow::ToOwned as _;
+use alloc::format;
+use alloc::string::{String, ToString as _};
+use alloc::vec::Vec;
+use std::io::{self, Write};
+
+use crate::event::{AdjustedValue, Event};
+use crate::frame::{
+    Frame, FrameDef as _, GpsUnit, GpsValue, MainConversion, MainUnit, MainValue, SlowUnit,
+    SlowValue,
+};
+use crate::units::prelude::*;
+use crate::units::{Flag as _, FlagSet};
+use crate::{FilterSet, Headers, ParserEvent};
+
+/// The separator between columns used by `blackbox_decode`.
+const SEPARATOR: &str = ", ";
+
+/// Options for [`write`].
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct CsvOptions {
+    /// Write the raw value of every field, like `blackbox_decode --raw`,
+    /// instead of converting them into the default `blackbox_decode` units.
+    ///
+    /// **Note:** Unlike `--raw`, predictors are still applied. See
+    /// [`Frame::get_raw`].
+    pub raw: bool,
+    /// Which fields to include. `blackbox_decode` always writes every field.
+    pub filters: FilterSet,
+}
+
+impl CsvOptions {
+    /// Options to write the raw value of every field. See
+    /// [`CsvOptions::raw`].
+    pub fn raw() -> Self {
+        Self {
+            raw: true,
+            ..Self::default()
+        }
+    }
+}
+
+/// Decodes the whole log, writing the main CSV to `main` and optionally the
+/// `.event` and `.gps.csv` outputs to `events` and `gps`.
+///
+/// Nothing is written to `gps` if the log cannot contain GPS frames. The slow
+/// frame columns are left empty until the first slow frame.
+pub fn write(
+    headers: &Headers,
+    options: &CsvOptions,
+    main: &mut dyn Write,
+    mut events: Option<&mut dyn Write>,
+    mut gps: Option<&mut dyn Write>,
+) -> io::Result<()> {
+    let raw = options.raw;
+    let mut parser = headers.data_parser_with_filters(&options.filters);
+
+    let main_def = parser.main_frame_def();
+    let slow_def = parser.slow_frame_def();
+    // `blackbox_decode` always places the time directly after `loopIteration`
+    let time_index = usize::from(
+        main_def
+            .get(0)
+            .is_some_and(|def| def.name == "loopIteration"),
+    );
+
+    // `blackbox_decode` does not convert motor outputs, `rcCommand`, `setpoint`,
+    // `debug` or speeds, which it writes as `eRPM[0]` or `headspeed` without a unit
+    let main_raw = main_def
+        .iter()
+        .map(|def| raw || !matches!(MainConversion::from_name(def.name, headers.debug_fields()), MainConversion::Unit(unit) if !matches!(unit, MainUnit::Motor | MainUnit::Frequency)))
+        .collect::<Vec<_>>();
+
+    let mut names = main_def
+        .iter()
+        .zip(&main_raw)
+        .map(|(def, &raw)| column_name(def.name, main_suffix(def.unit, raw)))
+        .collect::<Vec<_>>();
+    names.insert(time_index, "time (us)".to_owned());
+    names.extend(
+        slow_def
+            .iter()
+            .map(|def| column_name(def.name, slow_suffix(def.unit, raw))),
+    );
+    write_row(main, &names)?;
+
+    let gps_def = parser.gps_frame_def();
+    if let Some(gps_def) = &gps_def {
+        if let Some(gps) = &mut gps {
+            let mut names = Vec::with_capacity(gps_def.len() + 1);
+            names.push("time (us)".to_owned());
+            names.extend(
+                gps_def
+                    .iter()
+                    .map(|def| column_name(def.name, gps_suffix(def.unit, raw))),
+            );
+            write_row(gps, &names)?;
+        }
+    }
+
+    let slow_signed = slow_def.iter().map(|def| def.signed).collect::<Vec<_>>();
+    let mut slow_row = alloc::vec![String::new(); slow_signed.len()];
+    let main_signed = parser
+        .main_frame_def()
+        .iter()
+        .map(|def| def.signed)
+        .collect::<Vec<_>>();
+    let gps_signed = parser
+        .gps_frame_def()
+        .map(|def| def.iter().map(|def| def.signed).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let mut row = Vec::new();
+    while let Some(event) = parser.next() {
+        match event {
+            ParserEvent::Event(event) => {
+                if let Some(out) = &mut events {
+                    writeln!(out, "{}", event_json(&event))?;
+                }
+            }
+            ParserEvent::Main(frame) => {
+                row.clear();
+                row.extend(values(&frame, &main_signed, |i| main_raw[i], format_main));
+                row.insert(time_index, frame.time_raw().to_string());
+                row.extend_from_slice(&slow_row);
+                write_row(main, &row)?;
+            }
+            ParserEvent::Slow(frame) => {
+                slow_row.clear();
+                slow_row.extend(values(&frame, &slow_signed, |_| raw, format_slow));
+            }
+            ParserEvent::Gps(frame) => {
+                if let Some(out) = &mut gps {
+                    row.clear();
+                    row.push(frame.time_raw().to_string());
+                    row.extend(values(&frame, &gps_signed, |_| raw, format_gps));
+                    write_row(out, &row)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn write_row(out: &mut dyn Write, row: &[String]) -> io::Result<()> {
+    writeln!(out, "{}", row.join(SEPARATOR))
+}
+
+fn column_name(name: &str, suffix: Option<&str>) -> String {
+    suffix.map_or_else(|| name.to_owned(), |suffix| format!("{name} ({suffix})"))
+}
+
+fn values<'f, F: Frame>(
+    frame: &'f F,
+    signed: &'f [bool],
+    raw: impl Fn(usize) -> bool + 'f,
+    format: fn(F::Value) -> String,
+) -> impl Iterator<Item = String> + 'f {
+    (0..frame.len()).map(move |i| {
+        if raw(i) {
+            let value = frame.get_raw(i).unwrap();
+            if signed[i] {
+                value.cast_signed().to_string()
+            } else {
+                value.to_string()
+            }
+        } else {
+            format(frame.get(i).unwrap())
+        }
+    })
+}
+
+fn main_suffix(unit: MainUnit, raw: bool) -> Option<&'static str> {
+    if raw {
+        return None;
+    }
+
+    match unit {
+        MainUnit::Amperage => Some("A"),
+        MainUnit::Voltage => Some("V"),
+        MainUnit::Acceleration => Some("g"),
+        MainUnit::Rotation => Some("deg/s"),
+        MainUnit::Ratio => Some("%"),
+        MainUnit::Duration => Some("us"),
+        MainUnit::Frequency | MainUnit::Motor | MainUnit::Stick | MainUnit::Unitless => None,
+    }
+}
+
+fn format_main(value: MainValue) -> String {
+    match value {
+        MainValue::Amperage(current) => format!("{:.3}", current.get::<ampere>()),
+        MainValue::Voltage(voltage) => format!("{:.3}", voltage.get::<volt>()),
+        MainValue::Acceleration(accel) => format!("{:.2}", accel.get::<standard_gravity>()),
+        MainValue::Rotation(rotation) => format!("{:.2}", rotation.get::<degree_per_second>()),
+        MainValue::Frequency(frequency) => format!("{:.0}", frequency.get::<cycle_per_minute>()),
+        MainValue::Motor(motor) => format!("{:.1}", motor.get::<percent>()),
+        MainValue::Stick(stick) => format!("{:.3}", stick.deflection),
+        MainValue::Ratio(fraction) => format!("{:.1}", fraction.get::<percent>()),
+        MainValue::Duration(duration) => format!("{:.0}", duration.get::<microsecond>()),
+        MainValue::Unsigned(x) => x.to_string(),
+        MainValue::Signed(x) => x.to_string(),
+    }
+}
+
+fn slow_suffix(unit: SlowUnit, raw: bool) -> Option<&'static str> {
+    if raw {
+        return None;
+    }
+
+    match unit {
+        SlowUnit::FlightMode | SlowUnit::State | SlowUnit::FailsafePhase => Some("flags"),
+        SlowUnit::Boolean | SlowUnit::Unitless => None,
+    }
+}
+
+fn format_slow(value: SlowValue) -> String {
+    match value {
+        SlowValue::FlightMode(flags) => format_flags(&flags),
+        SlowValue::State(flags) => format_flags(&flags),
+        SlowValue::FailsafePhase(phase) => phase.as_name().to_owned(),
+        SlowValue::Boolean(b) => u8::from(b).to_string(),
+        SlowValue::Unsigned(x) => x.to_string(),
+        SlowValue::Signed(x) => x.to_string(),
+    }
+}
+
+/// Formats a set of flags like `blackbox_decode`, which writes `0` if no flags
+/// are set.
+fn format_flags<F: FlagSet>(flags: &F) -> String {
+    let names = flags.as_names();
+    if names.is_empty() {
+        "0".to_owned()
+    } else {
+        names.join("|")
+    }
+}
+
+fn gps_suffix(unit: GpsUnit, raw: bool) -> Option<&'static str> {
+    if raw {
+        return None;
+    }
+
+    match unit {
+        GpsUnit::Altitude => Some("m"),
+        GpsUnit::Velocity => Some("m/s"),
+        GpsUnit::Coordinate | GpsUnit::Heading | GpsUnit::Unitless => None,
+    }
+}
+
+fn format_gps(value: GpsValue) -> String {
+    match value {
+        GpsValue::Coordinate(degrees) => format!("{degrees:.7}"),
+        GpsValue::Altitude(altitude) => format!("{}", altitude.get::<meter>()),
+        GpsValue::Velocity(velocity) => format!("{:.2}", velocity.get::<meter_per_second>()),
+        GpsValue::Heading(degrees) => format!("{degrees:.1}"),
+        GpsValue::Unsigned(x) => x.to_string(),
+        GpsValue::Signed(x) => x.to_string(),
+    }
+}
+
+/// Formats an event as one line of a `blackbox_decode` `.event` file.
+fn event_json(event: &Event) -> String {
+    match event {
+        Event::SyncBeep(time) => format!(r#"{{"name":"Sync beep", "time":{time}}}"#),
+        Event::InflightAdjustment {
+            function,
+            new_value,
+        } => {
+            let value = match new_value {
+                AdjustedValue::Float(x) => format!(r#""newFloatValue":{x:.6}"#),
+                AdjustedValue::Int(x) => format!(r#""newValue":{x}"#),
+            };
+
+            format!(
+                r#"{{"name":"Inflight adjustment", "data":{{"adjustmentFunction":{function}, {value}}}}}"#
+            )
+        }
+        Event::Resume {
+            log_iteration,
+            time,
+        } => format!(
+            r#"{{"name":"Logging resume", "data":{{"logIteration":{log_iteration}}}, "time":{time}}}"#
+        ),
+        Event::Disarm(reason) => format!(r#"{{"name":"Disarm", "data":{{"reason":{reason}}}}}"#),
+        Event::FlightMode { flags, last_flags } => format!(
+            r#"{{"name":"Flight mode", "data":{{"newFlags":{flags}, "lastFlags":{last_flags}}}}}"#
+        ),
+        Event::ImuFailure { error } => {
+            format!(r#"{{"name":"IMU failure", "data":{{"error":{error}}}}}"#)
+        }
+        Event::End { disarm_reason } => disarm_reason.map_or_else(
+            || r#"{"name":"Log clean end"}"#.to_owned(),
+            |reason| format!(r#"{{"name":"Log clean end", "data":{{"disarmReason":{reason}}}}}"#),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static LOG: &[u8] = include_bytes!("../tests/logs/error-recovery.bbl");
+
+    fn export(options: &CsvOptions) -> (Vec<String>, Vec<String>) {
+        let headers = Headers::parse(LOG).unwrap();
+        let (mut main, mut events) = (Vec::new(), Vec::new());
+        write(&headers, options, &mut main, Some(&mut events), None).unwrap();
+
+        let lines = |bytes: Vec<u8>| {
+            String::from_utf8(bytes)
+                .unwrap()
+                .lines()
+                .map(str::to_owned)
+                .collect()
+        };
+        (lines(main), lines(events))
+    }
+
+    #[test]
+    fn column_names() {
+        let (main, _) = export(&CsvOptions::default());
+        let names = main[0].split(SEPARATOR).collect::<Vec<_>>();
+
+        assert_eq!(["loopIteration", "time (us)"], names[..2]);
+        assert!(names.contains(&"gyroADC[0] (deg/s)"));
+        assert!(names.contains(&"vbatLatest (V)"));
+        assert!(names.contains(&"flightModeFlags (flags)"));
+        assert!(names.contains(&"stateFlags (flags)"));
+        assert!(names.contains(&"failsafePhase (flags)"));
+        assert!(names.contains(&"rxSignalReceived"));
+
+        let (raw, _) = export(&CsvOptions::raw());
+        let raw = raw[0].split(SEPARATOR).collect::<Vec<_>>();
+        assert_eq!(names.len(), raw.len());
+        assert!(raw.contains(&"gyroADC[0]"));
+        assert!(raw.contains(&"vbatLatest"));
+        assert!(raw.contains(&"flightModeFlags"));
+    }
+
+    #[test]
+    fn rotorflight_header_line() {
+        let log = include_bytes!("../tests/logs/synthetic-rotorflight-4.3.bbl");
+        let headers = Headers::parse(log).unwrap();
+        let mut main = Vec::new();
+        write(&headers, &CsvOptions::default(), &mut main, None, None).unwrap();
+        let main = String::from_utf8(main).unwrap();
+
+        // Speeds are written as raw rpm, like `blackbox_decode` does for
+        // `eRPM[0]`
+        assert_eq!(
+            Some(
+                "loopIteration, time (us), axisP[0], axisP[1], axisP[2], gyroADC[0] (deg/s), \
+                 gyroADC[1] (deg/s), gyroADC[2] (deg/s), motor[0], motor[1], headspeed, \
+                 tailspeed, flightModeFlags (flags), stateFlags (flags), failsafePhase (flags), \
+                 rxSignalReceived, rxFlightChannelsValid"
+            ),
+            main.lines().next()
+        );
+
+        let first = main
+            .lines()
+            .nth(1)
+            .unwrap()
+            .split(SEPARATOR)
+            .collect::<Vec<_>>();
+        assert_eq!(["1800", "5400"], first[10..12]);
+    }
+
+    #[test]
+    fn rows() {
+        let headers = Headers::parse(LOG).unwrap();
+        let mut parser = headers.data_parser();
+        let (mut frames, mut event_count) = (0, 0);
+        while let Some(event) = parser.next() {
+            match event {
+                ParserEvent::Main(_) => frames += 1,
+                ParserEvent::Event(_) => event_count += 1,
+                ParserEvent::Slow(_) | ParserEvent::Gps(_) => {}
+            }
+        }
+
+        for options in [CsvOptions::default(), CsvOptions::raw()] {
+            let (main, events) = export(&options);
+            assert_eq!(frames + 1, main.len());
+            assert_eq!(event_count, events.len());
+
+            let columns = main[0].split(SEPARATOR).count();
+            assert!(main
+                .iter()
+                .all(|row| row.split(SEPARATOR).count() == columns));
+        }
+    }
+
+    #[test]
+    fn events() {
+        assert_eq!(
+            r#"{"name":"Sync beep", "time":1234}"#,
+            event_json(&Event::SyncBeep(1234))
+        );
+        assert_eq!(
+            r#"{"name":"Inflight adjustment", "data":{"adjustmentFunction":3, "newValue":-5}}"#,
+            event_json(&Event::InflightAdjustment {
+                function: 3,
+                new_value: AdjustedValue::Int(-5),
+            })
+        );
+        assert_eq!(
+            r#"{"name":"Log clean end"}"#,
+            event_json(&Event::End {
+                disarm_reason: None
+            })
+        );
+    }
+}
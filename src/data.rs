@@ -1,11 +1,19 @@
 //! Types for the data section of blackbox logs.
 
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::ops::Range;
+
 use crate::event::Event;
 use crate::filter::AppliedFilter;
-use crate::frame::gps::{GpsFrame, RawGpsFrame};
-use crate::frame::main::{MainFrame, RawMainFrame};
-use crate::frame::slow::{RawSlowFrame, SlowFrame};
-use crate::frame::{self, DataFrameKind, FilteredFrameDef, FrameKind, GpsHomeFrame};
+use crate::frame::gps::{GpsFrame, OwnedGpsFrame, RawGpsFrame};
+use crate::frame::main::{MainFrame, OwnedMainFrame, RawMainFrame};
+use crate::frame::slow::{OwnedSlowFrame, RawSlowFrame, SlowFrame};
+use crate::frame::{
+    self, DataFrameKind, FilteredFrameDef, FrameContext, FrameKind, GpsHomeFrame, GpsPosition,
+};
+use crate::index::{FrameIndex, SeekTarget};
 use crate::parser::InternalError;
 use crate::{FilterSet, Headers, Reader};
 
@@ -13,15 +21,9 @@
 #[derive(Debug)]
 pub struct DataParser<'data, 'headers> {
     headers: &'headers Headers<'data>,
-    main_filter: AppliedFilter,
-    slow_filter: AppliedFilter,
-    gps_filter: AppliedFilter,
+    state: ParserState,
     data: Reader<'data>,
     data_len: usize,
-    stats: Stats,
-    main_frames: MainFrameHistory,
-    gps_home_frame: Option<GpsHomeFrame>,
-    done: bool,
 }
 
 impl<'data, 'headers> DataParser<'data, 'headers> {
@@ -34,87 +36,471 @@ pub(crate) fn new(
 
         Self {
             headers,
-            main_filter: filters.main.apply(headers.main_frame_def()),
-            slow_filter: filters.slow.apply(headers.slow_frame_def()),
-            gps_filter: headers
-                .gps_frame_def()
-                .map(|def| filters.gps.apply(def))
-                .unwrap_or_default(),
+            state: ParserState::new(headers, filters),
             data,
             data_len,
-            stats: Stats::default(),
-            main_frames: MainFrameHistory::default(),
-            gps_home_frame: None,
-            done: false,
         }
     }
 
+    /// Creates a new parser that starts at the last intraframe at or before
+    /// `target`, or at the start of the data section if there is none.
+    ///
+    /// `index` must have been built from the same log as `headers`. See the
+    /// [`index`][crate::index] module for an example.
+    ///
+    /// **Note:** [`stats`][Self::stats] only include frames from after the
+    /// starting point.
+    pub fn starting_at(
+        headers: &'headers Headers<'data>,
+        filters: &FilterSet,
+        index: &FrameIndex,
+        target: SeekTarget,
+    ) -> Self {
+        let mut parser = headers.data_parser_with_filters(filters);
+
+        if let Some(entry) = index.find(target) {
+            let skip = entry.offset.saturating_sub(headers.data_offset());
+            parser.data.read_n_bytes(skip);
+            parser.data_len = parser.data.remaining();
+            parser.state.seek(
+                entry.offset,
+                MainFrameHistory::starting_at(entry.time),
+                index.gps_home_before(entry.offset),
+            );
+        }
+
+        parser
+    }
+
     pub fn main_frame_def<'a>(&'a self) -> FilteredFrameDef<'a, frame::MainFrameDef<'data>> {
-        FilteredFrameDef::new(self.headers.main_frame_def(), &self.main_filter)
+        self.state.main_frame_def(self.headers)
     }
 
     pub fn slow_frame_def<'a>(&'a self) -> FilteredFrameDef<'a, frame::SlowFrameDef<'data>> {
-        FilteredFrameDef::new(self.headers.slow_frame_def(), &self.slow_filter)
+        self.state.slow_frame_def(self.headers)
     }
 
     pub fn gps_frame_def<'a>(&'a self) -> Option<FilteredFrameDef<'a, frame::GpsFrameDef<'data>>> {
-        self.headers
-            .gps_frame_def()
-            .map(|def| FilteredFrameDef::new(def, &self.gps_filter))
+        self.state.gps_frame_def(self.headers)
     }
 
     /// Returns the current stats.
     #[inline]
     pub fn stats(&self) -> &Stats {
-        &self.stats
+        &self.state.stats
     }
 
     /// Returns `true` if the parser has reached the end of the log.
     #[inline]
     pub fn is_done(&self) -> bool {
-        self.done
+        self.state.done
+    }
+
+    /// Returns where the last event or frame returned by [`next`][Self::next]
+    /// was found, as a range of offsets from the start of the log.
+    ///
+    /// Use this to locate an [`Event`]. Frames and [`OwnedParserEvent`]s also
+    /// include their own location.
+    #[inline]
+    pub fn last_span(&self) -> Option<Range<usize>> {
+        self.state.last_span()
     }
 
     /// Continues parsing until the next [`ParserEvent`] can be returned.
     /// Returns `None` if the parser finds the end of the log.
     pub fn next<'parser>(&'parser mut self) -> Option<ParserEvent<'data, 'headers, 'parser>> {
+        loop {
+            let step = self.state.parse_next(&mut self.data, self.headers, true);
+
+            self.state.stats.progress =
+                1. - ((self.data.remaining() as f32) / (self.data_len as f32));
+
+            match step {
+                Step::Frame(InternalFrame::GpsHome(gps_home), _) => {
+                    self.state.set_gps_home(gps_home);
+                }
+                Step::Frame(frame, span) => {
+                    return Some(self.state.emit(self.headers, frame, span))
+                }
+                Step::Incomplete | Step::Eof => return None,
+            }
+        }
+    }
+
+    /// Like [`next`][Self::next], but returns an [`OwnedParserEvent`] that
+    /// does not borrow the parser.
+    pub fn next_owned(&mut self) -> Option<OwnedParserEvent> {
+        match self.next()?.into_owned_frame() {
+            Ok(frame) => Some(frame),
+            Err(event) => Some(OwnedParserEvent::Event {
+                event,
+                span: self.last_span()?,
+            }),
+        }
+    }
+
+    /// Converts the parser into an [`Iterator`] over [`OwnedParserEvent`]s,
+    /// which do not borrow the parser.
+    ///
+    /// This copies the values of every frame, so prefer
+    /// [`next`][Self::next] unless the frames need to outlive the parser.
+    #[inline]
+    pub fn into_owned_events(self) -> OwnedEvents<'data, 'headers> {
+        OwnedEvents(self)
+    }
+}
+
+/// An iterator over the [`OwnedParserEvent`]s of a log. See
+/// [`DataParser::into_owned_events`].
+#[derive(Debug)]
+pub struct OwnedEvents<'data, 'headers>(DataParser<'data, 'headers>);
+
+impl<'data, 'headers> OwnedEvents<'data, 'headers> {
+    /// Returns the wrapped parser, eg to check its
+    /// [`stats`][DataParser::stats].
+    #[inline]
+    pub fn parser(&self) -> &DataParser<'data, 'headers> {
+        &self.0
+    }
+}
+
+impl Iterator for OwnedEvents<'_, '_> {
+    type Item = OwnedParserEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next_owned()
+    }
+}
+
+impl FusedIterator for OwnedEvents<'_, '_> {}
+
+/// A push-based parser for the data section of blackbox logs, for when the log
+/// arrives in arbitrarily sized chunks, eg from a serial port.
+///
+/// Data is passed in using [`PushParser::feed`], after which any complete
+/// frames can be drained using [`PushParser::next`]. Just like
+/// [`DataParser::next`], a frame is only returned once the first byte of the
+/// following frame has been checked, so the last frame of the log will only be
+/// returned after the end of the log is found or [`PushParser::finish`] is
+/// called.
+///
+/// ```
+/// # let file = b"...";
+/// # for headers in blackbox_log::File::new(file).iter() {
+/// #     let headers = headers.unwrap();
+/// # let chunks: [&[u8]; 0] = [];
+/// let mut parser = headers.push_parser();
+///
+/// for chunk in chunks {
+///     parser.feed(chunk);
+///     while let Some(event) = parser.next() {
+///         // ...
+///     }
+/// }
+///
+/// parser.finish();
+/// while let Some(event) = parser.next() {
+///     // ...
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct PushParser<'data, 'headers> {
+    headers: &'headers Headers<'data>,
+    state: ParserState,
+    buffer: Vec<u8>,
+    /// Index of the next unparsed byte in `buffer`
+    position: usize,
+    finished: bool,
+}
+
+impl<'data, 'headers> PushParser<'data, 'headers> {
+    pub(crate) fn new(headers: &'headers Headers<'data>, filters: &FilterSet) -> Self {
+        Self {
+            headers,
+            state: ParserState::new(headers, filters),
+            buffer: Vec::new(),
+            position: 0,
+            finished: false,
+        }
+    }
+
+    pub fn main_frame_def<'a>(&'a self) -> FilteredFrameDef<'a, frame::MainFrameDef<'data>> {
+        self.state.main_frame_def(self.headers)
+    }
+
+    pub fn slow_frame_def<'a>(&'a self) -> FilteredFrameDef<'a, frame::SlowFrameDef<'data>> {
+        self.state.slow_frame_def(self.headers)
+    }
+
+    pub fn gps_frame_def<'a>(&'a self) -> Option<FilteredFrameDef<'a, frame::GpsFrameDef<'data>>> {
+        self.state.gps_frame_def(self.headers)
+    }
+
+    /// Returns the current stats.
+    ///
+    /// **Note:** Since the total length of the log is unknown,
+    /// [`Stats::progress`] is only updated once the end of the log is found.
+    #[inline]
+    pub fn stats(&self) -> &Stats {
+        &self.state.stats
+    }
+
+    /// Returns `true` if the parser has reached the end of the log.
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.state.done
+    }
+
+    /// Returns where the last event or frame returned by [`next`][Self::next]
+    /// was found, as a range of offsets from the start of the log.
+    ///
+    /// Use this to locate an [`Event`]. Frames and [`OwnedParserEvent`]s also
+    /// include their own location.
+    #[inline]
+    pub fn last_span(&self) -> Option<Range<usize>> {
+        self.state.last_span()
+    }
+
+    /// Returns the number of bytes that have been fed, but not yet parsed.
+    #[inline]
+    pub fn buffered(&self) -> usize {
+        self.buffer.len() - self.position
+    }
+
+    /// Appends the next chunk of the data section.
+    ///
+    /// Any data fed after [`finish`][Self::finish] is ignored.
+    pub fn feed(&mut self, data: &[u8]) {
+        if self.finished || self.state.done {
+            return;
+        }
+
+        // Only keep any partial frame left over from previous chunks
+        self.buffer.drain(..self.position);
+        self.position = 0;
+
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Marks the end of the input, allowing the final frame to be returned.
+    pub fn finish(&mut self) {
+        self.finished = true;
+    }
+
+    /// Continues parsing until the next [`ParserEvent`] can be returned.
+    ///
+    /// Returns `None` if more data needs to be [fed][Self::feed] first, or if
+    /// the end of the log has been found.
+    pub fn next<'parser>(&'parser mut self) -> Option<ParserEvent<'data, 'headers, 'parser>> {
+        loop {
+            let unread = &self.buffer[self.position..];
+            let mut data = Reader::new(unread);
+            let step = self
+                .state
+                .parse_next(&mut data, self.headers, self.finished);
+            self.position += unread.len() - data.remaining();
+
+            match step {
+                Step::Frame(InternalFrame::GpsHome(gps_home), _) => {
+                    self.state.set_gps_home(gps_home);
+                }
+                Step::Frame(frame, span) => {
+                    return Some(self.state.emit(self.headers, frame, span))
+                }
+                Step::Incomplete => return None,
+                Step::Eof => {
+                    if self.state.done {
+                        self.buffer = Vec::new();
+                        self.position = 0;
+                    }
+
+                    return None;
+                }
+            }
+        }
+    }
+
+    /// Like [`next`][Self::next], but returns an [`OwnedParserEvent`] that
+    /// does not borrow the parser.
+    pub fn next_owned(&mut self) -> Option<OwnedParserEvent> {
+        match self.next()?.into_owned_frame() {
+            Ok(frame) => Some(frame),
+            Err(event) => Some(OwnedParserEvent::Event {
+                event,
+                span: self.last_span()?,
+            }),
+        }
+    }
+}
+
+/// The parts of a data parser that are independent of where its input comes
+/// from.
+#[derive(Debug)]
+pub(crate) struct ParserState {
+    main_filter: AppliedFilter,
+    slow_filter: AppliedFilter,
+    gps_filter: AppliedFilter,
+    /// Shared with every owned frame created from this parser
+    context: Arc<FrameContext>,
+    pub(crate) stats: Stats,
+    main_frames: MainFrameHistory,
+    gps_home_frame: Option<GpsHomeFrame>,
+    /// Set while skipping past corrupted data, so resyncing can continue
+    /// across calls to [`ParserState::parse_next`]
+    corrupted: Option<Corruption>,
+    /// Where the last event or frame passed to [`ParserState::emit`] was found
+    last_span: Option<Span>,
+    /// Offset from the start of the log of the next byte to parse
+    offset: usize,
+    pub(crate) done: bool,
+}
+
+/// The result of trying to parse the next frame from a [`Reader`].
+#[derive(Debug)]
+pub(crate) enum Step {
+    /// Found a complete frame followed by a valid frame kind byte or the end of
+    /// the log.
+    Frame(InternalFrame, Span),
+    /// Reached the end of the available data before the end of the log. The
+    /// `Reader` is left at the start of the incomplete frame, or at its end if
+    /// the rest of it could only be skipped.
+    Incomplete,
+    /// Reached the end of the log.
+    Eof,
+}
+
+impl ParserState {
+    pub(crate) fn new(headers: &Headers, filters: &FilterSet) -> Self {
+        let main_filter = filters.main.apply(headers.main_frame_def());
+        let slow_filter = filters.slow.apply(headers.slow_frame_def());
+        let gps_filter = headers
+            .gps_frame_def()
+            .map(|def| filters.gps.apply(def))
+            .unwrap_or_default();
+
+        let context = FrameContext::new(
+            headers,
+            &FilteredFrameDef::new(headers.main_frame_def(), &main_filter),
+            &FilteredFrameDef::new(headers.slow_frame_def(), &slow_filter),
+            headers
+                .gps_frame_def()
+                .map(|def| FilteredFrameDef::new(def, &gps_filter))
+                .as_ref(),
+        );
+
+        Self {
+            main_filter,
+            slow_filter,
+            gps_filter,
+            context: Arc::new(context),
+            stats: Stats::default(),
+            main_frames: MainFrameHistory::default(),
+            gps_home_frame: None,
+            corrupted: None,
+            last_span: None,
+            offset: headers.data_offset(),
+            done: false,
+        }
+    }
+
+    pub(crate) fn main_frame_def<'a, 'data>(
+        &'a self,
+        headers: &'a Headers<'data>,
+    ) -> FilteredFrameDef<'a, frame::MainFrameDef<'data>> {
+        FilteredFrameDef::new(headers.main_frame_def(), &self.main_filter)
+    }
+
+    pub(crate) fn slow_frame_def<'a, 'data>(
+        &'a self,
+        headers: &'a Headers<'data>,
+    ) -> FilteredFrameDef<'a, frame::SlowFrameDef<'data>> {
+        FilteredFrameDef::new(headers.slow_frame_def(), &self.slow_filter)
+    }
+
+    pub(crate) fn gps_frame_def<'a, 'data>(
+        &'a self,
+        headers: &'a Headers<'data>,
+    ) -> Option<FilteredFrameDef<'a, frame::GpsFrameDef<'data>>> {
+        headers
+            .gps_frame_def()
+            .map(|def| FilteredFrameDef::new(def, &self.gps_filter))
+    }
+
+    /// Parses frames from `data` until one is found that can be returned, or
+    /// until the end of `data`.
+    ///
+    /// If `complete` is `false`, more of the log may follow the end of `data`,
+    /// so any frame that is cut off or cannot be checked against the following
+    /// frame kind byte is left unparsed.
+    pub(crate) fn parse_next(
+        &mut self,
+        data: &mut Reader,
+        headers: &Headers,
+        complete: bool,
+    ) -> Step {
+        let remaining = data.remaining();
+        let step = self.parse_next_from(data, headers, complete);
+        self.offset += remaining - data.remaining();
+        step
+    }
+
+    fn parse_next_from(&mut self, data: &mut Reader, headers: &Headers, complete: bool) -> Step {
         if self.done {
-            return None;
+            return Step::Eof;
         }
 
+        let (base, remaining) = (self.offset, data.remaining());
+        let offset = |data: &Reader| base + remaining - data.remaining();
+
         loop {
-            let byte = self.data.read_u8()?;
-            let restore = self.data.get_restore_point();
+            if self.corrupted.is_some() && skip_to_frame(data) {
+                self.end_corruption(offset(data));
+            }
+
+            let start = data.get_restore_point();
+            let start_offset = offset(data);
+
+            let Some(byte) = data.read_u8() else {
+                return if complete {
+                    self.end_corruption(start_offset);
+                    Step::Eof
+                } else {
+                    Step::Incomplete
+                };
+            };
+            let restore = data.get_restore_point();
 
             let Some(kind) = FrameKind::from_byte(byte) else {
-                skip_to_frame(&mut self.data);
+                self.start_corruption(None, start_offset, CorruptionReason::InvalidFrameKind);
                 continue;
             };
 
             tracing::trace!("trying to parse {kind:?} frame");
 
             let result = match kind {
-                FrameKind::Event => Event::parse(&mut self.data).map(InternalFrame::Event),
+                FrameKind::Event => {
+                    Event::parse(data, self.main_frames.last_time()).map(InternalFrame::Event)
+                }
                 FrameKind::Data(DataFrameKind::Intra | DataFrameKind::Inter) => {
-                    RawMainFrame::parse(&mut self.data, self.headers, kind, &self.main_frames)
+                    RawMainFrame::parse(data, headers, kind, &self.main_frames)
                         .map(InternalFrame::Main)
                 }
-                FrameKind::Data(DataFrameKind::Slow) => self
-                    .headers
+                FrameKind::Data(DataFrameKind::Slow) => headers
                     .slow_frame_def()
-                    .parse(&mut self.data, self.headers)
+                    .parse(data, headers, self.main_frames.last_time())
                     .map(InternalFrame::Slow),
                 FrameKind::Data(DataFrameKind::Gps) => {
-                    self.headers.gps_frame_def().as_ref().map_or_else(
+                    headers.gps_frame_def().as_ref().map_or_else(
                         || {
                             tracing::debug!("found GPS frame without GPS frame definition");
                             Err(InternalError::Retry)
                         },
                         |gps| {
                             gps.parse(
-                                &mut self.data,
-                                self.headers,
-                                self.main_frames.last().map(|frame| frame.time),
+                                data,
+                                headers,
+                                self.main_frames.last_time(),
                                 self.gps_home_frame.as_ref(),
                             )
                             .map(InternalFrame::Gps)
@@ -122,7 +508,7 @@ pub fn next<'parser>(&'parser mut self) -> Option<ParserEvent<'data, 'headers, '
                     )
                 }
                 FrameKind::Data(DataFrameKind::GpsHome) => {
-                    self.headers.gps_home_frame_def().as_ref().map_or_else(
+                    headers.gps_home_frame_def().as_ref().map_or_else(
                         || {
                             tracing::debug!(
                                 "found GPS home frame without GPS home frame definition"
@@ -131,75 +517,192 @@ pub fn next<'parser>(&'parser mut self) -> Option<ParserEvent<'data, 'headers, '
                         },
                         |gps_home| {
                             gps_home
-                                .parse(&mut self.data, self.headers)
+                                .parse(data, headers, self.main_frames.last_time())
                                 .map(InternalFrame::GpsHome)
                         },
                     )
                 }
             };
 
-            self.stats.progress = 1. - ((self.data.remaining() as f32) / (self.data_len as f32));
-
             match result {
+                Ok(_) if !complete && data.peek().is_none() => {
+                    data.restore(start);
+                    return Step::Incomplete;
+                }
                 // Check for a good frame kind byte, or EOF
                 Ok(frame)
-                    if self
-                        .data
+                    if data
                         .peek()
                         .is_none_or(|byte| FrameKind::from_byte(byte).is_some()) =>
                 {
-                    match frame {
-                        InternalFrame::Event(event) => {
-                            if matches!(event, Event::End { .. }) {
-                                self.done = true;
-                                self.stats.progress = 1.;
-                            }
-
-                            self.stats.counts.event += 1;
-                            return Some(ParserEvent::Event(event));
-                        }
-                        InternalFrame::Main(main) => {
-                            self.stats.counts.main += 1;
-                            let main = self.main_frames.push(main);
-
-                            return Some(ParserEvent::Main(MainFrame::new(
-                                self.headers,
-                                main,
-                                &self.main_filter,
-                            )));
-                        }
-                        InternalFrame::Slow(slow) => {
-                            self.stats.counts.slow += 1;
-                            return Some(ParserEvent::Slow(SlowFrame::new(
-                                self.headers,
-                                slow,
-                                &self.slow_filter,
-                            )));
-                        }
-                        InternalFrame::Gps(gps) => {
-                            self.stats.counts.gps += 1;
-                            return Some(ParserEvent::Gps(GpsFrame::new(
-                                self.headers,
-                                gps,
-                                &self.gps_filter,
-                            )));
-                        }
-                        InternalFrame::GpsHome(gps_home) => {
-                            self.stats.counts.gps_home += 1;
-                            self.gps_home_frame = Some(gps_home);
-                            continue;
-                        }
-                    }
+                    let span = Span {
+                        offset: start_offset,
+                        size: offset(data) - start_offset,
+                    };
+                    return Step::Frame(frame, span);
                 }
                 Ok(_) | Err(InternalError::Retry) => {
                     tracing::debug!("found corrupted {kind:?} frame");
-                    self.data.restore(restore);
-                    skip_to_frame(&mut self.data);
+                    data.restore(restore);
+                    self.start_corruption(Some(kind), start_offset, CorruptionReason::InvalidFrame);
+                }
+                Err(InternalError::Eof) if !complete => {
+                    data.restore(start);
+                    return Step::Incomplete;
                 }
                 Err(InternalError::Eof) => {
                     tracing::debug!("found unexpected end of file in data section");
-                    return None;
+                    self.start_corruption(
+                        Some(kind),
+                        start_offset,
+                        CorruptionReason::UnexpectedEof,
+                    );
+                    self.end_corruption(base + remaining);
+                    return Step::Eof;
+                }
+            }
+        }
+    }
+
+    /// Starts skipping corrupted data, unless already skipping.
+    fn start_corruption(
+        &mut self,
+        kind: Option<FrameKind>,
+        offset: usize,
+        reason: CorruptionReason,
+    ) {
+        self.corrupted.get_or_insert(Corruption {
+            kind,
+            offset,
+            skipped: 0,
+            reason,
+        });
+    }
+
+    /// Records the current corrupted region as ending at `end`, if any.
+    fn end_corruption(&mut self, end: usize) {
+        if let Some(mut corruption) = self.corrupted.take() {
+            corruption.skipped = end - corruption.offset;
+            tracing::debug!(?corruption, "skipped corrupted data");
+            self.stats.push_corruption(corruption);
+        }
+    }
+
+    /// Resets the state to start parsing at `offset`, which must be the start
+    /// of a frame.
+    pub(crate) fn seek(
+        &mut self,
+        offset: usize,
+        main_frames: MainFrameHistory,
+        gps_home: Option<GpsPosition>,
+    ) {
+        self.main_frames = main_frames;
+        self.gps_home_frame = gps_home.map(GpsHomeFrame);
+        self.corrupted = None;
+        self.last_span = None;
+        self.offset = offset;
+    }
+
+    /// Returns the bytes of the last event or frame passed to
+    /// [`ParserState::emit`].
+    #[inline]
+    pub(crate) fn last_span(&self) -> Option<Range<usize>> {
+        self.last_span
+            .map(|span| span.offset..(span.offset + span.size))
+    }
+
+    /// Returns the offset from the start of the log of the next byte to parse.
+    #[inline]
+    #[cfg_attr(not(feature = "rayon"), expect(dead_code))]
+    pub(crate) fn offset(&self) -> usize {
+        self.offset
+    }
+
+    #[inline]
+    #[cfg_attr(not(feature = "rayon"), expect(dead_code))]
+    pub(crate) fn main_frames(&self) -> &MainFrameHistory {
+        &self.main_frames
+    }
+
+    /// Records a main frame as the most recent one, to be used to predict the
+    /// following frames.
+    #[cfg_attr(not(feature = "rayon"), expect(dead_code))]
+    pub(crate) fn push_main(&mut self, main: RawMainFrame) {
+        self.count_main(&main);
+        self.main_frames.push(main);
+    }
+
+    fn count_main(&mut self, main: &RawMainFrame) {
+        if let Some(last) = self.main_frames.last() {
+            let rollovers = (main.time >> 32).saturating_sub(last.time >> 32);
+            self.stats.time_rollovers += usize::try_from(rollovers).unwrap_or(usize::MAX);
+        }
+
+        self.stats.counts.main += 1;
+    }
+
+    pub(crate) fn set_gps_home(&mut self, gps_home: GpsHomeFrame) {
+        self.stats.counts.gps_home += 1;
+        self.gps_home_frame = Some(gps_home);
+    }
+
+    /// Records a parsed frame and converts it into a [`ParserEvent`].
+    ///
+    /// # Panics
+    ///
+    /// GPS home frames are never returned to the user and must be passed to
+    /// [`ParserState::set_gps_home`] instead.
+    pub(crate) fn emit<'data, 'headers, 'parser>(
+        &'parser mut self,
+        headers: &'headers Headers<'data>,
+        frame: InternalFrame,
+        span: Span,
+    ) -> ParserEvent<'data, 'headers, 'parser> {
+        self.last_span = Some(span);
+
+        match frame {
+            InternalFrame::Event(event) => {
+                if matches!(event, Event::End { .. }) {
+                    self.done = true;
+                    self.stats.progress = 1.;
                 }
+
+                self.stats.counts.event += 1;
+                ParserEvent::Event(event)
+            }
+            InternalFrame::Main(main) => {
+                self.count_main(&main);
+                let main = self.main_frames.push(main);
+                ParserEvent::Main(MainFrame::new(
+                    headers,
+                    main,
+                    &self.main_filter,
+                    &self.context,
+                    span,
+                ))
+            }
+            InternalFrame::Slow(slow) => {
+                self.stats.counts.slow += 1;
+                ParserEvent::Slow(SlowFrame::new(
+                    headers,
+                    slow,
+                    &self.slow_filter,
+                    &self.context,
+                    span,
+                ))
+            }
+            InternalFrame::Gps(gps) => {
+                self.stats.counts.gps += 1;
+                ParserEvent::Gps(GpsFrame::new(
+                    headers,
+                    gps,
+                    &self.gps_filter,
+                    &self.context,
+                    span,
+                ))
+            }
+            InternalFrame::GpsHome(_) => {
+                unreachable!("GPS home frames must be passed to `set_gps_home`")
             }
         }
     }
@@ -207,12 +710,31 @@ pub fn next<'parser>(&'parser mut self) -> Option<ParserEvent<'data, 'headers, '
 
 /// Statistics about a decoded log.
 #[derive(Debug, Clone, Default)]
-#[cfg_attr(feature = "_serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct Stats {
     /// The number of valid frames found of each type.
     pub counts: FrameCounts,
 
+    /// The number of times the 32 bit microsecond time counter written in the
+    /// log was found to have rolled over.
+    pub time_rollovers: usize,
+
+    /// The number of regions of the data section that could not be parsed and
+    /// were skipped.
+    pub corrupted_regions: usize,
+
+    /// The total number of bytes skipped in corrupted regions.
+    pub corrupted_bytes: usize,
+
+    /// The first [`Stats::MAX_CORRUPTIONS`] corrupted regions, in order.
+    ///
+    /// Any after those are only included in
+    /// [`corrupted_regions`][Self::corrupted_regions] and
+    /// [`corrupted_bytes`][Self::corrupted_bytes], so badly corrupted logs
+    /// cannot use an unbounded amount of memory.
+    pub corruptions: Vec<Corruption>,
+
     /// The approximate percentage of the log data parsed so far as a number in
     /// the range `0..=1`.
     ///
@@ -221,8 +743,34 @@ pub struct Stats {
     pub progress: f32,
 }
 
+impl Stats {
+    /// The maximum number of regions kept in [`Stats::corruptions`].
+    pub const MAX_CORRUPTIONS: usize = 64;
+
+    fn push_corruption(&mut self, corruption: Corruption) {
+        self.corrupted_regions += 1;
+        self.corrupted_bytes += corruption.skipped;
+
+        if self.corruptions.len() < Self::MAX_CORRUPTIONS {
+            self.corruptions.push(corruption);
+        }
+    }
+
+    /// Adds the corrupted regions from `other`, which must come after any
+    /// already recorded.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn append_corruptions(&mut self, other: Self) {
+        self.corrupted_regions += other.corrupted_regions;
+        self.corrupted_bytes += other.corrupted_bytes;
+
+        let room = Self::MAX_CORRUPTIONS.saturating_sub(self.corruptions.len());
+        self.corruptions
+            .extend(other.corruptions.into_iter().take(room));
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default)]
-#[cfg_attr(feature = "_serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FrameCounts {
     pub event: usize,
     pub main: usize,
@@ -231,6 +779,36 @@ pub struct FrameCounts {
     pub gps_home: usize,
 }
 
+/// A region of the data section that could not be parsed and was skipped.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Corruption {
+    /// The kind of frame that failed to parse, or `None` if the first byte was
+    /// not a valid frame kind.
+    pub kind: Option<FrameKind>,
+    /// The offset of the first skipped byte from the start of the log.
+    pub offset: usize,
+    /// The number of bytes skipped before the start of the next frame or the
+    /// end of the log.
+    pub skipped: usize,
+    pub reason: CorruptionReason,
+}
+
+/// Why a [`Corruption`] was skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum CorruptionReason {
+    /// The first byte was not a valid frame kind.
+    InvalidFrameKind,
+    /// The frame could not be decoded, or was not followed by the start of
+    /// another frame.
+    InvalidFrame,
+    /// The log ended partway through the frame.
+    UnexpectedEof,
+}
+
 /// An item parsed from the data section of a blackbox log.
 ///
 /// See [`DataParser::next`].
@@ -242,9 +820,46 @@ pub enum ParserEvent<'data, 'headers, 'parser> {
     Gps(GpsFrame<'data, 'headers, 'parser>),
 }
 
+impl ParserEvent<'_, '_, '_> {
+    /// Copies a frame so it no longer borrows the parser or headers. Events are
+    /// returned as is, since only the parser knows where they were found.
+    pub(crate) fn into_owned_frame(self) -> Result<OwnedParserEvent, Event> {
+        match self {
+            Self::Event(event) => Err(event),
+            Self::Main(main) => Ok(OwnedParserEvent::Main(main.into_owned())),
+            Self::Slow(slow) => Ok(OwnedParserEvent::Slow(slow.into_owned())),
+            Self::Gps(gps) => Ok(OwnedParserEvent::Gps(gps.into_owned())),
+        }
+    }
+}
+
+/// An owned copy of a [`ParserEvent`] that can be stored or sent to another
+/// thread. See [`DataParser::next_owned`].
+#[derive(Debug, Clone)]
+pub enum OwnedParserEvent {
+    Event {
+        event: Event,
+        /// Where the event was found, as a range of offsets from the start of
+        /// the log.
+        span: Range<usize>,
+    },
+    Main(OwnedMainFrame),
+    Slow(OwnedSlowFrame),
+    Gps(OwnedGpsFrame),
+}
+
+/// The location of a frame within a log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Span {
+    pub(crate) offset: usize,
+    pub(crate) size: usize,
+}
+
+/// Advances to the next byte that could start a frame, returning `false` if
+/// none was found before the end of `data`.
 #[cold]
-fn skip_to_frame(data: &mut Reader) {
-    data.skip_until_any(
+fn skip_to_frame(data: &mut Reader) -> bool {
+    let found = data.skip_until_any(
         &[
             FrameKind::Event,
             FrameKind::Data(DataFrameKind::Intra),
@@ -254,20 +869,37 @@ fn skip_to_frame(data: &mut Reader) {
         ]
         .map(u8::from),
     );
+
+    if !found {
+        data.read_n_bytes(data.remaining());
+    }
+
+    found
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub(crate) struct MainFrameHistory {
     history: [Option<RawMainFrame>; 2],
     index_new: usize,
+    /// Used in place of the time of the last frame until the first frame is
+    /// pushed, so times stay correct after seeking past a rollover
+    start_time: Option<u64>,
 }
 
 impl MainFrameHistory {
+    /// Creates an empty history as if the last frame was at `time`.
+    pub(crate) fn starting_at(time: u64) -> Self {
+        Self {
+            start_time: Some(time),
+            ..Self::default()
+        }
+    }
+
     fn index_old(&self) -> usize {
         (self.index_new + 1) % self.history.len()
     }
 
-    fn push(&mut self, frame: RawMainFrame) -> &RawMainFrame {
+    pub(crate) fn push(&mut self, frame: RawMainFrame) -> &RawMainFrame {
         self.index_new = self.index_old();
         self.history[self.index_new] = Some(frame);
         self.last().unwrap()
@@ -277,16 +909,540 @@ pub(crate) fn last(&self) -> Option<&RawMainFrame> {
         self.history[self.index_new].as_ref()
     }
 
+    /// Adds `offset` to the time of every frame.
+    #[cfg_attr(not(feature = "rayon"), expect(dead_code))]
+    pub(crate) fn shift_time(&mut self, offset: u64) {
+        for frame in self.history.iter_mut().flatten() {
+            frame.time += offset;
+        }
+
+        if let Some(time) = &mut self.start_time {
+            *time += offset;
+        }
+    }
+
+    pub(crate) fn last_time(&self) -> Option<u64> {
+        self.last().map(|frame| frame.time).or(self.start_time)
+    }
+
     pub(crate) fn last_last(&self) -> Option<&RawMainFrame> {
         self.history[self.index_old()].as_ref()
     }
 }
 
 #[derive(Debug)]
-enum InternalFrame {
+pub(crate) enum InternalFrame {
     Event(Event),
     Main(RawMainFrame),
     Slow(RawSlowFrame),
     Gps(RawGpsFrame),
     GpsHome(GpsHomeFrame),
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, vec};
+
+    use super::*;
+    use crate::frame::Frame as _;
+
+    static LOG: &[u8] = include_bytes!("../tests/logs/error-recovery.bbl");
+
+    /// The kind, span, time and raw values of a frame
+    type Summary = (char, Range<usize>, Option<u64>, Vec<u32>);
+
+    /// Summarizes the next event. Events do not include their span, so it is
+    /// read from the parser instead.
+    fn next_summary(parser: &mut DataParser) -> Option<Summary> {
+        let (kind, time, values) = summarize(parser.next()?);
+        Some((kind, parser.last_span().unwrap(), time, values))
+    }
+
+    /// Same as [`next_summary`], but for a [`PushParser`]
+    fn next_push_summary(parser: &mut PushParser) -> Option<Summary> {
+        let (kind, time, values) = summarize(parser.next()?);
+        Some((kind, parser.last_span().unwrap(), time, values))
+    }
+
+    fn summarize(event: ParserEvent) -> (char, Option<u64>, Vec<u32>) {
+        match event {
+            ParserEvent::Event(_) => ('E', None, vec![]),
+            ParserEvent::Main(main) => ('M', Some(main.time_raw()), main.iter_raw().collect()),
+            ParserEvent::Slow(slow) => ('S', None, slow.iter_raw().collect()),
+            ParserEvent::Gps(gps) => ('G', Some(gps.time_raw()), gps.iter_raw().collect()),
+        }
+    }
+
+    fn data_section(log: &[u8]) -> &[u8] {
+        let mut data = Reader::new(log);
+        while data.peek() == Some(b'H') {
+            data.read_line();
+        }
+
+        &log[(log.len() - data.remaining())..]
+    }
+
+    #[test]
+    fn push_matches_data_parser() {
+        let headers = Headers::parse(LOG).unwrap();
+
+        let mut expected = Vec::new();
+        let mut parser = headers.data_parser();
+        while let Some(summary) = next_summary(&mut parser) {
+            expected.push(summary);
+        }
+        assert!(!expected.is_empty());
+
+        let corruptions = parser.stats().corruptions.clone();
+        assert!(!corruptions.is_empty());
+
+        for chunk in [1, 3, 50, 1000] {
+            let mut parser = headers.push_parser();
+            let mut events = Vec::new();
+
+            for chunk in data_section(LOG).chunks(chunk) {
+                parser.feed(chunk);
+                while let Some(summary) = next_push_summary(&mut parser) {
+                    events.push(summary);
+                }
+            }
+
+            parser.finish();
+            while let Some(summary) = next_push_summary(&mut parser) {
+                events.push(summary);
+            }
+
+            assert_eq!(expected, events, "chunk size: {chunk}");
+            assert_eq!(parser.stats().counts.main, parser_counts(&headers).main);
+            assert_eq!(
+                corruptions,
+                parser.stats().corruptions,
+                "chunk size: {chunk}"
+            );
+            assert_eq!(corruptions.len(), parser.stats().corrupted_regions);
+        }
+    }
+
+    #[test]
+    fn push_waits_for_next_frame_kind() {
+        let headers = Headers::parse(LOG).unwrap();
+        let data = data_section(LOG);
+
+        let mut parser = headers.push_parser();
+        parser.feed(&data[..1]);
+        assert!(parser.next().is_none());
+        assert_eq!(1, parser.buffered());
+
+        parser.finish();
+        assert!(parser.next().is_none());
+        assert_eq!(0, parser.stats().counts.main);
+    }
+
+    /// Builds a log with a minimal main frame definition and GPS frames, and
+    /// a data section from `frames`
+    fn gps_log(frames: &[&[u8]]) -> Vec<u8> {
+        gps_log_with_fields("time,GPS_numSat", "10,0", frames)
+    }
+
+    /// Same as [`gps_log`], but with the given GPS field names and predictors
+    fn gps_log_with_fields(names: &str, predictors: &str, frames: &[&[u8]]) -> Vec<u8> {
+        let names = format!("Field G name:{names}");
+        let predictors = format!("Field G predictor:{predictors}");
+        let headers = [
+            "Data version:2",
+            "Firmware revision:Betaflight 4.2.0 (8f2d21460) STM32F7X2",
+            "motor_pwm_protocol:0",
+            "Field I name:loopIteration,time",
+            "Field I signed:0,0",
+            "Field I predictor:0,0",
+            "Field I encoding:1,1",
+            "Field P predictor:6,2",
+            "Field P encoding:9,0",
+            "Field S name:flightModeFlags",
+            "Field S signed:0",
+            "Field S predictor:0",
+            "Field S encoding:1",
+            &names,
+            "Field G signed:0,0",
+            &predictors,
+            "Field G encoding:1,1",
+        ];
+
+        let mut log = crate::MARKER.to_vec();
+        for header in headers {
+            log.push(b'H');
+            log.push(b' ');
+            log.extend_from_slice(header.as_bytes());
+            log.push(b'\n');
+        }
+
+        for frame in frames {
+            log.extend_from_slice(frame);
+        }
+        log.extend_from_slice(b"E\xffEnd of log\0");
+
+        log
+    }
+
+    fn times(log: &[u8]) -> Vec<(char, u64)> {
+        let headers = Headers::parse(log).unwrap();
+        let mut parser = headers.data_parser();
+
+        let mut times = Vec::new();
+        while let Some(event) = parser.next() {
+            match event {
+                ParserEvent::Main(main) => times.push(('M', main.time_raw())),
+                ParserEvent::Gps(gps) => times.push(('G', gps.time_raw())),
+                ParserEvent::Event(_) | ParserEvent::Slow(_) => {}
+            }
+        }
+        times
+    }
+
+    #[test]
+    fn gps_time_from_last_main_frame() {
+        let log = gps_log(&[
+            // Intraframe: iteration 0, time 1000
+            b"I\x00\xe8\x07",
+            // GPS frame: 50us after the last main frame
+            b"G\x32\x07",
+            // Interframe: 100us after the intraframe
+            b"P\xc8\x01",
+            b"G\x14\x08",
+        ]);
+
+        assert_eq!(
+            [('M', 1000), ('G', 1050), ('M', 1100), ('G', 1120)],
+            *times(&log)
+        );
+    }
+
+    #[test]
+    fn gps_time_not_first_field() {
+        let log = gps_log_with_fields(
+            "GPS_numSat,time",
+            "0,10",
+            &[b"I\x00\xe8\x07", b"G\x07\x32", b"P\xc8\x01", b"G\x08\x14"],
+        );
+
+        assert_eq!(
+            [('M', 1000), ('G', 1050), ('M', 1100), ('G', 1120)],
+            *times(&log)
+        );
+    }
+
+    #[test]
+    fn slow_from_last_main_frame_time() {
+        let log = gps_log(&[b"I\x00\xe8\x07", b"S\x05"]);
+        let needle = b"Field S predictor:0\n";
+        let at = log.windows(needle.len()).position(|w| w == needle).unwrap();
+        let log = [
+            &log[..at],
+            b"Field S predictor:10\n",
+            &log[at + needle.len()..],
+        ]
+        .concat();
+
+        let headers = Headers::parse(&log).unwrap();
+        let mut parser = headers.data_parser();
+        parser.next();
+        let Some(ParserEvent::Slow(slow)) = parser.next() else {
+            panic!("expected a slow frame");
+        };
+        assert_eq!(Some(1005), slow.get_raw(0));
+    }
+
+    #[test]
+    fn gps_time_after_rollover() {
+        let log = gps_log(&[
+            // Intraframe: time u32::MAX - 99
+            b"I\x00\x9c\xff\xff\xff\x0f",
+            b"G\x0a\x07",
+            // Interframe: 200us later, past the rollover
+            b"P\x90\x03",
+            b"G\x0a\x07",
+        ]);
+
+        let start = u64::from(u32::MAX) - 99;
+        assert_eq!(
+            [
+                ('M', start),
+                ('G', start + 10),
+                ('M', start + 200),
+                ('G', start + 210)
+            ],
+            *times(&log)
+        );
+    }
+
+    #[test]
+    fn frame_spans() {
+        let log = gps_log(&[b"I\x00\xe8\x07", b"S\x00", b"G\x32\x07", b"P\xc8\x01"]);
+        let headers = Headers::parse(&log).unwrap();
+        let start = headers.data_offset();
+        assert_eq!(b'I', log[start]);
+
+        let mut parser = headers.data_parser();
+        let mut spans = Vec::new();
+        while let Some(event) = parser.next() {
+            let frame_span = match event {
+                ParserEvent::Event(_) => None,
+                ParserEvent::Main(main) => Some(main.offset()..(main.offset() + main.size())),
+                ParserEvent::Slow(slow) => Some(slow.offset()..(slow.offset() + slow.size())),
+                ParserEvent::Gps(gps) => Some(gps.offset()..(gps.offset() + gps.size())),
+            };
+
+            let span = parser.last_span().unwrap();
+            assert!(frame_span.is_none_or(|frame_span| frame_span == span));
+            spans.push(span);
+        }
+
+        let end = start + 4 + 2 + 3 + 3;
+        assert_eq!(
+            [
+                start..(start + 4),
+                (start + 4)..(start + 6),
+                (start + 6)..(start + 9),
+                (start + 9)..end,
+                end..log.len(),
+            ],
+            *spans
+        );
+    }
+
+    #[test]
+    fn owned_events_match_borrowed() {
+        use crate::frame::Value;
+        use crate::Filter;
+
+        fn values<F: frame::Frame>(frame: &F) -> Vec<(u32, Value)> {
+            frame.iter_raw().zip(frame.iter().map(Into::into)).collect()
+        }
+
+        fn assert_send_sync<T: Send + Sync + 'static>() {}
+        assert_send_sync::<OwnedParserEvent>();
+
+        let headers = Headers::parse(LOG).unwrap();
+        let filters = FilterSet {
+            main: Filter::OnlyFields(["rcCommand", "gyroADC"].into()),
+            slow: Filter::Unfiltered,
+            gps: Filter::Unfiltered,
+        };
+
+        for filters in [FilterSet::default(), filters] {
+            let mut expected = Vec::new();
+            let mut parser = headers.data_parser_with_filters(&filters);
+            while let Some(event) = parser.next() {
+                let (kind, time, values) = match event {
+                    ParserEvent::Event(_) => ('E', None, vec![]),
+                    ParserEvent::Main(main) => ('M', Some(main.time_raw()), values(&main)),
+                    ParserEvent::Slow(slow) => ('S', None, values(&slow)),
+                    ParserEvent::Gps(gps) => ('G', Some(gps.time_raw()), values(&gps)),
+                };
+                expected.push((kind, parser.last_span().unwrap(), time, values));
+            }
+            assert!(!expected.is_empty());
+
+            let owned = headers
+                .data_parser_with_filters(&filters)
+                .into_owned_events()
+                .map(|event| match event {
+                    OwnedParserEvent::Event { span, .. } => ('E', span, None, vec![]),
+                    OwnedParserEvent::Main(main) => (
+                        'M',
+                        main.offset()..(main.offset() + main.size()),
+                        Some(main.time_raw()),
+                        values(&main),
+                    ),
+                    OwnedParserEvent::Slow(slow) => (
+                        'S',
+                        slow.offset()..(slow.offset() + slow.size()),
+                        None,
+                        values(&slow),
+                    ),
+                    OwnedParserEvent::Gps(gps) => (
+                        'G',
+                        gps.offset()..(gps.offset() + gps.size()),
+                        Some(gps.time_raw()),
+                        values(&gps),
+                    ),
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(expected, owned);
+        }
+    }
+
+    fn data_offset(log: &[u8]) -> usize {
+        log.len() - data_section(log).len()
+    }
+
+    /// Returns every event in the log starting at or after `offset`
+    fn events_from(headers: &Headers, offset: usize) -> Vec<Summary> {
+        let mut parser = headers.data_parser();
+        let mut events = Vec::new();
+        while let Some(summary) = next_summary(&mut parser) {
+            if summary.1.start >= offset {
+                events.push(summary);
+            }
+        }
+        events
+    }
+
+    fn seek(headers: &Headers, index: &FrameIndex, target: SeekTarget) -> Vec<Summary> {
+        let mut parser = DataParser::starting_at(headers, &FilterSet::default(), index, target);
+        let mut events = Vec::new();
+        while let Some(summary) = next_summary(&mut parser) {
+            events.push(summary);
+        }
+        events
+    }
+
+    #[test]
+    fn seek_matches_data_parser() {
+        let headers = Headers::parse(LOG).unwrap();
+        let index = FrameIndex::new(&headers);
+        assert!(index.entries().len() > 1);
+
+        for entry in index.entries() {
+            let expected = events_from(&headers, entry.offset);
+            assert_eq!(
+                expected,
+                seek(&headers, &index, SeekTarget::Iteration(entry.iteration))
+            );
+            assert_eq!(
+                expected,
+                seek(&headers, &index, SeekTarget::Time(entry.time))
+            );
+        }
+
+        assert_eq!(
+            events_from(&headers, 0),
+            seek(&headers, &index, SeekTarget::Time(0))
+        );
+    }
+
+    #[test]
+    fn seek_after_rollover() {
+        let log = gps_log(&[
+            // Intraframe: time u32::MAX - 99
+            b"I\x00\x9c\xff\xff\xff\x0f",
+            // Interframe: 200us later, past the rollover
+            b"P\x90\x03",
+            // Intraframe: 300us after the first
+            b"I\x02\xc8\x01",
+            b"G\x0a\x07",
+        ]);
+
+        let headers = Headers::parse(&log).unwrap();
+        let index = FrameIndex::new(&headers);
+        assert_eq!(2, index.entries().len());
+
+        let rollover = 1 << 32;
+        assert_eq!(rollover + 200, index.entries()[1].time);
+
+        let events = seek(&headers, &index, SeekTarget::Iteration(2));
+        assert_eq!(events_from(&headers, index.entries()[1].offset), events);
+        assert_eq!(Some(rollover + 210), events[1].2);
+    }
+
+    fn corruptions(log: &[u8]) -> Vec<Corruption> {
+        let headers = Headers::parse(log).unwrap();
+        let mut parser = headers.data_parser();
+        while parser.next().is_some() {}
+        parser.stats().corruptions.clone()
+    }
+
+    #[test]
+    fn corrupted_frame() {
+        let log = gps_log(&[
+            b"I\x00\xe8\x07",
+            // Slow frame followed by garbage, which must be skipped up until
+            // the next intraframe
+            b"S\x00",
+            b"\x01\x02",
+            b"I\x00\xe8\x07",
+        ]);
+
+        assert_eq!(
+            [Corruption {
+                kind: Some(FrameKind::Data(DataFrameKind::Slow)),
+                offset: data_offset(&log) + 4,
+                skipped: 4,
+                reason: CorruptionReason::InvalidFrame,
+            }],
+            *corruptions(&log)
+        );
+    }
+
+    #[test]
+    fn corrupted_frame_kind() {
+        let log = gps_log(&[b"\x01\x02", b"I\x00\xe8\x07"]);
+
+        assert_eq!(
+            [Corruption {
+                kind: None,
+                offset: data_offset(&log),
+                skipped: 2,
+                reason: CorruptionReason::InvalidFrameKind,
+            }],
+            *corruptions(&log)
+        );
+    }
+
+    #[test]
+    fn corrupted_eof() {
+        let mut log = gps_log(&[b"I\x00\xe8\x07"]);
+        log.truncate(log.len() - b"E\xffEnd of log\0".len());
+        log.extend_from_slice(b"P\xc8");
+
+        assert_eq!(
+            [Corruption {
+                kind: Some(FrameKind::Data(DataFrameKind::Inter)),
+                offset: data_offset(&log) + 4,
+                skipped: 2,
+                reason: CorruptionReason::UnexpectedEof,
+            }],
+            *corruptions(&log)
+        );
+    }
+
+    #[test]
+    fn corruptions_are_capped() {
+        let count = Stats::MAX_CORRUPTIONS + 10;
+        let mut frames = (0..count)
+            .map(|i| {
+                let iteration = u8::try_from(i).unwrap();
+                // Each frame is 10us after the last, and always fits in 2 bytes
+                let time = 1_000 + 10 * u16::from(iteration);
+                let [low, high] = time.to_le_bytes();
+                let time = [(low & 0x7F) | 0x80, (high << 1) | (low >> 7)];
+                [
+                    vec![b'I', iteration, time[0], time[1]],
+                    // A slow frame followed by garbage, as in `corrupted_frame`
+                    b"S\x00\x01\x02".to_vec(),
+                ]
+            })
+            .collect::<Vec<_>>()
+            .concat();
+        frames.push(b"I\x00\xe8\x0F".to_vec());
+        let log = gps_log(&frames.iter().map(Vec::as_slice).collect::<Vec<_>>());
+
+        let headers = Headers::parse(&log).unwrap();
+        let mut parser = headers.data_parser();
+        while parser.next().is_some() {}
+        let stats = parser.stats();
+
+        assert_eq!(count + 1, stats.counts.main);
+        assert_eq!(count, stats.corrupted_regions);
+        assert_eq!(4 * count, stats.corrupted_bytes);
+        assert_eq!(Stats::MAX_CORRUPTIONS, stats.corruptions.len());
+        assert_eq!(data_offset(&log) + 4, stats.corruptions[0].offset);
+    }
+
+    fn parser_counts(headers: &Headers) -> FrameCounts {
+        let mut parser = headers.data_parser();
+        while parser.next().is_some() {}
+        parser.stats().counts
+    }
+}
@@ -1,14 +1,19 @@
 //! Types for blackbox log events.
 
+use alloc::vec::Vec;
+
 use tracing::instrument;
 
-use crate::parser::{decode, InternalError, InternalResult};
-use crate::Reader;
+use crate::frame::FrameKind;
+use crate::parser::{decode, encode, InternalError, InternalResult};
+use crate::writer::EncodeError;
+use crate::{frame, Reader};
 
 /// A decoded blackbox log event.
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "_serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
+    /// The time of the arming beep in microseconds since power on.
     SyncBeep(u64),
     InflightAdjustment {
         function: u8,
@@ -34,7 +39,7 @@ pub enum Event {
 /// A new value decoded from an
 /// [`InflightAdjustment`](`Event::InflightAdjustment`) event.
 #[derive(Debug, Clone, Copy, PartialEq)]
-#[cfg_attr(feature = "_serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AdjustedValue {
     Float(f32),
     Int(i32),
@@ -42,7 +47,7 @@ pub enum AdjustedValue {
 
 impl Event {
     #[instrument(level = "debug", name = "Event::parse", skip_all, fields(kind))]
-    pub(crate) fn parse(data: &mut Reader) -> InternalResult<Self> {
+    pub(crate) fn parse(data: &mut Reader, last_main_time: Option<u64>) -> InternalResult<Self> {
         let byte = data.read_u8().ok_or(InternalError::Eof)?;
         let kind = EventKind::from_byte(byte).ok_or_else(|| {
             tracing::debug!("found invalid event: {byte:0>#2x}");
@@ -51,10 +56,8 @@ pub(crate) fn parse(data: &mut Reader) -> InternalResult<Self> {
 
         let event = match kind {
             EventKind::SyncBeep => {
-                // TODO: SyncBeep handle time rollover
-
                 let time = decode::variable(data)?;
-                Self::SyncBeep(time.into())
+                Self::SyncBeep(frame::unwrap_time(time, last_main_time))
             }
 
             EventKind::InflightAdjustment => {
@@ -109,18 +112,14 @@ pub(crate) fn parse(data: &mut Reader) -> InternalResult<Self> {
 
                     let reason = data.read_u8().ok_or(InternalError::Eof)?.into();
 
-                    if data.read_u8() != Some(b')') {
-                        return Err(InternalError::Retry);
-                    }
+                    check_message(data, b")")?;
 
                     Some(reason)
                 } else {
                     None
                 };
 
-                if data.read_u8() != Some(0) {
-                    return Err(InternalError::Retry);
-                }
+                check_message(data, b"\0")?;
 
                 Self::End { disarm_reason }
             }
@@ -128,6 +127,94 @@ pub(crate) fn parse(data: &mut Reader) -> InternalResult<Self> {
 
         Ok(event)
     }
+
+    /// Writes the event, including its kind byte but not the leading `E`.
+    pub(crate) fn encode(
+        &self,
+        out: &mut Vec<u8>,
+        last_main_time: Option<u64>,
+    ) -> Result<(), EncodeError> {
+        let invalid = |field| EncodeError::invalid(FrameKind::Event, field);
+
+        match *self {
+            Self::SyncBeep(time) => {
+                #[expect(clippy::cast_possible_truncation)]
+                let raw = time as u32;
+                if frame::unwrap_time(raw, last_main_time) != time {
+                    return Err(invalid("time"));
+                }
+
+                out.push(EventKind::SyncBeep.into());
+                encode::variable(out, raw);
+            }
+
+            Self::InflightAdjustment {
+                function,
+                new_value,
+            } => {
+                if function & 0x80 != 0 {
+                    return Err(invalid("function"));
+                }
+
+                out.push(EventKind::InflightAdjustment.into());
+                match new_value {
+                    AdjustedValue::Float(value) => {
+                        out.push(function | 0x80);
+                        out.extend_from_slice(&value.to_le_bytes());
+                    }
+                    AdjustedValue::Int(value) => {
+                        out.push(function);
+                        encode::variable_signed(out, value);
+                    }
+                }
+            }
+
+            Self::Resume {
+                log_iteration,
+                time,
+            } => {
+                out.push(EventKind::Resume.into());
+                encode::variable(out, log_iteration);
+                encode::variable(out, time);
+            }
+
+            Self::Disarm(reason) => {
+                out.push(EventKind::Disarm.into());
+                encode::variable(out, reason);
+            }
+
+            Self::FlightMode { flags, last_flags } => {
+                out.push(EventKind::FlightMode.into());
+                encode::variable(out, flags);
+                encode::variable(out, last_flags);
+            }
+
+            Self::ImuFailure { error } => {
+                out.push(EventKind::ImuFailure.into());
+                encode::variable(out, error);
+            }
+
+            Self::End { disarm_reason } => {
+                let reason = disarm_reason
+                    .map(u8::try_from)
+                    .transpose()
+                    .map_err(|_| invalid("disarm_reason"))?;
+
+                out.push(EventKind::End.into());
+                out.extend_from_slice(b"End of log");
+
+                if let Some(reason) = reason {
+                    out.extend_from_slice(b" (disarm reason:");
+                    out.push(reason);
+                    out.push(b')');
+                }
+
+                out.push(0);
+            }
+        }
+
+        Ok(())
+    }
 }
 
 byte_enum! {
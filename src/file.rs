@@ -27,6 +27,13 @@ pub fn log_count(&self) -> usize {
         self.offsets.len()
     }
 
+    /// Returns the offset of the start of the `index`-th log from the start of
+    /// the file, or `None` if there is no log number `index`.
+    #[inline]
+    pub fn log_offset(&self, index: usize) -> Option<usize> {
+        self.offsets.get(index).copied()
+    }
+
     /// Returns an iterator over parsed [`Headers`] for each log.
     ///
     /// Roughly equivalent to repeatedly calling [`File::parse`], but may
@@ -1,5 +1,7 @@
 use alloc::borrow::ToOwned as _;
+use alloc::string::String;
 use alloc::vec::Vec;
+use core::cmp;
 
 use tracing::instrument;
 
@@ -8,15 +10,21 @@
     self, DataFrameKind, DataFrameProperty, FieldDef, FieldDefDetails, FrameDef, GpsHomeFrame,
 };
 use crate::headers::{ParseError, ParseResult};
-use crate::parser::{decode, Encoding, InternalResult};
+use crate::parser::{Encoding, InternalResult};
 use crate::predictor::{Predictor, PredictorContext};
 use crate::utils::to_base_field;
+use crate::writer::EncodeError;
 use crate::{Headers, Reader, Unit};
 
 /// The parsed frame definition for GPS frames.
 #[derive(Debug, Clone)]
 pub struct GpsFrameDef<'data> {
+    time: GpsFieldDef<'data>,
+    time_index: usize,
     pub(super) fields: Vec<GpsFieldDef<'data>>,
+    /// The encoding of every field, including `time`, in the order they are
+    /// written
+    encodings: Vec<Encoding>,
 }
 
 impl frame::seal::Sealed for GpsFrameDef<'_> {}
@@ -36,7 +44,12 @@ fn get<'a>(&'a self, index: usize) -> Option<FieldDef<'data, Self::Unit>>
         self.fields.get(index).map(
             |&GpsFieldDef {
                  name, unit, signed, ..
-             }| FieldDef { name, unit, signed },
+             }| FieldDef {
+                name,
+                description: None,
+                unit,
+                signed,
+            },
         )
     }
 }
@@ -51,6 +64,13 @@ pub(crate) fn validate(
         check_predictor: impl Fn(DataFrameKind, &'data str, Predictor, usize) -> ParseResult<()>,
         check_unit: impl Fn(DataFrameKind, &'data str, Unit) -> ParseResult<()>,
     ) -> ParseResult<()> {
+        check_predictor(
+            DataFrameKind::Gps,
+            self.time.name,
+            self.time.predictor,
+            self.time_index,
+        )?;
+
         for (
             i,
             GpsFieldDef {
@@ -76,18 +96,19 @@ pub(crate) fn parse(
         last_main_time: Option<u64>,
         last_home: Option<&GpsHomeFrame>,
     ) -> InternalResult<RawGpsFrame> {
-        let time = {
-            let time = last_main_time.unwrap_or(0);
-            let offset = decode::variable(data)?.into();
+        let ctx =
+            PredictorContext::with_home(headers, last_home.map(|home| home.0), last_main_time);
+
+        let mut raw =
+            frame::read_field_values(data, headers.data_version, &self.encodings, |&e| e)?;
 
-            let time = time.saturating_add(offset);
-            tracing::trace!(time, offset);
+        let time = {
+            let raw = raw.remove(self.time_index);
+            let time = self.time.predictor.apply_time(raw, &ctx);
+            tracing::trace!(time, raw);
             time
         };
 
-        let raw = frame::read_field_values(data, &self.fields, |f| f.encoding)?;
-
-        let ctx = PredictorContext::with_home(headers, last_home.map(|home| home.0));
         let mut values = Vec::with_capacity(raw.len());
 
         for (i, field) in self.fields.iter().enumerate() {
@@ -110,6 +131,46 @@ pub(crate) fn parse(
 
         Ok(RawGpsFrame { time, values })
     }
+
+    #[instrument(level = "trace", name = "GpsFrameDef::encode", skip_all)]
+    pub(crate) fn encode(
+        &self,
+        out: &mut Vec<u8>,
+        headers: &Headers,
+        frame: &RawGpsFrame,
+        last_main_time: Option<u64>,
+        last_home: Option<&GpsHomeFrame>,
+    ) -> Result<(), EncodeError> {
+        let invalid = |field| EncodeError::invalid(DataFrameKind::Gps, field);
+
+        let ctx =
+            PredictorContext::with_home(headers, last_home.map(|home| home.0), last_main_time);
+
+        let time = self
+            .time
+            .predictor
+            .unapply_time(frame.time, &ctx)
+            .ok_or_else(|| invalid(self.time.name))?;
+
+        let mut raw = self
+            .fields
+            .iter()
+            .zip(&frame.values)
+            .map(|(field, &value)| {
+                let signed = field.encoding.is_signed();
+                field.predictor.unapply(value, signed, None, &ctx)
+            })
+            .collect::<Vec<_>>();
+        raw.insert(self.time_index, time);
+
+        frame::write_field_values(out, headers.data_version, &self.encodings, |&e| e, &raw).map_err(
+            |i| match i.cmp(&self.time_index) {
+                cmp::Ordering::Less => invalid(self.fields[i].name),
+                cmp::Ordering::Equal => invalid(self.time.name),
+                cmp::Ordering::Greater => invalid(self.fields[i - 1].name),
+            },
+        )
+    }
 }
 
 #[allow(dead_code)]
@@ -160,6 +221,23 @@ pub(crate) fn update(&mut self, property: DataFrameProperty, value: &'data str)
         }
     }
 
+    /// The definition is optional, so headers are only missing if at least one
+    /// was found.
+    pub(crate) fn missing_headers(&self, missing: &mut Vec<String>) {
+        let kind = DataFrameKind::Gps;
+
+        if self.names.is_some()
+            || self.predictors.is_some()
+            || self.encodings.is_some()
+            || self.signs.is_some()
+        {
+            frame::check_header(missing, kind, "name", self.names);
+            frame::check_header(missing, kind, "signed", self.signs);
+            frame::check_header(missing, kind, "predictor", self.predictors);
+            frame::check_header(missing, kind, "encoding", self.encodings);
+        }
+    }
+
     pub(crate) fn parse(self) -> ParseResult<Option<GpsFrameDef<'data>>> {
         let kind = DataFrameKind::Gps;
 
@@ -186,24 +264,19 @@ pub(crate) fn parse(self) -> ParseResult<Option<GpsFrameDef<'data>>> {
                     unit: unit_from_name(name),
                     signed,
                 })
-            });
-
-        if !matches!(
-            fields.next().transpose()?,
-            Some(GpsFieldDef {
-                name: "time",
-                predictor: Predictor::LastMainFrameTime,
-                encoding: Encoding::Variable,
-                ..
             })
-        ) {
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let all_encodings = fields.iter().map(|f| f.encoding).collect();
+
+        let Some(time_index) = fields.iter().position(|f| f.name == "time") else {
             return Err(ParseError::MissingField {
                 frame: DataFrameKind::Gps,
                 field: "time".to_owned(),
             });
-        }
+        };
+        let time = fields.remove(time_index);
 
-        let mut fields = fields.collect::<Result<Vec<_>, _>>()?;
         for (i, j) in (1..fields.len()).map(|i| (i - 1, i)) {
             if fields[i].predictor == Predictor::HomeLat
                 && fields[j].predictor == Predictor::HomeLat
@@ -221,7 +294,12 @@ pub(crate) fn parse(self) -> ParseResult<Option<GpsFrameDef<'data>>> {
             return Err(ParseError::MalformedFrameDef(DataFrameKind::Gps));
         }
 
-        Ok(Some(GpsFrameDef { fields }))
+        Ok(Some(GpsFrameDef {
+            time,
+            time_index,
+            fields,
+            encodings: all_encodings,
+        }))
     }
 }
 
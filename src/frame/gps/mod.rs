@@ -1,9 +1,11 @@
 mod def;
 
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 
 pub use self::def::*;
-use super::Unit;
+use super::{Frame as _, FrameContext, Unit};
+use crate::data::Span;
 use crate::filter::AppliedFilter;
 use crate::units::prelude::*;
 use crate::{units, Headers};
@@ -14,6 +16,8 @@ pub struct GpsFrame<'data, 'headers, 'parser> {
     headers: &'headers Headers<'data>,
     raw: RawGpsFrame,
     filter: &'parser AppliedFilter,
+    context: &'parser Arc<FrameContext>,
+    span: Span,
 }
 
 impl super::seal::Sealed for GpsFrame<'_, '_, '_> {}
@@ -38,34 +42,7 @@ fn get(&self, index: usize) -> Option<Self::Value> {
         let def = &frame_def.fields[index];
         let raw = self.raw.values[index];
 
-        let value = match def.unit {
-            GpsUnit::Coordinate => {
-                assert!(def.signed);
-                let value = raw.cast_signed();
-
-                GpsValue::Coordinate(f64::from(value) / 10000000.)
-            }
-            GpsUnit::Altitude => {
-                let altitude = if def.signed {
-                    raw.cast_signed().into()
-                } else {
-                    raw.into()
-                };
-
-                GpsValue::Altitude(Length::new::<meter>(altitude))
-            }
-            GpsUnit::Velocity => {
-                assert!(!def.signed);
-                GpsValue::Velocity(units::new::velocity(raw))
-            }
-            GpsUnit::Heading => {
-                assert!(!def.signed);
-                GpsValue::Heading(f64::from(raw) / 10.)
-            }
-            GpsUnit::Unitless => GpsValue::new_unitless(raw, def.signed),
-        };
-
-        Some(value)
+        Some(GpsValue::new(raw, def.unit, def.signed))
     }
 }
 
@@ -74,14 +51,32 @@ pub(crate) fn new(
         headers: &'headers Headers<'data>,
         raw: RawGpsFrame,
         filter: &'parser AppliedFilter,
+        context: &'parser Arc<FrameContext>,
+        span: Span,
     ) -> Self {
         Self {
             headers,
             raw,
             filter,
+            context,
+            span,
         }
     }
 
+    /// Copies the frame so it no longer borrows the parser or headers.
+    pub fn into_owned(self) -> OwnedGpsFrame {
+        OwnedGpsFrame {
+            context: Arc::clone(self.context),
+            values: self.iter_raw().collect(),
+            time: self.raw.time,
+            span: self.span,
+        }
+    }
+
+    pub(crate) fn raw(&self) -> &RawGpsFrame {
+        &self.raw
+    }
+
     /// Returns the parsed time since power on.
     pub fn time(&self) -> Time {
         units::new::time(self.raw.time)
@@ -89,11 +84,88 @@ pub fn time(&self) -> Time {
 
     /// Returns the raw microsecond counter since power on.
     ///
-    /// **Note:** This does not currently handle overflow of the transmitted
-    /// 32bit counter.
+    /// Rollovers of the 32 bit counter written in the log are accounted for,
+    /// so this does not jump backwards after ~71 minutes.
     pub fn time_raw(&self) -> u64 {
         self.raw.time
     }
+
+    /// Returns the offset of the start of this frame from the start of the
+    /// log.
+    ///
+    /// Add [`File::log_offset`][crate::File::log_offset] to get the offset
+    /// within the whole file.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.span.offset
+    }
+
+    /// Returns the length of this frame in bytes, including the frame kind
+    /// byte.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.span.size
+    }
+}
+
+/// An owned copy of a [`GpsFrame`] that can outlive the parser that returned
+/// it. See [`GpsFrame::into_owned`].
+#[derive(Debug, Clone)]
+pub struct OwnedGpsFrame {
+    context: Arc<FrameContext>,
+    /// Only the fields that satisfy the filter
+    values: Vec<u32>,
+    time: u64,
+    span: Span,
+}
+
+impl super::seal::Sealed for OwnedGpsFrame {}
+
+impl super::Frame for OwnedGpsFrame {
+    type Value = GpsValue;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[inline]
+    fn get_raw(&self, index: usize) -> Option<u32> {
+        self.values.get(index).copied()
+    }
+
+    fn get(&self, index: usize) -> Option<Self::Value> {
+        let raw = *self.values.get(index)?;
+        let (unit, signed) = self.context.gps[index];
+        Some(GpsValue::new(raw, unit, signed))
+    }
+}
+
+impl OwnedGpsFrame {
+    /// Returns the parsed time since power on.
+    pub fn time(&self) -> Time {
+        units::new::time(self.time)
+    }
+
+    /// Returns the raw microsecond counter since power on. See
+    /// [`GpsFrame::time_raw`].
+    pub fn time_raw(&self) -> u64 {
+        self.time
+    }
+
+    /// Returns the offset of the start of this frame from the start of the
+    /// log. See [`GpsFrame::offset`].
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.span.offset
+    }
+
+    /// Returns the length of this frame in bytes, including the frame kind
+    /// byte.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.span.size
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -103,6 +175,7 @@ pub(crate) struct RawGpsFrame {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GpsValue {
     Coordinate(f64),
     Altitude(Length),
@@ -113,6 +186,35 @@ pub enum GpsValue {
 }
 
 impl GpsValue {
+    pub(crate) fn new(raw: u32, unit: GpsUnit, signed: bool) -> Self {
+        match unit {
+            GpsUnit::Coordinate => {
+                assert!(signed);
+                let value = raw.cast_signed();
+
+                Self::Coordinate(f64::from(value) / 10000000.)
+            }
+            GpsUnit::Altitude => {
+                let altitude = if signed {
+                    raw.cast_signed().into()
+                } else {
+                    raw.into()
+                };
+
+                Self::Altitude(Length::new::<meter>(altitude))
+            }
+            GpsUnit::Velocity => {
+                assert!(!signed);
+                Self::Velocity(units::new::velocity(raw))
+            }
+            GpsUnit::Heading => {
+                assert!(!signed);
+                Self::Heading(f64::from(raw) / 10.)
+            }
+            GpsUnit::Unitless => Self::new_unitless(raw, signed),
+        }
+    }
+
     const fn new_unitless(value: u32, signed: bool) -> Self {
         if signed {
             Self::Signed(value.cast_signed())
@@ -136,6 +238,7 @@ fn from(value: GpsValue) -> Self {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GpsUnit {
     Coordinate,
     Altitude,
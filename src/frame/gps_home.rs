@@ -1,12 +1,15 @@
 use alloc::borrow::ToOwned as _;
+use alloc::string::String;
+use alloc::vec;
 use alloc::vec::Vec;
 
 use tracing::instrument;
 
-use super::{read_field_values, DataFrameKind, DataFrameProperty};
+use super::{read_field_values, write_field_values, DataFrameKind, DataFrameProperty};
 use crate::headers::{ParseError, ParseResult};
 use crate::parser::{Encoding, InternalResult};
 use crate::predictor::{Predictor, PredictorContext};
+use crate::writer::EncodeError;
 use crate::{Headers, Reader};
 
 #[derive(Debug, Clone)]
@@ -49,11 +52,12 @@ pub(crate) fn parse(
         &self,
         data: &mut Reader,
         headers: &Headers,
+        last_main_time: Option<u64>,
     ) -> InternalResult<GpsHomeFrame> {
-        let raw = read_field_values(data, &self.0, |f| f.encoding)?;
-        let _ = read_field_values(data, &self.1, |&f| f)?;
+        let raw = read_field_values(data, headers.data_version, &self.0, |f| f.encoding)?;
+        let _ = read_field_values(data, headers.data_version, &self.1, |&f| f)?;
 
-        let ctx = PredictorContext::new(headers);
+        let ctx = PredictorContext::new(headers, last_main_time);
         let values = raw
             .iter()
             .zip(self.0.iter())
@@ -82,6 +86,43 @@ pub(crate) fn parse(
             longitude,
         }))
     }
+
+    /// Any fields after `GPS_home[1]` are written as zero.
+    #[instrument(level = "trace", name = "GpsHomeFrameDef::encode", skip_all)]
+    pub(crate) fn encode(
+        &self,
+        out: &mut Vec<u8>,
+        headers: &Headers,
+        frame: &GpsHomeFrame,
+        last_main_time: Option<u64>,
+    ) -> Result<(), EncodeError> {
+        let invalid = |field| EncodeError::invalid(DataFrameKind::GpsHome, field);
+
+        let ctx = PredictorContext::new(headers, last_main_time);
+        let GpsPosition {
+            latitude,
+            longitude,
+        } = frame.0;
+
+        let raw = [latitude, longitude]
+            .iter()
+            .zip(self.0.iter())
+            .map(|(&value, field)| {
+                field
+                    .predictor
+                    .unapply(value.cast_unsigned(), true, None, &ctx)
+            })
+            .collect::<Vec<_>>();
+
+        write_field_values(out, headers.data_version, &self.0, |f| f.encoding, &raw)
+            .map_err(|i| invalid(self.0[i].name))?;
+
+        // Every encoding can represent zero
+        let extra = vec![0; self.1.len()];
+        let _ = write_field_values(out, headers.data_version, &self.1, |&f| f, &extra);
+
+        Ok(())
+    }
 }
 
 #[allow(dead_code)]
@@ -112,8 +153,25 @@ pub(crate) fn update(&mut self, property: DataFrameProperty, value: &'data str)
         }
     }
 
+    /// The definition is optional, so headers are only missing if at least one
+    /// was found.
+    pub(crate) fn missing_headers(&self, missing: &mut Vec<String>) {
+        let kind = DataFrameKind::GpsHome;
+
+        if self.names.is_some()
+            || self.predictors.is_some()
+            || self.encodings.is_some()
+            || self.signs.is_some()
+        {
+            super::check_header(missing, kind, "name", self.names);
+            super::check_header(missing, kind, "signed", self.signs);
+            super::check_header(missing, kind, "predictor", self.predictors);
+            super::check_header(missing, kind, "encoding", self.encodings);
+        }
+    }
+
     pub(crate) fn parse(self) -> ParseResult<Option<GpsHomeFrameDef<'data>>> {
-        let kind = DataFrameKind::Gps;
+        let kind = DataFrameKind::GpsHome;
 
         if self.names.is_none()
             && self.predictors.is_none()
@@ -1,14 +1,16 @@
 use alloc::borrow::ToOwned as _;
+use alloc::string::String;
 use alloc::vec::Vec;
 
 use tracing::instrument;
 
 use super::{MainUnit, RawMainFrame};
 use crate::frame::{self, DataFrameKind, DataFrameProperty, FieldDef, FieldDefDetails, FrameDef};
-use crate::headers::{ParseError, ParseResult};
-use crate::parser::{decode, Encoding, InternalResult};
+use crate::headers::{DebugField, ParseError, ParseResult};
+use crate::parser::{decode, encode, Encoding, InternalResult};
 use crate::predictor::{self, Predictor, PredictorContext};
-use crate::utils::to_base_field;
+use crate::utils::{field_index, to_base_field};
+use crate::writer::EncodeError;
 use crate::{Headers, Reader, Unit};
 
 /// The parsed frame definition for main frames.
@@ -42,8 +44,16 @@ fn get<'a>(&'a self, index: usize) -> Option<FieldDef<'data, Self::Unit>>
 
         field.map(
             |&MainFieldDef {
-                 name, signed, unit, ..
-             }| FieldDef { name, unit, signed },
+                 name,
+                 signed,
+                 conversion,
+                 ..
+             }| FieldDef {
+                name,
+                description: conversion.description(),
+                unit: conversion.unit(),
+                signed,
+            },
         )
     }
 }
@@ -71,14 +81,14 @@ pub(crate) fn validate(
                 name,
                 predictor_intra,
                 predictor_inter,
-                unit,
+                conversion,
                 ..
             },
         ) in self.fields.iter().enumerate()
         {
             check_predictor(DataFrameKind::Intra, name, *predictor_intra, i)?;
             check_predictor(DataFrameKind::Inter, name, *predictor_inter, i)?;
-            check_unit(DataFrameKind::Intra, name, Unit::from(*unit))?;
+            check_unit(DataFrameKind::Intra, name, conversion.unit().into())?;
         }
 
         Ok(())
@@ -90,23 +100,20 @@ pub(crate) fn parse_intra(
         data: &mut Reader,
         headers: &Headers,
         last: Option<&RawMainFrame>,
+        last_time: Option<u64>,
     ) -> InternalResult<RawMainFrame> {
-        fn get_update_ctx(
-            last: Option<&'_ RawMainFrame>,
-        ) -> impl Fn(&mut PredictorContext, usize) + '_ {
-            move |ctx, i| ctx.set_last(last.map(|l| l.values[i]))
-        }
-
         let iteration = decode::variable(data)?;
         tracing::trace!(iteration);
-        let time = decode::variable(data)?.into();
+        let time = frame::unwrap_time(decode::variable(data)?, last_time);
         tracing::trace!(time);
 
         let values = frame::parse_impl(
-            PredictorContext::new(headers),
-            &frame::read_field_values(data, &self.fields, |f| f.encoding_intra)?,
+            PredictorContext::new(headers, last_time),
+            &frame::read_field_values(data, headers.data_version, &self.fields, |f| {
+                f.encoding_intra
+            })?,
             self.fields.iter().map(IntraFieldDef),
-            get_update_ctx(last),
+            intra_update_ctx(last),
         );
 
         Ok(RawMainFrame {
@@ -126,25 +133,11 @@ pub(crate) fn parse_inter(
         last_last: Option<&RawMainFrame>,
         skipped_frames: u32,
     ) -> InternalResult<RawMainFrame> {
-        fn get_update_ctx<'a>(
-            last: Option<&'a RawMainFrame>,
-            last_last: Option<&'a RawMainFrame>,
-        ) -> impl Fn(&mut PredictorContext<'_, '_>, usize) + 'a {
-            move |ctx, i| {
-                ctx.set_last_2(last.map(|l| l.values[i]), last_last.map(|l| l.values[i]));
-            }
-        }
-
         let iteration = 1 + last.map_or(0, |f| f.iteration) + skipped_frames;
         tracing::trace!(iteration);
 
         let time = {
-            // Get the time from last_last if last was an interframe
-            let last_last = last
-                .filter(|f| !f.intra)
-                .and_then(|_| last_last.map(|f| f.time));
-
-            let time = predictor::straight_line(last.map(|f| f.time), last_last);
+            let time = predict_inter_time(last, last_last);
             let offset = decode::variable_signed(data)?;
             let time = time.saturating_add_signed(offset.into());
 
@@ -153,10 +146,12 @@ fn get_update_ctx<'a>(
         };
 
         let values = frame::parse_impl(
-            PredictorContext::with_skipped(headers, skipped_frames),
-            &frame::read_field_values(data, &self.fields, |f| f.encoding_inter)?,
+            PredictorContext::with_skipped(headers, skipped_frames, last.map(|f| f.time)),
+            &frame::read_field_values(data, headers.data_version, &self.fields, |f| {
+                f.encoding_inter
+            })?,
             self.fields.iter().map(InterFieldDef),
-            get_update_ctx(last, last_last),
+            inter_update_ctx(last, last_last),
         );
 
         Ok(RawMainFrame {
@@ -166,6 +161,101 @@ fn get_update_ctx<'a>(
             values,
         })
     }
+
+    #[instrument(level = "trace", skip_all)]
+    pub(crate) fn encode_intra(
+        &self,
+        out: &mut Vec<u8>,
+        headers: &Headers,
+        frame: &RawMainFrame,
+        last: Option<&RawMainFrame>,
+    ) -> Result<(), EncodeError> {
+        let invalid = |field| EncodeError::invalid(DataFrameKind::Intra, field);
+
+        #[expect(clippy::cast_possible_truncation)]
+        let time = frame.time as u32;
+        if frame::unwrap_time(time, last.map(|f| f.time)) != frame.time {
+            return Err(invalid("time"));
+        }
+
+        encode::variable(out, frame.iteration);
+        encode::variable(out, time);
+
+        let raw = frame::encode_impl(
+            PredictorContext::new(headers, last.map(|f| f.time)),
+            &frame.values,
+            self.fields.iter().map(IntraFieldDef),
+            intra_update_ctx(last),
+        );
+
+        frame::write_field_values(
+            out,
+            headers.data_version,
+            &self.fields,
+            |f| f.encoding_intra,
+            &raw,
+        )
+        .map_err(|i| invalid(self.fields[i].name))
+    }
+
+    #[instrument(level = "trace", skip_all)]
+    pub(crate) fn encode_inter(
+        &self,
+        out: &mut Vec<u8>,
+        headers: &Headers,
+        frame: &RawMainFrame,
+        last: Option<&RawMainFrame>,
+        last_last: Option<&RawMainFrame>,
+        skipped_frames: u32,
+    ) -> Result<(), EncodeError> {
+        let invalid = |field| EncodeError::invalid(DataFrameKind::Inter, field);
+
+        if frame.iteration != 1 + last.map_or(0, |f| f.iteration) + skipped_frames {
+            return Err(invalid(self.iteration.name));
+        }
+
+        let offset = i128::from(frame.time) - i128::from(predict_inter_time(last, last_last));
+        let offset = i32::try_from(offset).map_err(|_| invalid("time"))?;
+        encode::variable_signed(out, offset);
+
+        let raw = frame::encode_impl(
+            PredictorContext::with_skipped(headers, skipped_frames, last.map(|f| f.time)),
+            &frame.values,
+            self.fields.iter().map(InterFieldDef),
+            inter_update_ctx(last, last_last),
+        );
+
+        frame::write_field_values(
+            out,
+            headers.data_version,
+            &self.fields,
+            |f| f.encoding_inter,
+            &raw,
+        )
+        .map_err(|i| invalid(self.fields[i].name))
+    }
+}
+
+fn intra_update_ctx(last: Option<&'_ RawMainFrame>) -> impl Fn(&mut PredictorContext, usize) + '_ {
+    move |ctx, i| ctx.set_last(last.map(|l| l.values[i]))
+}
+
+fn inter_update_ctx<'a>(
+    last: Option<&'a RawMainFrame>,
+    last_last: Option<&'a RawMainFrame>,
+) -> impl Fn(&mut PredictorContext<'_, '_>, usize) + 'a {
+    move |ctx, i| {
+        ctx.set_last_2(last.map(|l| l.values[i]), last_last.map(|l| l.values[i]));
+    }
+}
+
+fn predict_inter_time(last: Option<&RawMainFrame>, last_last: Option<&RawMainFrame>) -> u64 {
+    // Get the time from last_last if last was an interframe
+    let last_last = last
+        .filter(|f| !f.intra)
+        .and_then(|_| last_last.map(|f| f.time));
+
+    predictor::straight_line(last.map(|f| f.time), last_last)
 }
 
 #[derive(Debug, Clone)]
@@ -176,7 +266,7 @@ pub(crate) struct MainFieldDef<'data> {
     encoding_intra: Encoding,
     encoding_inter: Encoding,
     pub(crate) signed: bool,
-    pub(crate) unit: MainUnit,
+    pub(crate) conversion: MainConversion,
 }
 
 #[derive(Debug)]
@@ -253,7 +343,19 @@ pub(crate) fn update(
         }
     }
 
-    pub(crate) fn parse(self) -> ParseResult<MainFrameDef<'data>> {
+    pub(crate) fn missing_headers(&self, missing: &mut Vec<String>) {
+        let (intra, inter) = (DataFrameKind::Intra, DataFrameKind::Inter);
+
+        frame::check_header(missing, intra, "name", self.names);
+        frame::check_header(missing, intra, "signed", self.signs);
+        frame::check_header(missing, intra, "predictor", self.predictors_intra);
+        frame::check_header(missing, inter, "predictor", self.predictors_inter);
+        frame::check_header(missing, intra, "encoding", self.encodings_intra);
+        frame::check_header(missing, inter, "encoding", self.encodings_inter);
+    }
+
+    /// `debug` is the meaning of each `debug[n]` field in the log's debug mode.
+    pub(crate) fn parse(self, debug: &[Option<DebugField>]) -> ParseResult<MainFrameDef<'data>> {
         let kind_intra = DataFrameKind::Intra;
         let kind_inter = DataFrameKind::Inter;
 
@@ -279,7 +381,7 @@ pub(crate) fn parse(self) -> ParseResult<MainFrameDef<'data>> {
                         encoding_intra: encoding_intra?,
                         encoding_inter: encoding_inter?,
                         signed,
-                        unit: unit_from_name(name),
+                        conversion: MainConversion::from_name(name, debug),
                     })
                 },
             );
@@ -344,12 +446,61 @@ pub(crate) fn parse(self) -> ParseResult<MainFrameDef<'data>> {
     }
 }
 
+/// How a raw main field value is converted into a
+/// [`MainValue`][super::MainValue].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum MainConversion {
+    Unit(MainUnit),
+    /// `setpoint[0..=2]`, already in deg/s
+    Setpoint,
+    /// `rcCommand[n]`, converted using the rate curve for axis `n`
+    Stick(usize),
+    /// `debug[n]`, if its meaning is known for the debug mode
+    Debug(DebugField),
+}
+
+impl MainConversion {
+    /// `debug` is the meaning of each `debug[n]` field, from
+    /// [`Headers::debug_fields`][crate::Headers::debug_fields].
+    pub(crate) fn from_name(name: &str, debug: &[Option<DebugField>]) -> Self {
+        match (to_base_field(name), field_index(name)) {
+            ("rcCommand", Some(axis @ 0..4)) => Self::Stick(axis),
+            ("setpoint", Some(0..3)) => Self::Setpoint,
+            ("debug", Some(index)) => debug
+                .get(index)
+                .copied()
+                .flatten()
+                .map_or(Self::Unit(MainUnit::Unitless), Self::Debug),
+            _ => Self::Unit(unit_from_name(name)),
+        }
+    }
+
+    pub(crate) const fn unit(self) -> MainUnit {
+        match self {
+            Self::Unit(unit) => unit,
+            Self::Setpoint => MainUnit::Rotation,
+            Self::Stick(_) => MainUnit::Stick,
+            Self::Debug(field) => field.unit,
+        }
+    }
+
+    pub(crate) const fn description(self) -> Option<&'static str> {
+        match self {
+            Self::Debug(field) => Some(field.name),
+            Self::Unit(_) | Self::Setpoint | Self::Stick(_) => None,
+        }
+    }
+}
+
 fn unit_from_name(name: &str) -> MainUnit {
     match to_base_field(name) {
         "vbat" | "vbatLatest" => MainUnit::Voltage,
         "amperageLatest" => MainUnit::Amperage,
         "accSmooth" => MainUnit::Acceleration,
-        "gyroADC" => MainUnit::Rotation,
+        "gyroADC" | "gyroData" => MainUnit::Rotation,
+        // Rotorflight's measured speeds and its governor's target headspeed
+        "headspeed" | "tailspeed" | "govHeadspeed" | "govTarget" => MainUnit::Frequency,
+        "motor" => MainUnit::Motor,
         _ => MainUnit::Unitless,
     }
 }
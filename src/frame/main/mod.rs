@@ -1,13 +1,17 @@
 mod def;
 
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 
 pub use self::def::*;
-use super::{DataFrameKind, FrameKind, Unit};
-use crate::data::MainFrameHistory;
+use super::{DataFrameKind, Frame as _, FrameContext, FrameKind, Unit};
+use crate::data::{MainFrameHistory, Span};
 use crate::filter::AppliedFilter;
+use crate::headers::DebugField;
 use crate::parser::InternalResult;
 use crate::units::prelude::*;
+use crate::units::{Stick, UnitContext};
+use crate::writer::EncodeError;
 use crate::{units, Headers, Reader};
 
 /// Data parsed from a main frame.
@@ -16,6 +20,8 @@ pub struct MainFrame<'data, 'headers, 'parser> {
     headers: &'headers Headers<'data>,
     raw: &'parser RawMainFrame,
     filter: &'parser AppliedFilter,
+    context: &'parser Arc<FrameContext>,
+    span: Span,
 }
 
 impl super::seal::Sealed for MainFrame<'_, '_, '_> {}
@@ -52,30 +58,12 @@ fn get(&self, index: usize) -> Option<MainValue> {
         let def = &frame_def.fields[index];
         let raw = self.raw.values[index];
 
-        let value = match def.unit {
-            MainUnit::Amperage => {
-                debug_assert!(def.signed);
-                let raw = raw.cast_signed();
-                MainValue::Amperage(units::new::current(raw))
-            }
-            MainUnit::Voltage => {
-                debug_assert!(!def.signed);
-                MainValue::Voltage(units::new::vbat(raw))
-            }
-            MainUnit::Acceleration => {
-                debug_assert!(def.signed);
-                let raw = raw.cast_signed();
-                MainValue::Acceleration(units::new::acceleration(raw, self.headers))
-            }
-            MainUnit::Rotation => {
-                debug_assert!(def.signed);
-                let raw = raw.cast_signed();
-                MainValue::Rotation(units::new::angular_velocity(raw, self.headers))
-            }
-            MainUnit::Unitless => MainValue::new_unitless(raw, def.signed),
-        };
-
-        Some(value)
+        Some(MainValue::new(
+            raw,
+            def.conversion,
+            def.signed,
+            &self.context.units,
+        ))
     }
 }
 
@@ -84,14 +72,33 @@ pub(crate) fn new(
         headers: &'headers Headers<'data>,
         raw: &'parser RawMainFrame,
         filter: &'parser AppliedFilter,
+        context: &'parser Arc<FrameContext>,
+        span: Span,
     ) -> Self {
         Self {
             headers,
             raw,
             filter,
+            context,
+            span,
         }
     }
 
+    /// Copies the frame so it no longer borrows the parser or headers.
+    pub fn into_owned(self) -> OwnedMainFrame {
+        OwnedMainFrame {
+            context: Arc::clone(self.context),
+            values: self.iter_raw().collect(),
+            iteration: self.raw.iteration,
+            time: self.raw.time,
+            span: self.span,
+        }
+    }
+
+    pub(crate) fn raw(&self) -> &RawMainFrame {
+        self.raw
+    }
+
     /// Returns the parsed time since power on.
     #[inline]
     pub fn time(&self) -> Time {
@@ -100,17 +107,104 @@ pub fn time(&self) -> Time {
 
     /// Returns the raw microsecond counter since power on.
     ///
-    /// **Note:** This does not currently handle overflow of the transmitted
-    /// 32bit counter. See [#54](https://github.com/blackbox-log/blackbox-log/issues/54).
+    /// Rollovers of the 32 bit counter written in the log are accounted for,
+    /// so this does not jump backwards after ~71 minutes.
     #[inline]
     pub fn time_raw(&self) -> u64 {
         self.raw.time
     }
+
+    /// Returns the offset of the start of this frame from the start of the
+    /// log.
+    ///
+    /// Add [`File::log_offset`][crate::File::log_offset] to get the offset
+    /// within the whole file.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.span.offset
+    }
+
+    /// Returns the length of this frame in bytes, including the frame kind
+    /// byte.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.span.size
+    }
+}
+
+/// An owned copy of a [`MainFrame`] that can outlive the parser that returned
+/// it. See [`MainFrame::into_owned`].
+#[derive(Debug, Clone)]
+pub struct OwnedMainFrame {
+    context: Arc<FrameContext>,
+    /// Only the fields that satisfy the filter
+    values: Vec<u32>,
+    iteration: u32,
+    time: u64,
+    span: Span,
+}
+
+impl super::seal::Sealed for OwnedMainFrame {}
+
+impl super::Frame for OwnedMainFrame {
+    type Value = MainValue;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[inline]
+    fn get_raw(&self, index: usize) -> Option<u32> {
+        self.values.get(index).copied()
+    }
+
+    fn get(&self, index: usize) -> Option<MainValue> {
+        let raw = *self.values.get(index)?;
+        let (conversion, signed) = self.context.main[index];
+        Some(MainValue::new(raw, conversion, signed, &self.context.units))
+    }
+}
+
+impl OwnedMainFrame {
+    /// Returns the value of `loopIteration`, even if it was excluded by the
+    /// filter.
+    #[inline]
+    pub fn iteration(&self) -> u32 {
+        self.iteration
+    }
+
+    /// Returns the parsed time since power on.
+    #[inline]
+    pub fn time(&self) -> Time {
+        units::new::time(self.time)
+    }
+
+    /// Returns the raw microsecond counter since power on. See
+    /// [`MainFrame::time_raw`].
+    #[inline]
+    pub fn time_raw(&self) -> u64 {
+        self.time
+    }
+
+    /// Returns the offset of the start of this frame from the start of the
+    /// log. See [`MainFrame::offset`].
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.span.offset
+    }
+
+    /// Returns the length of this frame in bytes, including the frame kind
+    /// byte.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.span.size
+    }
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct RawMainFrame {
-    intra: bool,
+    pub(crate) intra: bool,
     pub(crate) iteration: u32,
     pub(crate) time: u64,
     pub(crate) values: Vec<u32>,
@@ -127,26 +221,135 @@ pub(crate) fn parse(
         let def = headers.main_frame_def();
 
         if kind == FrameKind::Data(DataFrameKind::Intra) {
-            def.parse_intra(data, headers, last)
+            def.parse_intra(data, headers, last, history.last_time())
         } else {
-            let skipped = 0; // FIXME
+            let skipped = last.map_or(0, |last| {
+                headers.frame_intervals().skipped_after(last.iteration)
+            });
 
             def.parse_inter(data, headers, last, history.last_last(), skipped)
         }
     }
+
+    pub(crate) fn encode(
+        &self,
+        out: &mut Vec<u8>,
+        headers: &Headers,
+        history: &MainFrameHistory,
+    ) -> Result<(), EncodeError> {
+        let last = history.last();
+        let def = headers.main_frame_def();
+
+        if self.intra {
+            def.encode_intra(out, headers, self, last)
+        } else {
+            let skipped = last.map_or(0, |last| {
+                headers.frame_intervals().skipped_after(last.iteration)
+            });
+
+            def.encode_inter(out, headers, self, last, history.last_last(), skipped)
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MainValue {
     Amperage(ElectricCurrent),
     Voltage(ElectricPotential),
     Acceleration(Acceleration),
     Rotation(AngularVelocity),
+    Frequency(Frequency),
+    /// Motor output as a fraction of full throttle. The raw value is available
+    /// from [`Frame::get_raw`][super::Frame::get_raw].
+    Motor(Ratio),
+    Stick(Stick),
+    /// A fraction, such as the CPU load in some debug modes
+    Ratio(Ratio),
+    /// A duration, such as the loop time in some debug modes
+    Duration(Time),
     Unsigned(u32),
     Signed(i32),
 }
 
 impl MainValue {
+    pub(crate) fn new(
+        raw: u32,
+        conversion: MainConversion,
+        signed: bool,
+        context: &UnitContext,
+    ) -> Self {
+        let unit = match conversion {
+            MainConversion::Unit(unit) => unit,
+            MainConversion::Setpoint => {
+                return Self::Rotation(units::new::setpoint(raw.cast_signed()));
+            }
+            MainConversion::Stick(axis) => {
+                return Self::Stick(units::new::stick(raw.cast_signed(), axis, context));
+            }
+            MainConversion::Debug(field) => return Self::new_debug(raw, signed, field),
+        };
+
+        match unit {
+            MainUnit::Amperage => {
+                debug_assert!(signed);
+                let raw = raw.cast_signed();
+                Self::Amperage(units::new::current(raw))
+            }
+            MainUnit::Voltage => {
+                debug_assert!(!signed);
+                Self::Voltage(units::new::vbat(raw, context))
+            }
+            MainUnit::Acceleration => {
+                debug_assert!(signed);
+                let raw = raw.cast_signed();
+                Self::Acceleration(units::new::acceleration(raw, context))
+            }
+            MainUnit::Rotation => {
+                debug_assert!(signed);
+                let raw = raw.cast_signed();
+                Self::Rotation(units::new::angular_velocity(raw, context))
+            }
+            MainUnit::Frequency => {
+                debug_assert!(!signed);
+                Self::Frequency(units::new::frequency(raw))
+            }
+            MainUnit::Motor => {
+                let raw = if signed {
+                    raw.cast_signed().into()
+                } else {
+                    raw.into()
+                };
+                Self::Motor(units::new::motor(raw, context))
+            }
+            MainUnit::Stick | MainUnit::Ratio | MainUnit::Duration | MainUnit::Unitless => {
+                Self::new_unitless(raw, signed)
+            }
+        }
+    }
+
+    fn new_debug(raw: u32, signed: bool, field: DebugField) -> Self {
+        let value = if signed {
+            f64::from(raw.cast_signed())
+        } else {
+            f64::from(raw)
+        };
+        let value = value * field.scale;
+
+        match field.unit {
+            MainUnit::Rotation => Self::Rotation(AngularVelocity::new::<degree_per_second>(value)),
+            MainUnit::Frequency => Self::Frequency(Frequency::new::<hertz>(value)),
+            MainUnit::Ratio => Self::Ratio(Ratio::new::<percent>(value)),
+            MainUnit::Duration => Self::Duration(Time::new::<microsecond>(value)),
+            MainUnit::Amperage
+            | MainUnit::Voltage
+            | MainUnit::Acceleration
+            | MainUnit::Motor
+            | MainUnit::Stick
+            | MainUnit::Unitless => Self::new_unitless(raw, signed),
+        }
+    }
+
     const fn new_unitless(value: u32, signed: bool) -> Self {
         if signed {
             Self::Signed(value.cast_signed())
@@ -163,6 +366,11 @@ fn from(value: MainValue) -> Self {
             MainValue::Voltage(v) => Self::Voltage(v),
             MainValue::Acceleration(a) => Self::Acceleration(a),
             MainValue::Rotation(r) => Self::Rotation(r),
+            MainValue::Frequency(f) => Self::Frequency(f),
+            MainValue::Motor(m) => Self::Motor(m),
+            MainValue::Stick(s) => Self::Stick(s),
+            MainValue::Ratio(r) => Self::Ratio(r),
+            MainValue::Duration(d) => Self::Duration(d),
             MainValue::Unsigned(x) => Self::Unsigned(x),
             MainValue::Signed(x) => Self::Signed(x),
         }
@@ -170,11 +378,17 @@ fn from(value: MainValue) -> Self {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MainUnit {
     Amperage,
     Voltage,
     Acceleration,
     Rotation,
+    Frequency,
+    Motor,
+    Stick,
+    Ratio,
+    Duration,
     Unitless,
 }
 
@@ -185,6 +399,11 @@ fn from(unit: MainUnit) -> Self {
             MainUnit::Voltage => Self::Voltage,
             MainUnit::Acceleration => Self::Acceleration,
             MainUnit::Rotation => Self::Rotation,
+            MainUnit::Frequency => Self::Frequency,
+            MainUnit::Motor => Self::Motor,
+            MainUnit::Stick => Self::Stick,
+            MainUnit::Ratio => Self::Ratio,
+            MainUnit::Duration => Self::Duration,
             MainUnit::Unitless => Self::Unitless,
         }
     }
@@ -9,22 +9,25 @@
 pub(crate) mod slow;
 
 use alloc::borrow::ToOwned as _;
-use alloc::format;
+use alloc::string::String;
 use alloc::vec::Vec;
+use alloc::{format, vec};
 use core::fmt;
 use core::iter::{FusedIterator, Peekable};
 use core::marker::PhantomData;
 
-pub use self::gps::{GpsFrame, GpsFrameDef, GpsUnit, GpsValue};
+pub use self::gps::{GpsFrame, GpsFrameDef, GpsUnit, GpsValue, OwnedGpsFrame};
 pub(crate) use self::gps_home::{GpsHomeFrame, GpsPosition};
-pub use self::main::{MainFrame, MainFrameDef, MainUnit, MainValue};
-pub use self::slow::{SlowFrame, SlowFrameDef, SlowUnit, SlowValue};
+pub(crate) use self::main::MainConversion;
+pub use self::main::{MainFrame, MainFrameDef, MainUnit, MainValue, OwnedMainFrame};
+pub use self::slow::{OwnedSlowFrame, SlowFrame, SlowFrameDef, SlowUnit, SlowValue};
 use crate::filter::AppliedFilter;
 use crate::headers::{ParseError, ParseResult};
-use crate::parser::{Encoding, InternalResult};
+use crate::parser::{DataVersion, Encoding, InternalResult};
 use crate::predictor::{Predictor, PredictorContext};
 use crate::units::prelude::*;
-use crate::{units, Reader};
+use crate::units::UnitContext;
+use crate::{units, Headers, Reader};
 
 mod seal {
     pub trait Sealed {}
@@ -66,8 +69,19 @@ fn iter<'def>(&'def self) -> FieldDefIter<'data, 'def, Self>
 
 /// Metadata describing one field.
 #[derive(Debug, Clone, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FieldDef<'data, U> {
     pub name: &'data str,
+    /// What the field contains if its name does not say, such as `gyro raw
+    /// roll` for `debug[0]` in the `GYRO_SCALED` debug mode.
+    ///
+    /// **Note:** Only some debug modes are described: the gyro, accelerometer,
+    /// `CYCLETIME`, `ANGLERATE`, `FFT_FREQ`, `RPM_FILTER`, `D_MIN` and
+    /// `ITERM_RELAX` modes of Betaflight, `CYCLETIME` for EmuFlight and `VIBE`
+    /// for INAV. In any other mode, and in every mode for Rotorflight,
+    /// `debug[n]` fields have no description and are unitless.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub description: Option<&'data str>,
     pub unit: U,
     pub signed: bool,
 }
@@ -216,7 +230,46 @@ fn get<'def>(&'def self, index: usize) -> Option<FieldDef<'data, Self::Unit>>
     }
 }
 
+/// The parts of the headers and filters needed to convert the values of owned
+/// frames, so they can be shared between frames without borrowing the log.
+#[derive(Debug)]
+pub(crate) struct FrameContext {
+    pub(crate) units: UnitContext,
+    /// The conversion and signedness of each main field that satisfies the
+    /// filter
+    pub(crate) main: Vec<(MainConversion, bool)>,
+    pub(crate) slow: Vec<(SlowUnit, bool)>,
+    pub(crate) gps: Vec<(GpsUnit, bool)>,
+}
+
+impl FrameContext {
+    pub(crate) fn new<'data>(
+        headers: &Headers,
+        main: &FilteredFrameDef<'_, MainFrameDef<'data>>,
+        slow: &FilteredFrameDef<'_, SlowFrameDef<'data>>,
+        gps: Option<&FilteredFrameDef<'_, GpsFrameDef<'data>>>,
+    ) -> Self {
+        fn fields<'data, F: FrameDef<'data>>(def: &F) -> Vec<(F::Unit, bool)> {
+            def.iter().map(|field| (field.unit, field.signed)).collect()
+        }
+
+        Self {
+            units: UnitContext::new(headers),
+            main: main
+                .iter()
+                .map(|field| {
+                    let conversion = MainConversion::from_name(field.name, headers.debug_fields());
+                    (conversion, field.signed)
+                })
+                .collect(),
+            slow: fields(slow),
+            gps: gps.map(fields).unwrap_or_default(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum FrameKind {
     Event,
@@ -238,6 +291,12 @@ pub(crate) const fn from_byte(byte: u8) -> Option<Self> {
     }
 }
 
+impl From<DataFrameKind> for FrameKind {
+    fn from(kind: DataFrameKind) -> Self {
+        Self::Data(kind)
+    }
+}
+
 impl From<FrameKind> for char {
     fn from(kind: FrameKind) -> Self {
         match kind {
@@ -267,7 +326,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 
 byte_enum! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-    #[cfg_attr(feature = "_serde", derive(serde::Serialize))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum DataFrameKind {
         Intra = b'I',
@@ -325,12 +384,17 @@ trait FieldDefDetails<'data> {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "_serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Unit {
     Amperage,
     Voltage,
     Acceleration,
     Rotation,
+    Frequency,
+    Motor,
+    Stick,
+    Ratio,
+    Duration,
     FlightMode,
     State,
     FailsafePhase,
@@ -343,11 +407,18 @@ pub enum Unit {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Value {
     Amperage(ElectricCurrent),
     Voltage(ElectricPotential),
     Acceleration(Acceleration),
     Rotation(AngularVelocity),
+    Frequency(Frequency),
+    /// Motor output as a fraction of full throttle
+    Motor(Ratio),
+    Stick(units::Stick),
+    Ratio(Ratio),
+    Duration(Time),
     FlightMode(units::FlightModeSet),
     State(units::StateSet),
     FailsafePhase(units::FailsafePhase),
@@ -360,6 +431,24 @@ pub enum Value {
     Signed(i32),
 }
 
+/// Extends a raw 32 bit microsecond timestamp to 64 bits, using the previous
+/// timestamp to detect if the counter has rolled over in either direction.
+///
+/// Out of the epochs before, at, and after the one containing `last`, this
+/// picks the one that puts the time closest to `last`.
+pub(crate) fn unwrap_time(raw: u32, last: Option<u64>) -> u64 {
+    let Some(last) = last else {
+        return raw.into();
+    };
+
+    let rollovers = last >> 32;
+    let max_rollovers = u64::from(u32::MAX);
+    (rollovers.saturating_sub(1)..=rollovers.saturating_add(1).min(max_rollovers))
+        .map(|epoch| (epoch << 32) | u64::from(raw))
+        .min_by_key(|time| time.abs_diff(last))
+        .unwrap_or(raw.into())
+}
+
 pub(crate) fn is_frame_def_header(header: &str) -> bool {
     parse_frame_def_header(header).is_some()
 }
@@ -395,9 +484,27 @@ pub(crate) fn from_name(s: &str) -> Option<Self> {
     }
 }
 
+fn header_name(kind: DataFrameKind, property: &str) -> String {
+    format!("Field {} {property}", char::from(kind))
+}
+
+/// Adds the name of the `Field <kind> <property>` header to `missing` if its
+/// `value` was not found.
+pub(crate) fn check_header(
+    missing: &mut Vec<String>,
+    kind: DataFrameKind,
+    property: &str,
+    value: Option<&str>,
+) {
+    if value.is_none() {
+        missing.push(header_name(kind, property));
+    }
+}
+
 fn missing_header_error(kind: DataFrameKind, property: &'static str) -> ParseError {
-    tracing::error!("missing header `Field {} {property}`", char::from(kind));
-    ParseError::MissingHeader
+    let header = header_name(kind, property);
+    tracing::error!("missing header `{header}`");
+    ParseError::MissingHeaders(vec![header])
 }
 
 fn parse_names(
@@ -417,7 +524,7 @@ fn parse_enum_list<'a, T>(
     let s = s.ok_or_else(|| missing_header_error(kind, property))?;
     Ok(s.split(',').map(move |s| {
         parse(s).ok_or_else(|| ParseError::InvalidHeader {
-            header: format!("Field {} {property}", char::from(kind)),
+            header: header_name(kind, property),
             value: s.to_owned(),
         })
     }))
@@ -461,6 +568,7 @@ fn count_fields_with_same_encoding(
 
 fn read_field_values<T>(
     data: &mut Reader,
+    version: DataVersion,
     fields: &[T],
     get_encoding: impl Fn(&T) -> Encoding,
 ) -> InternalResult<Vec<u32>> {
@@ -471,7 +579,7 @@ fn read_field_values<T>(
         let extra = encoding.max_chunk_size() - 1;
         let extra = count_fields_with_same_encoding(&mut encodings, extra, encoding);
 
-        encoding.decode_into(data, extra, &mut values)?;
+        encoding.decode_into(data, version, extra, &mut values)?;
     }
 
     debug_assert_eq!(values.len(), fields.len());
@@ -479,6 +587,34 @@ fn read_field_values<T>(
     Ok(values)
 }
 
+/// The inverse of [`read_field_values`], returning the index of the first value
+/// that could not be encoded.
+fn write_field_values<T>(
+    out: &mut Vec<u8>,
+    version: DataVersion,
+    fields: &[T],
+    get_encoding: impl Fn(&T) -> Encoding,
+    values: &[u32],
+) -> Result<(), usize> {
+    debug_assert_eq!(values.len(), fields.len());
+
+    let mut encodings = fields.iter().map(get_encoding).peekable();
+    let mut start = 0;
+
+    while let Some(encoding) = encodings.next() {
+        let extra = encoding.max_chunk_size() - 1;
+        let extra = count_fields_with_same_encoding(&mut encodings, extra, encoding);
+        let end = start + extra + 1;
+
+        encoding
+            .encode_into(out, version, &values[start..end])
+            .map_err(|i| start + i)?;
+        start = end;
+    }
+
+    Ok(())
+}
+
 fn parse_impl<'data, F: FieldDefDetails<'data>>(
     mut ctx: PredictorContext<'_, 'data>,
     raw: &[u32],
@@ -511,3 +647,66 @@ fn parse_impl<'data, F: FieldDefDetails<'data>>(
 
     values
 }
+
+/// The inverse of [`parse_impl`], returning the raw values to be written.
+fn encode_impl<'data, F: FieldDefDetails<'data>>(
+    mut ctx: PredictorContext<'_, 'data>,
+    values: &[u32],
+    fields: impl IntoIterator<Item = F>,
+    update_ctx: impl Fn(&mut PredictorContext<'_, 'data>, usize),
+) -> Vec<u32> {
+    fields
+        .into_iter()
+        .enumerate()
+        .map(|(i, field)| {
+            update_ctx(&mut ctx, i);
+
+            let signed = field.encoding().is_signed();
+            field
+                .predictor()
+                .unapply(values[i], signed, Some(&values[..i]), &ctx)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROLLOVER: u64 = 1 << 32;
+
+    #[test]
+    fn unwrap_time_first() {
+        assert_eq!(5, unwrap_time(5, None));
+        assert_eq!(u64::from(u32::MAX), unwrap_time(u32::MAX, None));
+    }
+
+    #[test]
+    fn unwrap_time_forwards() {
+        assert_eq!(200, unwrap_time(200, Some(100)));
+        assert_eq!(ROLLOVER + 200, unwrap_time(200, Some(ROLLOVER + 100)));
+    }
+
+    #[test]
+    fn unwrap_time_rollover() {
+        let last = u64::from(u32::MAX - 99);
+        assert_eq!(ROLLOVER + 50, unwrap_time(50, Some(last)));
+        assert_eq!(2 * ROLLOVER + 50, unwrap_time(50, Some(ROLLOVER + last)));
+    }
+
+    #[test]
+    fn unwrap_time_backwards() {
+        // Too far to be a rollover, so assume the time went backwards instead
+        assert_eq!(50, unwrap_time(50, Some(1_000)));
+        assert_eq!(50, unwrap_time(50, Some(u64::from(u32::MAX / 2))));
+    }
+
+    #[test]
+    fn unwrap_time_backwards_rollover() {
+        // A small step back across a rollover stays in the previous epoch
+        let last = ROLLOVER + 10;
+        let raw = u32::MAX - 5;
+        assert_eq!(ROLLOVER - 6, unwrap_time(raw, Some(last)));
+        assert_eq!(ROLLOVER + 5, unwrap_time(5, Some(last)));
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::string::String;
 use alloc::vec::Vec;
 
 use tracing::instrument;
@@ -7,6 +8,7 @@
 use crate::headers::{ParseError, ParseResult};
 use crate::parser::{Encoding, InternalResult};
 use crate::predictor::{Predictor, PredictorContext};
+use crate::writer::EncodeError;
 use crate::{Headers, Reader, Unit};
 
 /// The parsed frame definition for slow frames.
@@ -32,7 +34,12 @@ fn get<'a>(&'a self, index: usize) -> Option<FieldDef<'data, Self::Unit>>
         self.fields.get(index).map(
             |&SlowFieldDef {
                  name, unit, signed, ..
-             }| FieldDef { name, unit, signed },
+             }| FieldDef {
+                name,
+                description: None,
+                unit,
+                signed,
+            },
         )
     }
 }
@@ -69,16 +76,42 @@ pub(crate) fn parse(
         &self,
         data: &mut Reader,
         headers: &Headers,
+        last_main_time: Option<u64>,
     ) -> InternalResult<RawSlowFrame> {
         let values = frame::parse_impl(
-            PredictorContext::new(headers),
-            &frame::read_field_values(data, &self.fields, |f| f.encoding)?,
+            PredictorContext::new(headers, last_main_time),
+            &frame::read_field_values(data, headers.data_version, &self.fields, |f| f.encoding)?,
             self.fields.iter(),
             |_, _| {},
         );
 
         Ok(RawSlowFrame(values))
     }
+
+    #[instrument(level = "trace", name = "SlowFrameDef::encode", skip_all)]
+    pub(crate) fn encode(
+        &self,
+        out: &mut Vec<u8>,
+        headers: &Headers,
+        frame: &RawSlowFrame,
+        last_main_time: Option<u64>,
+    ) -> Result<(), EncodeError> {
+        let raw = frame::encode_impl(
+            PredictorContext::new(headers, last_main_time),
+            &frame.0,
+            self.fields.iter(),
+            |_, _| {},
+        );
+
+        frame::write_field_values(
+            out,
+            headers.data_version,
+            &self.fields,
+            |f| f.encoding,
+            &raw,
+        )
+        .map_err(|i| EncodeError::invalid(DataFrameKind::Slow, self.fields[i].name))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -128,9 +161,30 @@ pub(crate) fn update(&mut self, property: DataFrameProperty, value: &'data str)
         }
     }
 
+    /// Returns `true` if none of the slow frame definition headers were found.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.names.is_none()
+            && self.predictors.is_none()
+            && self.encodings.is_none()
+            && self.signs.is_none()
+    }
+
+    pub(crate) fn missing_headers(&self, missing: &mut Vec<String>) {
+        let kind = DataFrameKind::Slow;
+
+        frame::check_header(missing, kind, "name", self.names);
+        frame::check_header(missing, kind, "signed", self.signs);
+        frame::check_header(missing, kind, "predictor", self.predictors);
+        frame::check_header(missing, kind, "encoding", self.encodings);
+    }
+
     pub(crate) fn parse(self) -> ParseResult<SlowFrameDef<'data>> {
         let kind = DataFrameKind::Slow;
 
+        if self.is_empty() {
+            return Ok(SlowFrameDef { fields: Vec::new() });
+        }
+
         let mut names = frame::parse_names(kind, self.names)?;
         let mut predictors = frame::parse_predictors(kind, self.predictors)?;
         let mut encodings = frame::parse_encodings(kind, self.encodings)?;
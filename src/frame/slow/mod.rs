@@ -1,10 +1,13 @@
 mod def;
 
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 
 pub use self::def::*;
-use super::Unit;
+use super::{Frame as _, FrameContext, Unit};
+use crate::data::Span;
 use crate::filter::AppliedFilter;
+use crate::units::UnitContext;
 use crate::{units, Headers};
 
 /// Data parsed from a slow frame.
@@ -16,6 +19,8 @@ pub struct SlowFrame<'data, 'headers, 'parser> {
     headers: &'headers Headers<'data>,
     raw: RawSlowFrame,
     filter: &'parser AppliedFilter,
+    context: &'parser Arc<FrameContext>,
+    span: Span,
 }
 
 impl super::seal::Sealed for SlowFrame<'_, '_, '_> {}
@@ -40,24 +45,12 @@ fn get(&self, index: usize) -> Option<Self::Value> {
         let def = &frame_def.fields[index];
         let raw = self.raw.0[index];
 
-        let firmware = self.headers.internal_firmware;
-        let value = match def.unit {
-            SlowUnit::FlightMode => SlowValue::FlightMode(units::FlightModeSet::new(raw, firmware)),
-            SlowUnit::State => SlowValue::State(units::StateSet::new(raw, firmware)),
-            SlowUnit::FailsafePhase => {
-                SlowValue::FailsafePhase(units::FailsafePhase::new(raw, firmware))
-            }
-            SlowUnit::Boolean => {
-                if raw > 1 {
-                    tracing::debug!("invalid boolean ({raw:0>#8x})");
-                }
-
-                SlowValue::Boolean(raw != 0)
-            }
-            SlowUnit::Unitless => SlowValue::new_unitless(raw, def.signed),
-        };
-
-        Some(value)
+        Some(SlowValue::new(
+            raw,
+            def.unit,
+            def.signed,
+            &self.context.units,
+        ))
     }
 }
 
@@ -66,21 +59,104 @@ pub(crate) fn new(
         headers: &'headers Headers<'data>,
         raw: RawSlowFrame,
         filter: &'parser AppliedFilter,
+        context: &'parser Arc<FrameContext>,
+        span: Span,
     ) -> Self {
         Self {
             headers,
             raw,
             filter,
+            context,
+            span,
         }
     }
+
+    /// Copies the frame so it no longer borrows the parser or headers.
+    pub fn into_owned(self) -> OwnedSlowFrame {
+        OwnedSlowFrame {
+            context: Arc::clone(self.context),
+            values: self.iter_raw().collect(),
+            span: self.span,
+        }
+    }
+
+    pub(crate) fn raw(&self) -> &RawSlowFrame {
+        &self.raw
+    }
+
+    /// Returns the offset of the start of this frame from the start of the
+    /// log.
+    ///
+    /// Add [`File::log_offset`][crate::File::log_offset] to get the offset
+    /// within the whole file.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.span.offset
+    }
+
+    /// Returns the length of this frame in bytes, including the frame kind
+    /// byte.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.span.size
+    }
+}
+
+/// An owned copy of a [`SlowFrame`] that can outlive the parser that returned
+/// it. See [`SlowFrame::into_owned`].
+#[derive(Debug, Clone)]
+pub struct OwnedSlowFrame {
+    context: Arc<FrameContext>,
+    /// Only the fields that satisfy the filter
+    values: Vec<u32>,
+    span: Span,
+}
+
+impl super::seal::Sealed for OwnedSlowFrame {}
+
+impl super::Frame for OwnedSlowFrame {
+    type Value = SlowValue;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[inline]
+    fn get_raw(&self, index: usize) -> Option<u32> {
+        self.values.get(index).copied()
+    }
+
+    fn get(&self, index: usize) -> Option<Self::Value> {
+        let raw = *self.values.get(index)?;
+        let (unit, signed) = self.context.slow[index];
+        Some(SlowValue::new(raw, unit, signed, &self.context.units))
+    }
+}
+
+impl OwnedSlowFrame {
+    /// Returns the offset of the start of this frame from the start of the
+    /// log. See [`SlowFrame::offset`].
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.span.offset
+    }
+
+    /// Returns the length of this frame in bytes, including the frame kind
+    /// byte.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.span.size
+    }
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct RawSlowFrame(Vec<u32>);
+pub(crate) struct RawSlowFrame(pub(crate) Vec<u32>);
 
 impl RawSlowFrame {}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SlowValue {
     FlightMode(units::FlightModeSet),
     State(units::StateSet),
@@ -91,6 +167,25 @@ pub enum SlowValue {
 }
 
 impl SlowValue {
+    fn new(raw: u32, unit: SlowUnit, signed: bool, context: &UnitContext) -> Self {
+        let firmware = context.firmware;
+        match unit {
+            SlowUnit::FlightMode => Self::FlightMode(units::FlightModeSet::new(raw, firmware)),
+            SlowUnit::State => Self::State(units::StateSet::new(raw, firmware)),
+            SlowUnit::FailsafePhase => {
+                Self::FailsafePhase(units::FailsafePhase::new(raw, firmware))
+            }
+            SlowUnit::Boolean => {
+                if raw > 1 {
+                    tracing::debug!("invalid boolean ({raw:0>#8x})");
+                }
+
+                Self::Boolean(raw != 0)
+            }
+            SlowUnit::Unitless => Self::new_unitless(raw, signed),
+        }
+    }
+
     const fn new_unitless(value: u32, signed: bool) -> Self {
         if signed {
             Self::Signed(value.cast_signed())
@@ -114,6 +209,7 @@ fn from(value: SlowValue) -> Self {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SlowUnit {
     FlightMode,
     State,
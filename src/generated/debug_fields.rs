@@ -0,0 +1,272 @@
+/// Returns the meaning of each `debug[n]` field in a debug mode.
+#[allow(
+    unused_qualifications,
+    clippy::enum_glob_use,
+    clippy::match_same_arms,
+    clippy::unseparated_literal_suffix
+)]
+pub(crate) const fn debug_fields(
+    mode: DebugMode,
+    fw: crate::headers::InternalFirmware,
+) -> &'static [Option<DebugField>] {
+    use crate::headers::InternalFirmware::*;
+    match (mode, fw) {
+        (
+            DebugMode::Accelerometer,
+            Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+            | Betaflight4_4 | Betaflight4_5 | Betaflight4_6,
+        ) => &[
+            Some(DebugField {
+                name: "acc raw x",
+                unit: crate::frame::MainUnit::Unitless,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "acc raw y",
+                unit: crate::frame::MainUnit::Unitless,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "acc raw z",
+                unit: crate::frame::MainUnit::Unitless,
+                scale: 1f64,
+            }),
+        ],
+        (
+            DebugMode::Anglerate,
+            Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+            | Betaflight4_4,
+        ) => &[
+            Some(DebugField {
+                name: "setpoint roll",
+                unit: crate::frame::MainUnit::Rotation,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "setpoint pitch",
+                unit: crate::frame::MainUnit::Rotation,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "setpoint yaw",
+                unit: crate::frame::MainUnit::Rotation,
+                scale: 1f64,
+            }),
+        ],
+        (
+            DebugMode::Cycletime,
+            Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+            | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+        ) => &[
+            Some(DebugField {
+                name: "cycle time",
+                unit: crate::frame::MainUnit::Duration,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "cpu load",
+                unit: crate::frame::MainUnit::Ratio,
+                scale: 1f64,
+            }),
+        ],
+        (DebugMode::DMin, Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4) => &[
+            Some(DebugField {
+                name: "d min gyro factor roll",
+                unit: crate::frame::MainUnit::Unitless,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "d min setpoint factor roll",
+                unit: crate::frame::MainUnit::Unitless,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "d roll",
+                unit: crate::frame::MainUnit::Unitless,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "d pitch",
+                unit: crate::frame::MainUnit::Unitless,
+                scale: 1f64,
+            }),
+        ],
+        (DebugMode::FftFreq, Betaflight4_0 | Betaflight4_1 | Betaflight4_2) => &[
+            Some(DebugField {
+                name: "dyn notch center roll",
+                unit: crate::frame::MainUnit::Frequency,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "dyn notch center pitch",
+                unit: crate::frame::MainUnit::Frequency,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "dyn notch center yaw",
+                unit: crate::frame::MainUnit::Frequency,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "gyro pre dyn notch roll",
+                unit: crate::frame::MainUnit::Rotation,
+                scale: 1f64,
+            }),
+        ],
+        (DebugMode::FftFreq, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => &[
+            Some(DebugField {
+                name: "dyn notch 1 center",
+                unit: crate::frame::MainUnit::Frequency,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "dyn notch 2 center",
+                unit: crate::frame::MainUnit::Frequency,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "dyn notch 3 center",
+                unit: crate::frame::MainUnit::Frequency,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "gyro pre dyn notch",
+                unit: crate::frame::MainUnit::Rotation,
+                scale: 1f64,
+            }),
+        ],
+        (
+            DebugMode::GyroFiltered,
+            Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+            | Betaflight4_5 | Betaflight4_6,
+        ) => &[
+            Some(DebugField {
+                name: "gyro filtered roll",
+                unit: crate::frame::MainUnit::Rotation,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "gyro filtered pitch",
+                unit: crate::frame::MainUnit::Rotation,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "gyro filtered yaw",
+                unit: crate::frame::MainUnit::Rotation,
+                scale: 1f64,
+            }),
+        ],
+        (
+            DebugMode::GyroRaw,
+            Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+            | Betaflight4_4 | Betaflight4_5 | Betaflight4_6,
+        ) => &[
+            Some(DebugField {
+                name: "gyro adc roll",
+                unit: crate::frame::MainUnit::Unitless,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "gyro adc pitch",
+                unit: crate::frame::MainUnit::Unitless,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "gyro adc yaw",
+                unit: crate::frame::MainUnit::Unitless,
+                scale: 1f64,
+            }),
+        ],
+        (
+            DebugMode::GyroScaled,
+            Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+            | Betaflight4_5 | Betaflight4_6,
+        ) => &[
+            Some(DebugField {
+                name: "gyro raw roll",
+                unit: crate::frame::MainUnit::Rotation,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "gyro raw pitch",
+                unit: crate::frame::MainUnit::Rotation,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "gyro raw yaw",
+                unit: crate::frame::MainUnit::Rotation,
+                scale: 1f64,
+            }),
+        ],
+        (
+            DebugMode::ItermRelax,
+            Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+            | Betaflight4_5 | Betaflight4_6,
+        ) => &[
+            Some(DebugField {
+                name: "setpoint high pass roll",
+                unit: crate::frame::MainUnit::Rotation,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "iterm relax factor roll",
+                unit: crate::frame::MainUnit::Ratio,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "iterm relaxed error roll",
+                unit: crate::frame::MainUnit::Rotation,
+                scale: 1f64,
+            }),
+        ],
+        (
+            DebugMode::RpmFilter,
+            Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+            | Betaflight4_6,
+        ) => &[
+            Some(DebugField {
+                name: "motor 1 frequency",
+                unit: crate::frame::MainUnit::Frequency,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "motor 2 frequency",
+                unit: crate::frame::MainUnit::Frequency,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "motor 3 frequency",
+                unit: crate::frame::MainUnit::Frequency,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "motor 4 frequency",
+                unit: crate::frame::MainUnit::Frequency,
+                scale: 1f64,
+            }),
+        ],
+        (DebugMode::Vibe, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => &[
+            Some(DebugField {
+                name: "vibration x",
+                unit: crate::frame::MainUnit::Unitless,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "vibration y",
+                unit: crate::frame::MainUnit::Unitless,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "vibration z",
+                unit: crate::frame::MainUnit::Unitless,
+                scale: 1f64,
+            }),
+            Some(DebugField {
+                name: "acc clipping count",
+                unit: crate::frame::MainUnit::Unitless,
+                scale: 1f64,
+            }),
+        ],
+        _ => &[],
+    }
+}
@@ -1,5 +1,5 @@
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "_serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// The configured debugging info for a log.
 #[non_exhaustive]
 pub enum DebugMode {
@@ -19,6 +19,8 @@ pub enum DebugMode {
     AdcInternal,
     /// `AGL`
     Agl,
+    /// `AIRMODE`
+    Airmode,
     /// `ALTITUDE`
     Altitude,
     /// `ALWAYS`
@@ -39,12 +41,20 @@ pub enum DebugMode {
     AutoTrim,
     /// `AUTOTUNE`
     AutoTune,
+    /// `AUTOPILOT_ALTITUDE`
+    AutopilotAltitude,
+    /// `AUTOPILOT_POSITION`
+    AutopilotPosition,
     /// `BARO`
     Baro,
     /// `BATTERY`
     Battery,
     /// `BLACKBOX_OUTPUT`
     BlackboxOutput,
+    /// `CHIRP`
+    Chirp,
+    /// `CORE_TEMP`
+    CoreTemp,
     /// `CRSF_LINK_STATISTICS_DOWN`
     CrsfLinkStatisticsDown,
     /// `CRSF_LINK_STATISTICS_PWR`
@@ -69,6 +79,12 @@ pub enum DebugMode {
     DshotRpmTelemetry,
     /// `DSHOT_TELEMETRY_COUNTS`
     DshotTelemetryCounts,
+    /// `DTERM_FILTER`
+    DtermFilter,
+    /// `DUAL_GYRO`
+    DualGyro,
+    /// `DUAL_GYRO_COMBINE`
+    DualGyroCombine,
     /// `DUAL_GYRO_DIFF`
     DualGyroDiff,
     /// `DUAL_GYRO_RAW`
@@ -111,6 +127,8 @@ pub enum DebugMode {
     FftFreq,
     /// `FFT_TIME`
     FftTime,
+    /// `FLASH_TEST_PRBS`
+    FlashTestPrbs,
     /// `FLOW`
     Flow,
     /// `FLOW_RAW`
@@ -121,6 +139,8 @@ pub enum DebugMode {
     Ghst,
     /// `GHST_MSP`
     GhstMsp,
+    /// `GIMBAL`
+    Gimbal,
     /// `GPS`
     Gps,
     /// `GPS_CONNECTION`
@@ -167,14 +187,20 @@ pub enum DebugMode {
     MagCalib,
     /// `MAG_TASK_RATE`
     MagTaskRate,
+    /// `MAVLINK_TELEMETRY`
+    MavlinkTelemetry,
     /// `MAX7456_SIGNAL`
     Max7456Signal,
     /// `MAX7456_SPICLOCK`
     Max7456Spiclock,
+    /// `MIXER`
+    Mixer,
     /// `NAV_YAW`
     NavYaw,
     /// `NONE`
     None,
+    /// `NOTCH`
+    Notch,
     /// `PCF8574`
     Pcf8574,
     /// `PID_MEASUREMENT`
@@ -225,6 +251,8 @@ pub enum DebugMode {
     RxStateTime,
     /// `RX_TIMING`
     RxTiming,
+    /// `S_TERM`
+    STerm,
     /// `SAG_COMP_VOLTAGE`
     SagCompVoltage,
     /// `SBUS`
@@ -241,6 +269,8 @@ pub enum DebugMode {
     Smartaudio,
     /// `SMITH_PREDICTOR`
     SmithPredictor,
+    /// `SPA`
+    Spa,
     /// `SPM_CELLS`
     SpmCells,
     /// `SPM_VARIO`
@@ -249,16 +279,24 @@ pub enum DebugMode {
     SpmVs600,
     /// `STACK`
     Stack,
+    /// `TASK`
+    Task,
     /// `TIMING_ACCURACY`
     TimingAccuracy,
+    /// `TPA`
+    Tpa,
     /// `USB`
     Usb,
+    /// `VELOCITY`
+    Velocity,
     /// `VIBE`
     Vibe,
     /// `VTX_MSP`
     VtxMsp,
     /// `VTX_TRAMP`
     VtxTramp,
+    /// `WING_SETPOINT`
+    WingSetpoint,
 }
 #[allow(unused_qualifications)]
 impl crate::units::Flag for DebugMode {
@@ -272,6 +310,7 @@ fn as_name(&self) -> &'static str {
             Self::AdaptiveFilter => "ADAPTIVE_FILTER",
             Self::AdcInternal => "ADC_INTERNAL",
             Self::Agl => "AGL",
+            Self::Airmode => "AIRMODE",
             Self::Altitude => "ALTITUDE",
             Self::Always => "ALWAYS",
             Self::AngleMode => "ANGLE_MODE",
@@ -282,9 +321,13 @@ fn as_name(&self) -> &'static str {
             Self::AutoLevel => "AUTOLEVEL",
             Self::AutoTrim => "AUTOTRIM",
             Self::AutoTune => "AUTOTUNE",
+            Self::AutopilotAltitude => "AUTOPILOT_ALTITUDE",
+            Self::AutopilotPosition => "AUTOPILOT_POSITION",
             Self::Baro => "BARO",
             Self::Battery => "BATTERY",
             Self::BlackboxOutput => "BLACKBOX_OUTPUT",
+            Self::Chirp => "CHIRP",
+            Self::CoreTemp => "CORE_TEMP",
             Self::CrsfLinkStatisticsDown => "CRSF_LINK_STATISTICS_DOWN",
             Self::CrsfLinkStatisticsPwr => "CRSF_LINK_STATISTICS_PWR",
             Self::CrsfLinkStatisticsUplink => "CRSF_LINK_STATISTICS_UPLINK",
@@ -297,6 +340,9 @@ fn as_name(&self) -> &'static str {
             Self::DshotRpmErrors => "DSHOT_RPM_ERRORS",
             Self::DshotRpmTelemetry => "DSHOT_RPM_TELEMETRY",
             Self::DshotTelemetryCounts => "DSHOT_TELEMETRY_COUNTS",
+            Self::DtermFilter => "DTERM_FILTER",
+            Self::DualGyro => "DUAL_GYRO",
+            Self::DualGyroCombine => "DUAL_GYRO_COMBINE",
             Self::DualGyroDiff => "DUAL_GYRO_DIFF",
             Self::DualGyroRaw => "DUAL_GYRO_RAW",
             Self::DualGyroScaled => "DUAL_GYRO_SCALED",
@@ -318,11 +364,13 @@ fn as_name(&self) -> &'static str {
             Self::Fft => "FFT",
             Self::FftFreq => "FFT_FREQ",
             Self::FftTime => "FFT_TIME",
+            Self::FlashTestPrbs => "FLASH_TEST_PRBS",
             Self::Flow => "FLOW",
             Self::FlowRaw => "FLOW_RAW",
             Self::Fport => "FPORT",
             Self::Ghst => "GHST",
             Self::GhstMsp => "GHST_MSP",
+            Self::Gimbal => "GIMBAL",
             Self::Gps => "GPS",
             Self::GpsConnection => "GPS_CONNECTION",
             Self::GpsDop => "GPS_DOP",
@@ -346,10 +394,13 @@ fn as_name(&self) -> &'static str {
             Self::Lulu => "LULU",
             Self::MagCalib => "MAG_CALIB",
             Self::MagTaskRate => "MAG_TASK_RATE",
+            Self::MavlinkTelemetry => "MAVLINK_TELEMETRY",
             Self::Max7456Signal => "MAX7456_SIGNAL",
             Self::Max7456Spiclock => "MAX7456_SPICLOCK",
+            Self::Mixer => "MIXER",
             Self::NavYaw => "NAV_YAW",
             Self::None => "NONE",
+            Self::Notch => "NOTCH",
             Self::Pcf8574 => "PCF8574",
             Self::PidMeasurement => "PID_MEASUREMENT",
             Self::Pidloop => "PIDLOOP",
@@ -375,6 +426,7 @@ fn as_name(&self) -> &'static str {
             Self::RxSpektrumSpi => "RX_SPEKTRUM_SPI",
             Self::RxStateTime => "RX_STATE_TIME",
             Self::RxTiming => "RX_TIMING",
+            Self::STerm => "S_TERM",
             Self::SagCompVoltage => "SAG_COMP_VOLTAGE",
             Self::Sbus => "SBUS",
             Self::Sbus2 => "SBUS2",
@@ -383,15 +435,20 @@ fn as_name(&self) -> &'static str {
             Self::Sdio => "SDIO",
             Self::Smartaudio => "SMARTAUDIO",
             Self::SmithPredictor => "SMITH_PREDICTOR",
+            Self::Spa => "SPA",
             Self::SpmCells => "SPM_CELLS",
             Self::SpmVario => "SPM_VARIO",
             Self::SpmVs600 => "SPM_VS600",
             Self::Stack => "STACK",
+            Self::Task => "TASK",
             Self::TimingAccuracy => "TIMING_ACCURACY",
+            Self::Tpa => "TPA",
             Self::Usb => "USB",
+            Self::Velocity => "VELOCITY",
             Self::Vibe => "VIBE",
             Self::VtxMsp => "VTX_MSP",
             Self::VtxTramp => "VTX_TRAMP",
+            Self::WingSetpoint => "WING_SETPOINT",
         }
     }
 }
@@ -414,289 +471,528 @@ pub(crate) fn new(raw: u32, fw: crate::headers::InternalFirmware) -> Option<Self
         match (raw, fw) {
             (
                 0u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4
+                | Rotorflight4_5,
             ) => Some(Self::None),
-            (1u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Cycletime)
-            }
+            (
+                1u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Cycletime),
             (1u32, Inav5) => Some(Self::Gyro),
-            (1u32, Inav6 | Inav7 | Inav8) => Some(Self::Agl),
-            (2u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Battery)
-            }
+            (1u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Agl),
+            (
+                2u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Battery),
             (2u32, Inav5) => Some(Self::Agl),
-            (2u32, Inav6 | Inav7 | Inav8) => Some(Self::FlowRaw),
-            (3u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::GyroFiltered)
-            }
+            (2u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::FlowRaw),
+            (3u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Gyro),
+            (
+                3u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::GyroFiltered),
             (3u32, Inav5) => Some(Self::FlowRaw),
-            (3u32, Inav6 | Inav7 | Inav8) => Some(Self::Flow),
-            (4u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Accelerometer)
-            }
+            (3u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Flow),
+            (
+                4u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Accelerometer),
             (4u32, Inav5) => Some(Self::Flow),
-            (4u32, Inav6 | Inav7 | Inav8) => Some(Self::Always),
-            (5u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Pidloop)
-            }
+            (4u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Always),
+            (5u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Mixer),
+            (
+                5u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Pidloop),
             (5u32, Inav5) => Some(Self::Sbus),
-            (5u32, Inav6 | Inav7 | Inav8) => Some(Self::SagCompVoltage),
-            (6u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::GyroScaled)
-            }
+            (5u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::SagCompVoltage),
+            (6u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Airmode),
+            (
+                6u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::GyroScaled),
             (6u32, Inav5) => Some(Self::Fport),
-            (6u32, Inav6 | Inav7 | Inav8) => Some(Self::Vibe),
-            (7u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::RcInterpolation)
-            }
+            (6u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Vibe),
+            (7u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Pidloop),
+            (
+                7u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::RcInterpolation),
             (7u32, Inav5) => Some(Self::Always),
-            (7u32, Inav6 | Inav7 | Inav8) => Some(Self::Cruise),
-            (8u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Anglerate)
-            }
+            (7u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Cruise),
+            (8u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Notch),
+            (
+                8u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Anglerate),
             (8u32, Inav5) => Some(Self::SagCompVoltage),
-            (8u32, Inav6 | Inav7 | Inav8) => Some(Self::RemFlightTime),
-            (9u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::EscSensor)
-            }
+            (8u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::RemFlightTime),
+            (9u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::RcInterpolation),
+            (
+                9u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::EscSensor),
             (9u32, Inav5) => Some(Self::Vibe),
-            (9u32, Inav6 | Inav7 | Inav8) => Some(Self::Smartaudio),
-            (10u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Scheduler)
-            }
+            (9u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Smartaudio),
+            (10u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Velocity),
+            (
+                10u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Scheduler),
             (10u32, Inav5) => Some(Self::Cruise),
-            (10u32, Inav6 | Inav7 | Inav8) => Some(Self::Acc),
-            (11u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Stack)
-            }
+            (10u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Acc),
+            (11u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::DtermFilter),
+            (
+                11u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Stack),
             (11u32, Inav5) => Some(Self::RemFlightTime),
-            (11u32, Inav6 | Inav7 | Inav8) => Some(Self::NavYaw),
-            (12u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::EscSensorRpm)
-            }
+            (11u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::NavYaw),
+            (12u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Anglerate),
+            (
+                12u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::EscSensorRpm),
             (12u32, Inav5) => Some(Self::Smartaudio),
-            (12u32, Inav6 | Inav7 | Inav8) => Some(Self::Pcf8574),
-            (13u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::EscSensorTmp)
-            }
+            (12u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Pcf8574),
+            (13u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::EscSensor),
+            (
+                13u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::EscSensorTmp),
             (13u32, Inav5) => Some(Self::Acc),
-            (13u32, Inav6 | Inav7 | Inav8) => Some(Self::DynamicGyroLpf),
-            (14u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Altitude)
-            }
+            (13u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::DynamicGyroLpf),
+            (14u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Scheduler),
+            (
+                14u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Altitude),
             (14u32, Inav5) => Some(Self::Erpm),
-            (14u32, Inav6 | Inav7 | Inav8) => Some(Self::AutoLevel),
-            (15u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Fft)
-            }
+            (14u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::AutoLevel),
+            (15u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Stack),
+            (
+                15u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Fft),
             (15u32, Inav5) => Some(Self::RpmFilter),
-            (15u32, Inav6 | Inav7 | Inav8) => Some(Self::Altitude),
-            (16u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::FftTime)
-            }
+            (15u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Altitude),
+            (16u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::EscSensorRpm),
+            (
+                16u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::FftTime),
             (16u32, Inav5) => Some(Self::RpmFreq),
-            (16u32, Inav6 | Inav7 | Inav8) => Some(Self::AutoTrim),
-            (17u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::FftFreq)
-            }
+            (16u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::AutoTrim),
+            (17u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::EscSensorTmp),
+            (
+                17u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::FftFreq),
             (17u32, Inav5) => Some(Self::NavYaw),
-            (17u32, Inav6 | Inav7 | Inav8) => Some(Self::AutoTune),
-            (18u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::RxFrskySpi)
-            }
+            (17u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::AutoTune),
+            (18u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Altitude),
+            (
+                18u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::RxFrskySpi),
             (18u32, Inav5) => Some(Self::DynamicFilter),
-            (18u32, Inav6 | Inav7 | Inav8) => Some(Self::RateDynamics),
-            (19u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::RxSfhssSpi)
-            }
+            (18u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::RateDynamics),
+            (19u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Fft),
+            (
+                19u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::RxSfhssSpi),
             (19u32, Inav5) => Some(Self::DynamicFilterFrequency),
-            (19u32, Inav6 | Inav7 | Inav8) => Some(Self::Landing),
-            (20u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::GyroRaw)
-            }
+            (19u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Landing),
+            (20u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::FftTime),
+            (
+                20u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::GyroRaw),
             (20u32, Inav5) => Some(Self::Irlock),
-            (20u32, Inav6 | Inav7 | Inav8) => Some(Self::PosEst),
-            (21u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::DualGyroRaw)
-            }
+            (20u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::PosEst),
+            (21u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::FftFreq),
+            (21u32, Betaflight4_0) => Some(Self::DualGyro),
+            (
+                21u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::DualGyroRaw),
             (21u32, Inav5) => Some(Self::KalmanGain),
-            (21u32, Inav8) => Some(Self::AdaptiveFilter),
-            (22u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::DualGyroDiff)
-            }
+            (21u32, Inav8 | Inav9) => Some(Self::AdaptiveFilter),
+            (22u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::RxFrskySpi),
+            (22u32, Betaflight4_0) => Some(Self::DualGyroRaw),
+            (
+                22u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::DualGyroDiff),
             (22u32, Inav5) => Some(Self::PidMeasurement),
-            (22u32, Inav8) => Some(Self::Headtracking),
-            (23u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Max7456Signal)
-            }
+            (22u32, Inav8 | Inav9) => Some(Self::Headtracking),
+            (23u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::GyroRaw),
+            (23u32, Betaflight4_0) => Some(Self::DualGyroCombine),
+            (
+                23u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Max7456Signal),
             (23u32, Inav5) => Some(Self::SpmCells),
-            (23u32, Inav8) => Some(Self::Gps),
-            (24u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Max7456Spiclock)
-            }
+            (23u32, Inav8 | Inav9) => Some(Self::Gps),
+            (24u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::DualGyro),
+            (24u32, Betaflight4_0) => Some(Self::DualGyroDiff),
+            (
+                24u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Max7456Spiclock),
             (24u32, Inav5) => Some(Self::SpmVs600),
-            (24u32, Inav8) => Some(Self::Lulu),
-            (25u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Sbus)
-            }
+            (24u32, Inav8 | Inav9) => Some(Self::Lulu),
+            (25u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::DualGyroRaw),
+            (25u32, Betaflight4_0) => Some(Self::Max7456Signal),
+            (
+                25u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Sbus),
             (25u32, Inav5) => Some(Self::SpmVario),
-            (25u32, Inav8) => Some(Self::Sbus2),
-            (26u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Fport)
-            }
+            (25u32, Inav8 | Inav9) => Some(Self::Sbus2),
+            (26u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::DualGyroCombine),
+            (26u32, Betaflight4_0) => Some(Self::Max7456Spiclock),
+            (
+                26u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Fport),
             (26u32, Inav5) => Some(Self::Pcf8574),
-            (27u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Rangefinder)
-            }
+            (27u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::DualGyroDiff),
+            (27u32, Betaflight4_0) => Some(Self::Sbus),
+            (
+                27u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Rangefinder),
             (27u32, Inav5) => Some(Self::DynamicGyroLpf),
-            (28u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::RangefinderQuality)
-            }
+            (28u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Max7456Signal),
+            (28u32, Betaflight4_0) => Some(Self::Fport),
+            (
+                28u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::RangefinderQuality),
             (28u32, Inav5) => Some(Self::AutoLevel),
-            (29u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::LidarTf)
-            }
+            (29u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Max7456Spiclock),
+            (29u32, Betaflight4_0) => Some(Self::Rangefinder),
+            (
+                29u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::LidarTf),
             (29u32, Inav5) => Some(Self::Imu2),
-            (30u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::AdcInternal)
-            }
+            (30u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Sbus),
+            (30u32, Betaflight4_0) => Some(Self::RangefinderQuality),
+            (
+                30u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::AdcInternal),
             (30u32, Inav5) => Some(Self::Altitude),
-            (31u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::RunawayTakeoff)
-            }
+            (31u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Fport),
+            (31u32, Betaflight4_0) => Some(Self::LidarTf),
+            (
+                31u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::RunawayTakeoff),
             (31u32, Inav5) => Some(Self::SmithPredictor),
-            (32u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Sdio)
-            }
+            (32u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Rangefinder),
+            (32u32, Betaflight4_0) => Some(Self::AdcInternal),
+            (
+                32u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Sdio),
             (32u32, Inav5) => Some(Self::AutoTrim),
-            (33u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::CurrentSensor)
-            }
+            (33u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::RangefinderQuality),
+            (33u32, Betaflight4_0) => Some(Self::RunawayTakeoff),
+            (
+                33u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::CurrentSensor),
             (33u32, Inav5) => Some(Self::AutoTune),
-            (34u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Usb)
-            }
+            (34u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::LidarTf),
+            (34u32, Betaflight4_0) => Some(Self::Sdio),
+            (
+                34u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Usb),
             (34u32, Inav5) => Some(Self::RateDynamics),
-            (35u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Smartaudio)
-            }
+            (35u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::CoreTemp),
+            (35u32, Betaflight4_0) => Some(Self::CurrentSensor),
+            (
+                35u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Smartaudio),
             (35u32, Inav5) => Some(Self::Landing),
-            (36u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Rth)
-            }
-            (37u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::ItermRelax)
-            }
-            (38u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::AcroTrainer)
-            }
-            (39u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::RcSmoothing)
-            }
-            (40u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::RxSignalLoss)
-            }
-            (41u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::RcSmoothingRate)
-            }
-            (42u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::AntiGravity)
-            }
-            (43u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::DynLpf)
-            }
-            (44u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::RxSpektrumSpi)
-            }
-            (45u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::DshotRpmTelemetry)
-            }
-            (46u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::RpmFilter)
-            }
-            (47u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::DMin)
-            }
-            (48u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::AcCorrection)
-            }
-            (49u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::AcError)
-            }
-            (50u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::DualGyroScaled)
-            }
-            (51u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::DshotRpmErrors)
-            }
-            (52u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::CrsfLinkStatisticsUplink)
-            }
-            (53u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::CrsfLinkStatisticsPwr)
-            }
-            (54u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::CrsfLinkStatisticsDown)
-            }
-            (55u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Baro)
-            }
-            (56u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::GpsRescueThrottlePid)
-            }
-            (57u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::DynIdle)
-            }
+            (36u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::RunawayTakeoff),
+            (36u32, Betaflight4_0) => Some(Self::Usb),
+            (
+                36u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Rth),
+            (37u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Sdio),
+            (37u32, Betaflight4_0) => Some(Self::Smartaudio),
+            (
+                37u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::ItermRelax),
+            (38u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::CurrentSensor),
+            (38u32, Betaflight4_0) => Some(Self::Rth),
+            (
+                38u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::AcroTrainer),
+            (39u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Usb),
+            (39u32, Betaflight4_0) => Some(Self::ItermRelax),
+            (
+                39u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::RcSmoothing),
+            (40u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Smartaudio),
+            (40u32, Betaflight4_0) => Some(Self::AcroTrainer),
+            (
+                40u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::RxSignalLoss),
+            (41u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Rth),
+            (41u32, Betaflight4_0) => Some(Self::RcSmoothing),
+            (
+                41u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::RcSmoothingRate),
+            (42u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::ItermRelax),
+            (42u32, Betaflight4_0) => Some(Self::RxSignalLoss),
+            (
+                42u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::AntiGravity),
+            (43u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::AcroTrainer),
+            (43u32, Betaflight4_0) => Some(Self::RcSmoothingRate),
+            (
+                43u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::DynLpf),
+            (44u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::RcSmoothing),
+            (44u32, Betaflight4_0) => Some(Self::AntiGravity),
+            (
+                44u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::RxSpektrumSpi),
+            (45u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::RxSignalLoss),
+            (45u32, Betaflight4_0) => Some(Self::DynLpf),
+            (
+                45u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::DshotRpmTelemetry),
+            (46u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::RcSmoothingRate),
+            (46u32, Betaflight4_0) => Some(Self::RxSpektrumSpi),
+            (
+                46u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::RpmFilter),
+            (
+                47u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::DMin),
+            (
+                48u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::AcCorrection),
+            (
+                49u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::AcError),
+            (
+                50u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::DualGyroScaled),
+            (
+                51u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::DshotRpmErrors),
+            (
+                52u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::CrsfLinkStatisticsUplink),
+            (
+                53u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::CrsfLinkStatisticsPwr),
+            (
+                54u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::CrsfLinkStatisticsDown),
+            (
+                55u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Baro),
+            (
+                56u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::GpsRescueThrottlePid),
+            (
+                57u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::DynIdle),
             (58u32, Betaflight4_2) => Some(Self::FfLimit),
-            (58u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::FeedforwardLimit),
+            (
+                58u32,
+                Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::FeedforwardLimit),
             (59u32, Betaflight4_2) => Some(Self::FfInterpolated),
-            (59u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::Feedforward),
-            (60u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::BlackboxOutput)
-            }
-            (61u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::GyroSample)
-            }
-            (62u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::RxTiming)
-            }
-            (63u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::DLpf),
-            (64u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::VtxTramp),
-            (65u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::Ghst),
-            (66u32, Betaflight4_3) => Some(Self::SchedulerDeterminism),
-            (66u32, Betaflight4_4 | Betaflight4_5) => Some(Self::GhstMsp),
-            (67u32, Betaflight4_3) => Some(Self::TimingAccuracy),
-            (67u32, Betaflight4_4 | Betaflight4_5) => Some(Self::SchedulerDeterminism),
-            (68u32, Betaflight4_3) => Some(Self::RxExpresslrsSpi),
-            (68u32, Betaflight4_4 | Betaflight4_5) => Some(Self::TimingAccuracy),
-            (69u32, Betaflight4_3) => Some(Self::RxExpresslrsPhaselock),
-            (69u32, Betaflight4_4 | Betaflight4_5) => Some(Self::RxExpresslrsSpi),
-            (70u32, Betaflight4_3) => Some(Self::RxStateTime),
-            (70u32, Betaflight4_4 | Betaflight4_5) => Some(Self::RxExpresslrsPhaselock),
-            (71u32, Betaflight4_4 | Betaflight4_5) => Some(Self::RxStateTime),
-            (72u32, Betaflight4_4 | Betaflight4_5) => Some(Self::GpsRescueVelocity),
-            (73u32, Betaflight4_4 | Betaflight4_5) => Some(Self::GpsRescueHeading),
-            (74u32, Betaflight4_4 | Betaflight4_5) => Some(Self::GpsRescueTracking),
+            (
+                59u32,
+                Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Feedforward),
+            (
+                60u32,
+                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6
+                | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::BlackboxOutput),
+            (
+                61u32,
+                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6
+                | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::GyroSample),
+            (
+                62u32,
+                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6
+                | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::RxTiming),
+            (
+                63u32,
+                Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::DLpf),
+            (
+                64u32,
+                Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::VtxTramp),
+            (
+                65u32,
+                Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Ghst),
+            (66u32, Betaflight4_3 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => {
+                Some(Self::SchedulerDeterminism)
+            }
+            (66u32, Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => Some(Self::GhstMsp),
+            (67u32, Betaflight4_3 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => {
+                Some(Self::TimingAccuracy)
+            }
+            (67u32, Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(Self::SchedulerDeterminism)
+            }
+            (68u32, Betaflight4_3 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => {
+                Some(Self::RxExpresslrsSpi)
+            }
+            (68u32, Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => Some(Self::TimingAccuracy),
+            (69u32, Betaflight4_3 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => {
+                Some(Self::RxExpresslrsPhaselock)
+            }
+            (69u32, Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => Some(Self::RxExpresslrsSpi),
+            (70u32, Betaflight4_3 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => {
+                Some(Self::RxStateTime)
+            }
+            (70u32, Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(Self::RxExpresslrsPhaselock)
+            }
+            (71u32, Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => Some(Self::RxStateTime),
+            (72u32, Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => Some(Self::GpsRescueVelocity),
+            (73u32, Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => Some(Self::GpsRescueHeading),
+            (74u32, Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => Some(Self::GpsRescueTracking),
             (75u32, Betaflight4_4) => Some(Self::Attitude),
-            (75u32, Betaflight4_5) => Some(Self::GpsConnection),
+            (75u32, Betaflight4_5 | Betaflight4_6) => Some(Self::GpsConnection),
             (76u32, Betaflight4_4) => Some(Self::VtxMsp),
-            (76u32, Betaflight4_5) => Some(Self::Attitude),
+            (76u32, Betaflight4_5 | Betaflight4_6) => Some(Self::Attitude),
             (77u32, Betaflight4_4) => Some(Self::GpsDop),
-            (77u32, Betaflight4_5) => Some(Self::VtxMsp),
+            (77u32, Betaflight4_5 | Betaflight4_6) => Some(Self::VtxMsp),
             (78u32, Betaflight4_4) => Some(Self::Failsafe),
-            (78u32, Betaflight4_5) => Some(Self::GpsDop),
-            (79u32, Betaflight4_5) => Some(Self::Failsafe),
-            (80u32, Betaflight4_5) => Some(Self::GyroCalibration),
-            (81u32, Betaflight4_5) => Some(Self::AngleMode),
-            (82u32, Betaflight4_5) => Some(Self::AngleTarget),
-            (83u32, Betaflight4_5) => Some(Self::CurrentAngle),
-            (84u32, Betaflight4_5) => Some(Self::DshotTelemetryCounts),
-            (85u32, Betaflight4_5) => Some(Self::RpmLimit),
-            (86u32, Betaflight4_5) => Some(Self::RcStats),
-            (87u32, Betaflight4_5) => Some(Self::MagCalib),
-            (88u32, Betaflight4_5) => Some(Self::MagTaskRate),
-            (89u32, Betaflight4_5) => Some(Self::Ezlanding),
+            (78u32, Betaflight4_5 | Betaflight4_6) => Some(Self::GpsDop),
+            (79u32, Betaflight4_5 | Betaflight4_6) => Some(Self::Failsafe),
+            (80u32, Betaflight4_5 | Betaflight4_6) => Some(Self::GyroCalibration),
+            (81u32, Betaflight4_5 | Betaflight4_6) => Some(Self::AngleMode),
+            (82u32, Betaflight4_5 | Betaflight4_6) => Some(Self::AngleTarget),
+            (83u32, Betaflight4_5 | Betaflight4_6) => Some(Self::CurrentAngle),
+            (84u32, Betaflight4_5 | Betaflight4_6) => Some(Self::DshotTelemetryCounts),
+            (85u32, Betaflight4_5 | Betaflight4_6) => Some(Self::RpmLimit),
+            (86u32, Betaflight4_5 | Betaflight4_6) => Some(Self::RcStats),
+            (87u32, Betaflight4_5 | Betaflight4_6) => Some(Self::MagCalib),
+            (88u32, Betaflight4_5 | Betaflight4_6) => Some(Self::MagTaskRate),
+            (89u32, Betaflight4_5 | Betaflight4_6) => Some(Self::Ezlanding),
+            (90u32, Betaflight4_6) => Some(Self::Tpa),
+            (91u32, Betaflight4_6) => Some(Self::STerm),
+            (92u32, Betaflight4_6) => Some(Self::Spa),
+            (93u32, Betaflight4_6) => Some(Self::Task),
+            (94u32, Betaflight4_6) => Some(Self::Gimbal),
+            (95u32, Betaflight4_6) => Some(Self::WingSetpoint),
+            (96u32, Betaflight4_6) => Some(Self::AutopilotAltitude),
+            (97u32, Betaflight4_6) => Some(Self::AutopilotPosition),
+            (98u32, Betaflight4_6) => Some(Self::Chirp),
+            (99u32, Betaflight4_6) => Some(Self::FlashTestPrbs),
+            (100u32, Betaflight4_6) => Some(Self::MavlinkTelemetry),
             _ => {
                 #[allow(clippy::redundant_closure_call)]
                 (|raw| tracing::error!("invalid debug mode: {raw}"))(raw);
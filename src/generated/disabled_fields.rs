@@ -44,7 +44,7 @@ fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.write_str(&names.join("|"))
     }
 }
-#[cfg(feature = "_serde")]
+#[cfg(feature = "serde")]
 #[allow(clippy::cast_possible_truncation)]
 impl ::serde::Serialize for DisabledFields {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -59,8 +59,32 @@ fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         seq.end()
     }
 }
+/// Only the flags are serialized, so this picks a firmware that
+/// supports all of them, trying the newest versions first.
+#[cfg(feature = "serde")]
+#[allow(unused_qualifications)]
+impl<'de> ::serde::Deserialize<'de> for DisabledFields {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let flags = <::alloc::vec::Vec<FieldGroup>>::deserialize(deserializer)?;
+        crate::headers::InternalFirmware::ALL
+            .iter()
+            .rev()
+            .find_map(|&firmware| {
+                let raw = flags.iter().try_fold(0_u32, |raw, flag| {
+                    flag.to_bit(firmware).map(|bit| raw | (1 << bit))
+                })?;
+                Some(Self::new(raw, firmware))
+            })
+            .ok_or_else(|| {
+                <D::Error as serde::de::Error>::custom("no supported firmware has all flags")
+            })
+    }
+}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "_serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A set of fields that can be disabled in Betaflight logs.
 ///
 /// See [`Flag`][crate::units::Flag].
@@ -92,6 +116,8 @@ pub enum FieldGroup {
     Rpm,
     /// `RSSI`
     Rssi,
+    /// `SERVO`
+    Servo,
     /// `SETPOINT`
     Setpoint,
 }
@@ -112,6 +138,7 @@ fn as_name(&self) -> &'static str {
             Self::RcCommands => "RC_COMMANDS",
             Self::Rpm => "RPM",
             Self::Rssi => "RSSI",
+            Self::Servo => "SERVO",
             Self::Setpoint => "SETPOINT",
         }
     }
@@ -133,20 +160,45 @@ impl FieldGroup {
     const fn from_bit(bit: u32, fw: crate::headers::InternalFirmware) -> Option<Self> {
         use crate::headers::InternalFirmware::*;
         match (bit, fw) {
-            (0u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::Pid),
-            (1u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::RcCommands),
-            (2u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::Setpoint),
-            (3u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::Battery),
-            (4u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::Mag),
-            (5u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::Altitude),
-            (6u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::Rssi),
-            (7u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::Gyro),
-            (8u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::Acc),
-            (9u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::DebugLog),
-            (10u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::Motor),
-            (11u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::Gps),
-            (12u32, Betaflight4_5) => Some(Self::Rpm),
-            (13u32, Betaflight4_5) => Some(Self::GyroUnfiltered),
+            (0u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(Self::Pid)
+            }
+            (1u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(Self::RcCommands)
+            }
+            (2u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(Self::Setpoint)
+            }
+            (3u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(Self::Battery)
+            }
+            (4u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(Self::Mag)
+            }
+            (5u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(Self::Altitude)
+            }
+            (6u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(Self::Rssi)
+            }
+            (7u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(Self::Gyro)
+            }
+            (8u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(Self::Acc)
+            }
+            (9u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(Self::DebugLog)
+            }
+            (10u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(Self::Motor)
+            }
+            (11u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(Self::Gps)
+            }
+            (12u32, Betaflight4_5 | Betaflight4_6) => Some(Self::Rpm),
+            (13u32, Betaflight4_5 | Betaflight4_6) => Some(Self::GyroUnfiltered),
+            (14u32, Betaflight4_6) => Some(Self::Servo),
             _ => None,
         }
     }
@@ -154,20 +206,45 @@ const fn from_bit(bit: u32, fw: crate::headers::InternalFirmware) -> Option<Self
     const fn to_bit(self, fw: crate::headers::InternalFirmware) -> Option<u32> {
         use crate::headers::InternalFirmware::*;
         match (self, fw) {
-            (Self::Pid, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(0u32),
-            (Self::RcCommands, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(1u32),
-            (Self::Setpoint, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(2u32),
-            (Self::Battery, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(3u32),
-            (Self::Mag, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(4u32),
-            (Self::Altitude, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(5u32),
-            (Self::Rssi, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(6u32),
-            (Self::Gyro, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(7u32),
-            (Self::Acc, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(8u32),
-            (Self::DebugLog, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(9u32),
-            (Self::Motor, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(10u32),
-            (Self::Gps, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(11u32),
-            (Self::Rpm, Betaflight4_5) => Some(12u32),
-            (Self::GyroUnfiltered, Betaflight4_5) => Some(13u32),
+            (Self::Pid, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(0u32)
+            }
+            (Self::RcCommands, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(1u32)
+            }
+            (Self::Setpoint, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(2u32)
+            }
+            (Self::Battery, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(3u32)
+            }
+            (Self::Mag, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(4u32)
+            }
+            (Self::Altitude, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(5u32)
+            }
+            (Self::Rssi, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(6u32)
+            }
+            (Self::Gyro, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(7u32)
+            }
+            (Self::Acc, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(8u32)
+            }
+            (Self::DebugLog, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(9u32)
+            }
+            (Self::Motor, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(10u32)
+            }
+            (Self::Gps, Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6) => {
+                Some(11u32)
+            }
+            (Self::Rpm, Betaflight4_5 | Betaflight4_6) => Some(12u32),
+            (Self::GyroUnfiltered, Betaflight4_5 | Betaflight4_6) => Some(13u32),
+            (Self::Servo, Betaflight4_6) => Some(14u32),
             _ => None,
         }
     }
@@ -1,5 +1,5 @@
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "_serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// The current failsafe phase.
 #[non_exhaustive]
 pub enum FailsafePhase {
@@ -59,31 +59,54 @@ pub(crate) fn new(raw: u32, fw: crate::headers::InternalFirmware) -> Self {
         match (raw, fw) {
             (
                 0u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4
+                | Rotorflight4_5,
             ) => Self::Idle,
             (
                 1u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4
+                | Rotorflight4_5,
             ) => Self::RxLossDetected,
-            (2u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Self::Landing,
-            (2u32, Inav5 | Inav6 | Inav7 | Inav8) => Self::RxLossIdle,
-            (3u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Self::Landed,
-            (3u32, Inav5 | Inav6 | Inav7 | Inav8) => Self::ReturnToHome,
-            (4u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Self::RxLossMonitoring
-            }
-            (4u32, Inav5 | Inav6 | Inav7 | Inav8) => Self::Landing,
-            (5u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Self::RxLossRecovered
-            }
-            (5u32, Inav5 | Inav6 | Inav7 | Inav8) => Self::Landed,
-            (6u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Self::GpsRescue
-            }
-            (6u32, Inav5 | Inav6 | Inav7 | Inav8) => Self::RxLossMonitoring,
-            (7u32, Inav5 | Inav6 | Inav7 | Inav8) => Self::RxLossRecovered,
+            (
+                2u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Self::Landing,
+            (2u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Self::RxLossIdle,
+            (
+                3u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Self::Landed,
+            (3u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Self::ReturnToHome,
+            (
+                4u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Self::RxLossMonitoring,
+            (4u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Self::Landing,
+            (
+                5u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Self::RxLossRecovered,
+            (5u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Self::Landed,
+            (
+                6u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Self::GpsRescue,
+            (6u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Self::RxLossMonitoring,
+            (7u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Self::RxLossRecovered,
             _ => {
                 #[allow(clippy::redundant_closure_call)]
                 (|raw| tracing::debug!("invalid failsafe phase ({raw})"))(raw);
@@ -44,7 +44,7 @@ fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.write_str(&names.join("|"))
     }
 }
-#[cfg(feature = "_serde")]
+#[cfg(feature = "serde")]
 #[allow(clippy::cast_possible_truncation)]
 impl ::serde::Serialize for FeatureSet {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -59,8 +59,32 @@ fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         seq.end()
     }
 }
+/// Only the flags are serialized, so this picks a firmware that
+/// supports all of them, trying the newest versions first.
+#[cfg(feature = "serde")]
+#[allow(unused_qualifications)]
+impl<'de> ::serde::Deserialize<'de> for FeatureSet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let flags = <::alloc::vec::Vec<Feature>>::deserialize(deserializer)?;
+        crate::headers::InternalFirmware::ALL
+            .iter()
+            .rev()
+            .find_map(|&firmware| {
+                let raw = flags.iter().try_fold(0_u32, |raw, flag| {
+                    flag.to_bit(firmware).map(|bit| raw | (1 << bit))
+                })?;
+                Some(Self::new(raw, firmware))
+            })
+            .ok_or_else(|| {
+                <D::Error as serde::de::Error>::custom("no supported firmware has all flags")
+            })
+    }
+}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "_serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A feature defined by any supported firmware.
 ///
 /// See [`Flag`][crate::units::Flag].
@@ -80,10 +104,14 @@ pub enum Feature {
     CurrentMeter,
     /// `DASHBOARD`
     Dashboard,
+    /// `DISPLAY`
+    Display,
     /// `DYNAMIC_FILTER`
     DynamicFilter,
     /// `ESC_SENSOR`
     EscSensor,
+    /// `FAILSAFE`
+    Failsafe,
     /// `FW_AUTOTRIM`
     FwAutotrim,
     /// `FW_LAUNCH`
@@ -98,6 +126,8 @@ pub enum Feature {
     LedStrip,
     /// `MOTOR_STOP`
     MotorStop,
+    /// `ONESHOT125`
+    Oneshot125,
     /// `OSD`
     Osd,
     /// `PWM_OUTPUT_ENABLE`
@@ -122,6 +152,10 @@ pub enum Feature {
     ServoTilt,
     /// `SOFTSERIAL`
     SoftSerial,
+    /// `SOFTSPI`
+    Softspi,
+    /// `SONAR`
+    Sonar,
     /// `SUPEREXPO_RATES`
     SuperexpoRates,
     /// `TELEMETRY`
@@ -150,8 +184,10 @@ fn as_name(&self) -> &'static str {
             Self::ChannelForwarding => "CHANNEL_FORWARDING",
             Self::CurrentMeter => "CURRENT_METER",
             Self::Dashboard => "DASHBOARD",
+            Self::Display => "DISPLAY",
             Self::DynamicFilter => "DYNAMIC_FILTER",
             Self::EscSensor => "ESC_SENSOR",
+            Self::Failsafe => "FAILSAFE",
             Self::FwAutotrim => "FW_AUTOTRIM",
             Self::FwLaunch => "FW_LAUNCH",
             Self::Geozone => "GEOZONE",
@@ -159,6 +195,7 @@ fn as_name(&self) -> &'static str {
             Self::InflightAccCal => "INFLIGHT_ACC_CAL",
             Self::LedStrip => "LED_STRIP",
             Self::MotorStop => "MOTOR_STOP",
+            Self::Oneshot125 => "ONESHOT125",
             Self::Osd => "OSD",
             Self::PwmOutputEnable => "PWM_OUTPUT_ENABLE",
             Self::RangeFinder => "RANGEFINDER",
@@ -171,6 +208,8 @@ fn as_name(&self) -> &'static str {
             Self::RxSpi => "RX_SPI",
             Self::ServoTilt => "SERVO_TILT",
             Self::SoftSerial => "SOFTSERIAL",
+            Self::Softspi => "SOFTSPI",
+            Self::Sonar => "SONAR",
             Self::SuperexpoRates => "SUPEREXPO_RATES",
             Self::Telemetry => "TELEMETRY",
             Self::ThreeD => "3D",
@@ -199,104 +238,150 @@ impl Feature {
     const fn from_bit(bit: u32, fw: crate::headers::InternalFirmware) -> Option<Self> {
         use crate::headers::InternalFirmware::*;
         match (bit, fw) {
-            (0u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::RxPpm)
-            }
-            (0u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::ThrottleVbatCompensation),
-            (1u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Vbat),
-            (2u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::InflightAccCal)
-            }
-            (2u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::TxProfileSelection),
-            (3u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::RxSerial)
-            }
-            (3u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::BatProfileAutoswitch),
+            (
+                0u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4,
+            ) => Some(Self::RxPpm),
+            (0u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::ThrottleVbatCompensation),
+            (1u32, Cleanflight1 | Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Vbat),
+            (
+                2u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4,
+            ) => Some(Self::InflightAccCal),
+            (2u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::TxProfileSelection),
+            (
+                3u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4,
+            ) => Some(Self::RxSerial),
+            (3u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::BatProfileAutoswitch),
             (
                 4u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6,
             ) => Some(Self::MotorStop),
-            (4u32, Inav8) => Some(Self::Geozone),
-            (5u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::ServoTilt)
-            }
+            (4u32, Inav8 | Inav9) => Some(Self::Geozone),
+            (
+                5u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4,
+            ) => Some(Self::ServoTilt),
             (
                 6u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9,
             ) => Some(Self::SoftSerial),
             (
                 7u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9,
             ) => Some(Self::Gps),
-            (9u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::RangeFinder)
-            }
+            (8u32, Cleanflight1) => Some(Self::Failsafe),
+            (
+                9u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(Self::RangeFinder),
+            (9u32, Cleanflight1) => Some(Self::Sonar),
             (
                 10u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9,
             ) => Some(Self::Telemetry),
-            (11u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::CurrentMeter),
-            (12u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::ThreeD)
-            }
-            (12u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::ReversibleMotors),
-            (13u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::RxParallelPwm)
-            }
-            (14u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::RxMsp)
+            (11u32, Cleanflight1 | Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => {
+                Some(Self::CurrentMeter)
             }
+            (
+                12u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4,
+            ) => Some(Self::ThreeD),
+            (12u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::ReversibleMotors),
+            (
+                13u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4,
+            ) => Some(Self::RxParallelPwm),
+            (
+                14u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4,
+            ) => Some(Self::RxMsp),
             (
                 15u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9,
             ) => Some(Self::RssiAdc),
             (
                 16u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9,
             ) => Some(Self::LedStrip),
             (
                 17u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Inav5 | Inav6
+                | Inav7 | Inav8 | Inav9,
             ) => Some(Self::Dashboard),
-            (18u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Osd)
-            }
-            (19u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Blackbox),
-            (20u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::ChannelForwarding)
-            }
+            (17u32, Cleanflight1) => Some(Self::Display),
+            (
+                18u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(Self::Osd),
+            (18u32, Cleanflight1) => Some(Self::Oneshot125),
+            (19u32, Cleanflight1 | Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Blackbox),
+            (
+                20u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4,
+            ) => Some(Self::ChannelForwarding),
             (
                 21u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Inav5 | Inav6
+                | Inav7 | Inav8 | Inav9,
             ) => Some(Self::Transponder),
             (
                 22u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Inav5 | Inav6
+                | Inav7 | Inav8 | Inav9,
             ) => Some(Self::AirMode),
-            (23u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::SuperexpoRates),
-            (24u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Vtx),
-            (25u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::RxSpi)
-            }
-            (27u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::EscSensor)
-            }
-            (28u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::AntiGravity)
-            }
-            (28u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::PwmOutputEnable),
-            (29u32, Betaflight4_2) => Some(Self::DynamicFilter),
-            (29u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Osd),
-            (30u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::FwLaunch),
-            (31u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::FwAutotrim),
+            (23u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::SuperexpoRates),
+            (24u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Vtx),
+            (
+                25u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(Self::RxSpi),
+            (26u32, Betaflight3_5 | EmuFlight0_4) => Some(Self::Softspi),
+            (
+                27u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(Self::EscSensor),
+            (
+                28u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(Self::AntiGravity),
+            (28u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::PwmOutputEnable),
+            (
+                29u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | EmuFlight0_4,
+            ) => Some(Self::DynamicFilter),
+            (29u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Osd),
+            (30u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::FwLaunch),
+            (31u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::FwAutotrim),
             _ => None,
         }
     }
@@ -304,107 +389,150 @@ const fn from_bit(bit: u32, fw: crate::headers::InternalFirmware) -> Option<Self
     const fn to_bit(self, fw: crate::headers::InternalFirmware) -> Option<u32> {
         use crate::headers::InternalFirmware::*;
         match (self, fw) {
-            (Self::RxPpm, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(0u32)
-            }
-            (Self::ThrottleVbatCompensation, Inav5 | Inav6 | Inav7 | Inav8) => Some(0u32),
-            (Self::Vbat, Inav5 | Inav6 | Inav7 | Inav8) => Some(1u32),
+            (
+                Self::RxPpm,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4,
+            ) => Some(0u32),
+            (Self::ThrottleVbatCompensation, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(0u32),
+            (Self::Vbat, Cleanflight1 | Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(1u32),
             (
                 Self::InflightAccCal,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4,
             ) => Some(2u32),
-            (Self::TxProfileSelection, Inav5 | Inav6 | Inav7 | Inav8) => Some(2u32),
-            (Self::RxSerial, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(3u32)
-            }
-            (Self::BatProfileAutoswitch, Inav5 | Inav6 | Inav7 | Inav8) => Some(3u32),
+            (Self::TxProfileSelection, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(2u32),
+            (
+                Self::RxSerial,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4,
+            ) => Some(3u32),
+            (Self::BatProfileAutoswitch, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(3u32),
             (
                 Self::MotorStop,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6,
             ) => Some(4u32),
-            (Self::Geozone, Inav8) => Some(4u32),
-            (Self::ServoTilt, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(5u32)
-            }
+            (Self::Geozone, Inav8 | Inav9) => Some(4u32),
+            (
+                Self::ServoTilt,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4,
+            ) => Some(5u32),
             (
                 Self::SoftSerial,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9,
             ) => Some(6u32),
             (
                 Self::Gps,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9,
             ) => Some(7u32),
-            (Self::RangeFinder, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(9u32)
-            }
+            (Self::Failsafe, Cleanflight1) => Some(8u32),
+            (
+                Self::RangeFinder,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(9u32),
+            (Self::Sonar, Cleanflight1) => Some(9u32),
             (
                 Self::Telemetry,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9,
             ) => Some(10u32),
-            (Self::CurrentMeter, Inav5 | Inav6 | Inav7 | Inav8) => Some(11u32),
-            (Self::ThreeD, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(12u32)
+            (Self::CurrentMeter, Cleanflight1 | Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => {
+                Some(11u32)
             }
-            (Self::ReversibleMotors, Inav5 | Inav6 | Inav7 | Inav8) => Some(12u32),
+            (
+                Self::ThreeD,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4,
+            ) => Some(12u32),
+            (Self::ReversibleMotors, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(12u32),
             (
                 Self::RxParallelPwm,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4,
             ) => Some(13u32),
-            (Self::RxMsp, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(14u32)
-            }
+            (
+                Self::RxMsp,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4,
+            ) => Some(14u32),
             (
                 Self::RssiAdc,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9,
             ) => Some(15u32),
             (
                 Self::LedStrip,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9,
             ) => Some(16u32),
             (
                 Self::Dashboard,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Inav5 | Inav6
+                | Inav7 | Inav8 | Inav9,
             ) => Some(17u32),
-            (Self::Osd, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(18u32)
-            }
-            (Self::Blackbox, Inav5 | Inav6 | Inav7 | Inav8) => Some(19u32),
+            (Self::Display, Cleanflight1) => Some(17u32),
+            (
+                Self::Osd,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(18u32),
+            (Self::Oneshot125, Cleanflight1) => Some(18u32),
+            (Self::Blackbox, Cleanflight1 | Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(19u32),
             (
                 Self::ChannelForwarding,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4,
             ) => Some(20u32),
             (
                 Self::Transponder,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Inav5 | Inav6
+                | Inav7 | Inav8 | Inav9,
             ) => Some(21u32),
             (
                 Self::AirMode,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Inav5 | Inav6
+                | Inav7 | Inav8 | Inav9,
             ) => Some(22u32),
-            (Self::SuperexpoRates, Inav5 | Inav6 | Inav7 | Inav8) => Some(23u32),
-            (Self::Vtx, Inav5 | Inav6 | Inav7 | Inav8) => Some(24u32),
-            (Self::RxSpi, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(25u32)
-            }
-            (Self::EscSensor, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(27u32)
-            }
-            (Self::AntiGravity, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(28u32)
-            }
-            (Self::PwmOutputEnable, Inav5 | Inav6 | Inav7 | Inav8) => Some(28u32),
-            (Self::DynamicFilter, Betaflight4_2) => Some(29u32),
-            (Self::Osd, Inav5 | Inav6 | Inav7 | Inav8) => Some(29u32),
-            (Self::FwLaunch, Inav5 | Inav6 | Inav7 | Inav8) => Some(30u32),
-            (Self::FwAutotrim, Inav5 | Inav6 | Inav7 | Inav8) => Some(31u32),
+            (Self::SuperexpoRates, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(23u32),
+            (Self::Vtx, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(24u32),
+            (
+                Self::RxSpi,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(25u32),
+            (Self::Softspi, Betaflight3_5 | EmuFlight0_4) => Some(26u32),
+            (
+                Self::EscSensor,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(27u32),
+            (
+                Self::AntiGravity,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(28u32),
+            (Self::PwmOutputEnable, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(28u32),
+            (
+                Self::DynamicFilter,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | EmuFlight0_4,
+            ) => Some(29u32),
+            (Self::Osd, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(29u32),
+            (Self::FwLaunch, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(30u32),
+            (Self::FwAutotrim, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(31u32),
             _ => None,
         }
     }
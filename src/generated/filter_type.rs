@@ -0,0 +1,72 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The kind of a lowpass filter.
+#[non_exhaustive]
+pub enum FilterType {
+    /// `BIQUAD`
+    Biquad,
+    /// `PT1`
+    Pt1,
+    /// `PT2`
+    Pt2,
+    /// `PT3`
+    Pt3,
+}
+#[allow(unused_qualifications)]
+impl crate::units::Flag for FilterType {
+    fn as_name(&self) -> &'static str {
+        match self {
+            Self::Biquad => "BIQUAD",
+            Self::Pt1 => "PT1",
+            Self::Pt2 => "PT2",
+            Self::Pt3 => "PT3",
+        }
+    }
+}
+#[allow(unused_qualifications)]
+impl ::core::fmt::Display for FilterType {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let s = <Self as crate::units::Flag>::as_name(self);
+        f.write_str(s)
+    }
+}
+#[allow(
+    unused_qualifications,
+    clippy::enum_glob_use,
+    clippy::match_same_arms,
+    clippy::unseparated_literal_suffix
+)]
+impl FilterType {
+    pub(crate) fn new(raw: u32, fw: crate::headers::InternalFirmware) -> Option<Self> {
+        use crate::headers::InternalFirmware::*;
+        match (raw, fw) {
+            (
+                0u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Inav5 | Inav6
+                | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Pt1),
+            (
+                1u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Inav5 | Inav6
+                | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Biquad),
+            (
+                2u32,
+                Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Inav5 | Inav6
+                | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Pt2),
+            (
+                3u32,
+                Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Inav5 | Inav6
+                | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Pt3),
+            _ => {
+                #[allow(clippy::redundant_closure_call)]
+                (|raw| tracing::debug!("invalid filter type: {raw}"))(raw);
+                None
+            }
+        }
+    }
+}
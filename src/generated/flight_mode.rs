@@ -44,7 +44,7 @@ fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.write_str(&names.join("|"))
     }
 }
-#[cfg(feature = "_serde")]
+#[cfg(feature = "serde")]
 #[allow(clippy::cast_possible_truncation)]
 impl ::serde::Serialize for FlightModeSet {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -59,8 +59,32 @@ fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         seq.end()
     }
 }
+/// Only the flags are serialized, so this picks a firmware that
+/// supports all of them, trying the newest versions first.
+#[cfg(feature = "serde")]
+#[allow(unused_qualifications)]
+impl<'de> ::serde::Deserialize<'de> for FlightModeSet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let flags = <::alloc::vec::Vec<FlightMode>>::deserialize(deserializer)?;
+        crate::headers::InternalFirmware::ALL
+            .iter()
+            .rev()
+            .find_map(|&firmware| {
+                let raw = flags.iter().try_fold(0_u32, |raw, flag| {
+                    flag.to_bit(firmware).map(|bit| raw | (1 << bit))
+                })?;
+                Some(Self::new(raw, firmware))
+            })
+            .ok_or_else(|| {
+                <D::Error as serde::de::Error>::custom("no supported firmware has all flags")
+            })
+    }
+}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "_serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A flight mode.
 ///
 /// See [`Flag`][crate::units::Flag].
@@ -70,6 +94,8 @@ pub enum FlightMode {
     AcroTrainer,
     /// `AIRMODE`
     Airmode,
+    /// `ALTHOLD`
+    AltitudeHold,
     /// `ANGLE`
     Angle,
     /// `ANGLEHOLD`
@@ -84,6 +110,8 @@ pub enum FlightMode {
     AutoTrim,
     /// `AUTOTUNE`
     AutoTune,
+    /// `BARO`
+    Baro,
     /// `BEEP GPS COUNT`
     BeepGpsCount,
     /// `BEEPER MUTE`
@@ -108,6 +136,8 @@ pub enum FlightMode {
     Camera2,
     /// `CAMERA3`
     Camera3,
+    /// `CHIRP`
+    Chirp,
     /// `FAILSAFE`
     Failsafe,
     /// `FLAPERON`
@@ -124,6 +154,12 @@ pub enum FlightMode {
     Gimbalrlock,
     /// `GIMBALTLOCK`
     Gimbaltlock,
+    /// `GOVERNOR BYPASS`
+    GovernorBypass,
+    /// `GPS HOLD`
+    GpsHold,
+    /// `GPS HOME`
+    GpsHome,
     /// `GPS RESCUE`
     GpsRescue,
     /// `HEADADJ`
@@ -194,10 +230,14 @@ pub enum FlightMode {
     PidAudio,
     /// `PLANWPMISSION`
     PlanWpMission,
+    /// `POSHOLD`
+    PositionHold,
     /// `PREARM`
     Prearm,
     /// `READY`
     Ready,
+    /// `RESCUE`
+    Rescue,
     /// `SERVO1`
     Servo1,
     /// `SERVO2`
@@ -206,6 +246,8 @@ pub enum FlightMode {
     Servo3,
     /// `SOARING`
     Soaring,
+    /// `SONAR`
+    Sonar,
     /// `STICK COMMAND DISABLE`
     StickCommandDisable,
     /// `SURFACE`
@@ -214,6 +256,8 @@ pub enum FlightMode {
     Telemetry,
     /// `3D`
     ThreeD,
+    /// `TRAINER`
+    Trainer,
     /// `TURNASSIST`
     TurnAssist,
     /// `TURTLE`
@@ -237,6 +281,7 @@ fn as_name(&self) -> &'static str {
         match self {
             Self::AcroTrainer => "ACRO TRAINER",
             Self::Airmode => "AIRMODE",
+            Self::AltitudeHold => "ALTHOLD",
             Self::Angle => "ANGLE",
             Self::AngleHold => "ANGLEHOLD",
             Self::AntiGravity => "ANTI GRAVITY",
@@ -244,6 +289,7 @@ fn as_name(&self) -> &'static str {
             Self::AutoLevel => "AUTOLEVEL",
             Self::AutoTrim => "AUTOTRIM",
             Self::AutoTune => "AUTOTUNE",
+            Self::Baro => "BARO",
             Self::BeepGpsCount => "BEEP GPS COUNT",
             Self::BeeperMute => "BEEPER MUTE",
             Self::BeeperOn => "BEEPERON",
@@ -256,6 +302,7 @@ fn as_name(&self) -> &'static str {
             Self::Camera1 => "CAMERA1",
             Self::Camera2 => "CAMERA2",
             Self::Camera3 => "CAMERA3",
+            Self::Chirp => "CHIRP",
             Self::Failsafe => "FAILSAFE",
             Self::Flaperon => "FLAPERON",
             Self::FpvAngleMix => "FPV ANGLE MIX",
@@ -264,6 +311,9 @@ fn as_name(&self) -> &'static str {
             Self::Gimbalhtrk => "GIMBALHTRK",
             Self::Gimbalrlock => "GIMBALRLOCK",
             Self::Gimbaltlock => "GIMBALTLOCK",
+            Self::GovernorBypass => "GOVERNOR BYPASS",
+            Self::GpsHold => "GPS HOLD",
+            Self::GpsHome => "GPS HOME",
             Self::GpsRescue => "GPS RESCUE",
             Self::HeadAdjust => "HEADADJ",
             Self::HeadFree => "HEADFREE",
@@ -299,16 +349,20 @@ fn as_name(&self) -> &'static str {
             Self::Passthru => "PASSTHRU",
             Self::PidAudio => "PID AUDIO",
             Self::PlanWpMission => "PLANWPMISSION",
+            Self::PositionHold => "POSHOLD",
             Self::Prearm => "PREARM",
             Self::Ready => "READY",
+            Self::Rescue => "RESCUE",
             Self::Servo1 => "SERVO1",
             Self::Servo2 => "SERVO2",
             Self::Servo3 => "SERVO3",
             Self::Soaring => "SOARING",
+            Self::Sonar => "SONAR",
             Self::StickCommandDisable => "STICK COMMAND DISABLE",
             Self::Surface => "SURFACE",
             Self::Telemetry => "TELEMETRY",
             Self::ThreeD => "3D",
+            Self::Trainer => "TRAINER",
             Self::TurnAssist => "TURNASSIST",
             Self::Turtle => "TURTLE",
             Self::User1 => "USER1",
@@ -339,201 +393,362 @@ const fn from_bit(bit: u32, fw: crate::headers::InternalFirmware) -> Option<Self
         match (bit, fw) {
             (
                 0u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Inav5 | Inav6
+                | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
             ) => Some(Self::Arm),
+            (0u32, Cleanflight1) => Some(Self::Angle),
             (
                 1u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Inav5 | Inav6
+                | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
             ) => Some(Self::Angle),
+            (1u32, Cleanflight1) => Some(Self::Horizon),
             (
                 2u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Inav5 | Inav6
+                | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
             ) => Some(Self::Horizon),
-            (3u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Mag)
-            }
-            (3u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::NavAltitudeHold),
-            (4u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::HeadFree)
-            }
-            (4u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::HeadingHold),
-            (5u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Passthru)
-            }
-            (5u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::HeadFree),
-            (6u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Failsafe)
-            }
-            (6u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::HeadAdjust),
-            (7u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::GpsRescue)
-            }
-            (7u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::CamStab),
-            (8u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::AntiGravity)
-            }
-            (8u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::NavRth),
-            (9u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::HeadAdjust)
-            }
-            (9u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::NavPositionHold),
-            (10u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::CamStab)
-            }
-            (10u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Manual),
+            (2u32, Cleanflight1) => Some(Self::Mag),
+            (
+                3u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(Self::Mag),
+            (3u32, Cleanflight1) => Some(Self::Baro),
+            (3u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::NavAltitudeHold),
+            (3u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::AltitudeHold),
+            (
+                4u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::HeadFree),
+            (4u32, Betaflight4_6) => Some(Self::AltitudeHold),
+            (4u32, Cleanflight1) => Some(Self::GpsHome),
+            (4u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::HeadingHold),
+            (4u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::Rescue),
+            (
+                5u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::Passthru),
+            (5u32, Betaflight4_6 | Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::HeadFree),
+            (5u32, Cleanflight1) => Some(Self::GpsHold),
+            (5u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::Failsafe),
+            (
+                6u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::Failsafe),
+            (6u32, Betaflight4_6) => Some(Self::Chirp),
+            (6u32, Cleanflight1) => Some(Self::HeadFree),
+            (6u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::HeadAdjust),
+            (6u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::GpsRescue),
+            (
+                7u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::GpsRescue),
+            (7u32, Betaflight4_6) => Some(Self::Passthru),
+            (7u32, Cleanflight1) => Some(Self::AutoTune),
+            (7u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::CamStab),
+            (7u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::Trainer),
+            (
+                8u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::AntiGravity),
+            (8u32, Betaflight4_6) => Some(Self::Failsafe),
+            (8u32, Cleanflight1) => Some(Self::Passthru),
+            (8u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::NavRth),
+            (8u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::BeeperOn),
+            (
+                9u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::HeadAdjust),
+            (9u32, Betaflight4_6) => Some(Self::PositionHold),
+            (9u32, Cleanflight1) => Some(Self::Sonar),
+            (9u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::NavPositionHold),
+            (9u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::LedLow),
+            (
+                10u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::CamStab),
+            (10u32, Betaflight4_6) => Some(Self::GpsRescue),
+            (10u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Manual),
+            (10u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::Calibration),
             (
                 11u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4 | Inav5 | Inav6 | Inav7 | Inav8
+                | Inav9,
             ) => Some(Self::BeeperOn),
+            (11u32, Betaflight4_6) => Some(Self::AntiGravity),
+            (11u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::Osd),
             (
                 12u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4 | Inav5 | Inav6 | Inav7 | Inav8
+                | Inav9,
             ) => Some(Self::LedLow),
-            (13u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Calibration)
-            }
-            (13u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Lights),
-            (14u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Osd)
-            }
-            (14u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::NavLaunch),
-            (15u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Telemetry)
-            }
-            (15u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Osd),
-            (16u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Servo1)
-            }
-            (16u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Telemetry),
-            (17u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Servo2)
-            }
-            (17u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Blackbox),
-            (18u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Servo3)
-            }
-            (18u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Failsafe),
-            (19u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Blackbox)
-            }
-            (19u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::NavWaypoint),
+            (12u32, Betaflight4_6) => Some(Self::HeadAdjust),
+            (12u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::Telemetry),
+            (
+                13u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::Calibration),
+            (13u32, Betaflight4_6) => Some(Self::CamStab),
+            (13u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Lights),
+            (13u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::Blackbox),
+            (
+                14u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::Osd),
+            (14u32, Betaflight4_6) => Some(Self::BeeperOn),
+            (14u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::NavLaunch),
+            (14u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::BlackboxErase),
+            (
+                15u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::Telemetry),
+            (15u32, Betaflight4_6) => Some(Self::LedLow),
+            (15u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Osd),
+            (15u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::Camera1),
+            (
+                16u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::Servo1),
+            (16u32, Betaflight4_6) => Some(Self::Calibration),
+            (16u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Telemetry),
+            (16u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::Camera2),
+            (
+                17u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::Servo2),
+            (17u32, Betaflight4_6) => Some(Self::Osd),
+            (17u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Blackbox),
+            (17u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::Camera3),
+            (
+                18u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::Servo3),
+            (18u32, Betaflight4_6) => Some(Self::Telemetry),
+            (18u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Failsafe),
+            (18u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::Prearm),
+            (
+                19u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::Blackbox),
+            (19u32, Betaflight4_6) => Some(Self::Servo1),
+            (19u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::NavWaypoint),
+            (19u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::BeepGpsCount),
             (
                 20u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4 | Inav5 | Inav6 | Inav7 | Inav8
+                | Inav9,
             ) => Some(Self::Airmode),
-            (21u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::ThreeD)
-            }
-            (21u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::HomeReset),
-            (22u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::FpvAngleMix)
-            }
-            (22u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::GcsNav),
-            (23u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::BlackboxErase)
-            }
+            (20u32, Betaflight4_6) => Some(Self::Servo2),
+            (20u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::VtxPitMode),
+            (
+                21u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::ThreeD),
+            (21u32, Betaflight4_6) => Some(Self::Servo3),
+            (21u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::HomeReset),
+            (21u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::Paralyze),
+            (
+                22u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::FpvAngleMix),
+            (22u32, Betaflight4_6) => Some(Self::Blackbox),
+            (22u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::GcsNav),
+            (22u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::User1),
+            (
+                23u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::BlackboxErase),
+            (23u32, Betaflight4_6) => Some(Self::Airmode),
             (23u32, Inav5 | Inav6 | Inav7) => Some(Self::Killswitch),
-            (24u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Camera1)
-            }
-            (24u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Surface),
-            (25u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Camera2)
-            }
-            (25u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Flaperon),
-            (26u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Camera3)
-            }
-            (26u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::TurnAssist),
-            (27u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Turtle)
-            }
-            (27u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::AutoTrim),
-            (28u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Prearm)
-            }
-            (28u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::AutoTune),
-            (29u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::BeepGpsCount)
-            }
-            (29u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Camera1),
-            (30u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::VtxPitMode)
-            }
-            (30u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Camera2),
-            (31u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Paralyze)
-            }
-            (31u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Camera3),
-            (32u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::User1)
-            }
-            (32u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::OsdAlt1),
-            (33u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::User2)
-            }
-            (33u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::OsdAlt2),
-            (34u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::User3)
-            }
-            (34u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::OsdAlt3),
-            (35u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::User4)
-            }
-            (35u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::NavCourseHold),
-            (36u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::PidAudio)
-            }
-            (36u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Braking),
-            (37u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::AcroTrainer)
-            }
-            (37u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::User1),
-            (38u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
+            (23u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::User2),
+            (
+                24u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::Camera1),
+            (24u32, Betaflight4_6) => Some(Self::ThreeD),
+            (24u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Surface),
+            (24u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::User3),
+            (
+                25u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::Camera2),
+            (25u32, Betaflight4_6) => Some(Self::FpvAngleMix),
+            (25u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Flaperon),
+            (25u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::User4),
+            (
+                26u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::Camera3),
+            (26u32, Betaflight4_6) => Some(Self::BlackboxErase),
+            (26u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::TurnAssist),
+            (26u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => {
                 Some(Self::VtxControlDisable)
             }
-            (38u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::User2),
-            (39u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::LaunchControl)
-            }
-            (39u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::FpvAngleMix),
-            (40u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::MspOverride)
-            }
-            (40u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::LoiterChange),
-            (41u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
+            (
+                27u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::Turtle),
+            (27u32, Betaflight4_6) => Some(Self::Camera1),
+            (27u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::AutoTrim),
+            (27u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::MspOverride),
+            (
+                28u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::Prearm),
+            (28u32, Betaflight4_6) => Some(Self::Camera2),
+            (28u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::AutoTune),
+            (28u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => {
                 Some(Self::StickCommandDisable)
             }
-            (41u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::MspRcOverride),
+            (
+                29u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::BeepGpsCount),
+            (29u32, Betaflight4_6) => Some(Self::Camera3),
+            (29u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Camera1),
+            (29u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::BeeperMute),
+            (
+                30u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::VtxPitMode),
+            (30u32, Betaflight4_6) => Some(Self::Turtle),
+            (30u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Camera2),
+            (30u32, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(Self::GovernorBypass),
+            (
+                31u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::Paralyze),
+            (31u32, Betaflight4_6) => Some(Self::Prearm),
+            (31u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Camera3),
+            (
+                32u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::User1),
+            (32u32, Betaflight4_6) => Some(Self::BeepGpsCount),
+            (32u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::OsdAlt1),
+            (
+                33u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::User2),
+            (33u32, Betaflight4_6) => Some(Self::VtxPitMode),
+            (33u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::OsdAlt2),
+            (
+                34u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::User3),
+            (34u32, Betaflight4_6) => Some(Self::Paralyze),
+            (34u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::OsdAlt3),
+            (
+                35u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::User4),
+            (35u32, Betaflight4_6) => Some(Self::User1),
+            (35u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::NavCourseHold),
+            (
+                36u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::PidAudio),
+            (36u32, Betaflight4_6) => Some(Self::User2),
+            (36u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Braking),
+            (
+                37u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(Self::AcroTrainer),
+            (37u32, Betaflight4_6) => Some(Self::User3),
+            (37u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::User1),
+            (
+                38u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5,
+            ) => Some(Self::VtxControlDisable),
+            (38u32, Betaflight4_6) => Some(Self::User4),
+            (38u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::User2),
+            (
+                39u32,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5,
+            ) => Some(Self::LaunchControl),
+            (39u32, Betaflight4_6) => Some(Self::PidAudio),
+            (39u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::FpvAngleMix),
+            (
+                40u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5,
+            ) => Some(Self::MspOverride),
+            (40u32, Betaflight4_6) => Some(Self::AcroTrainer),
+            (40u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::LoiterChange),
+            (
+                41u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5,
+            ) => Some(Self::StickCommandDisable),
+            (41u32, Betaflight4_6) => Some(Self::VtxControlDisable),
+            (41u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::MspRcOverride),
             (42u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
                 Some(Self::BeeperMute)
             }
-            (42u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Prearm),
+            (42u32, Betaflight4_6) => Some(Self::LaunchControl),
+            (42u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Prearm),
             (43u32, Betaflight4_5) => Some(Self::Ready),
-            (43u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Turtle),
+            (43u32, Betaflight4_6) => Some(Self::MspOverride),
+            (43u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Turtle),
             (44u32, Betaflight4_5) => Some(Self::LapTimerReset),
-            (44u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::NavCruise),
-            (45u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::AutoLevel),
-            (46u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::PlanWpMission),
-            (47u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Soaring),
-            (48u32, Inav6 | Inav7 | Inav8) => Some(Self::User3),
-            (49u32, Inav6 | Inav7 | Inav8) => Some(Self::User4),
-            (50u32, Inav6 | Inav7 | Inav8) => Some(Self::MissionChange),
-            (51u32, Inav7 | Inav8) => Some(Self::Beepermute),
-            (52u32, Inav7 | Inav8) => Some(Self::Multifunction),
-            (53u32, Inav7 | Inav8) => Some(Self::Mixerprofile),
-            (54u32, Inav7 | Inav8) => Some(Self::Mixertransition),
-            (55u32, Inav7 | Inav8) => Some(Self::AngleHold),
-            (56u32, Inav8) => Some(Self::Gimbaltlock),
-            (57u32, Inav8) => Some(Self::Gimbalrlock),
-            (58u32, Inav8) => Some(Self::Gimbalcenter),
-            (59u32, Inav8) => Some(Self::Gimbalhtrk),
+            (44u32, Betaflight4_6) => Some(Self::StickCommandDisable),
+            (44u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::NavCruise),
+            (45u32, Betaflight4_6) => Some(Self::BeeperMute),
+            (45u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::AutoLevel),
+            (46u32, Betaflight4_6) => Some(Self::Ready),
+            (46u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::PlanWpMission),
+            (47u32, Betaflight4_6) => Some(Self::LapTimerReset),
+            (47u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Soaring),
+            (48u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::User3),
+            (49u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::User4),
+            (50u32, Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::MissionChange),
+            (51u32, Inav7 | Inav8 | Inav9) => Some(Self::Beepermute),
+            (52u32, Inav7 | Inav8 | Inav9) => Some(Self::Multifunction),
+            (53u32, Inav7 | Inav8 | Inav9) => Some(Self::Mixerprofile),
+            (54u32, Inav7 | Inav8 | Inav9) => Some(Self::Mixertransition),
+            (55u32, Inav7 | Inav8 | Inav9) => Some(Self::AngleHold),
+            (56u32, Inav8 | Inav9) => Some(Self::Gimbaltlock),
+            (57u32, Inav8 | Inav9) => Some(Self::Gimbalrlock),
+            (58u32, Inav8 | Inav9) => Some(Self::Gimbalcenter),
+            (59u32, Inav8 | Inav9) => Some(Self::Gimbalhtrk),
             _ => None,
         }
     }
@@ -543,205 +758,362 @@ const fn to_bit(self, fw: crate::headers::InternalFirmware) -> Option<u32> {
         match (self, fw) {
             (
                 Self::Arm,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Inav5 | Inav6
+                | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
             ) => Some(0u32),
+            (Self::Angle, Cleanflight1) => Some(0u32),
             (
                 Self::Angle,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Inav5 | Inav6
+                | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
             ) => Some(1u32),
+            (Self::Horizon, Cleanflight1) => Some(1u32),
             (
                 Self::Horizon,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Inav5 | Inav6
+                | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
             ) => Some(2u32),
-            (Self::Mag, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(3u32)
-            }
-            (Self::NavAltitudeHold, Inav5 | Inav6 | Inav7 | Inav8) => Some(3u32),
-            (Self::HeadFree, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(4u32)
-            }
-            (Self::HeadingHold, Inav5 | Inav6 | Inav7 | Inav8) => Some(4u32),
-            (Self::Passthru, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(5u32)
-            }
-            (Self::HeadFree, Inav5 | Inav6 | Inav7 | Inav8) => Some(5u32),
-            (Self::Failsafe, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(6u32)
-            }
-            (Self::HeadAdjust, Inav5 | Inav6 | Inav7 | Inav8) => Some(6u32),
-            (Self::GpsRescue, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(7u32)
-            }
-            (Self::CamStab, Inav5 | Inav6 | Inav7 | Inav8) => Some(7u32),
-            (Self::AntiGravity, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(8u32)
-            }
-            (Self::NavRth, Inav5 | Inav6 | Inav7 | Inav8) => Some(8u32),
-            (Self::HeadAdjust, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(9u32)
-            }
-            (Self::NavPositionHold, Inav5 | Inav6 | Inav7 | Inav8) => Some(9u32),
-            (Self::CamStab, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(10u32)
-            }
-            (Self::Manual, Inav5 | Inav6 | Inav7 | Inav8) => Some(10u32),
+            (Self::Mag, Cleanflight1) => Some(2u32),
+            (
+                Self::Mag,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(3u32),
+            (Self::Baro, Cleanflight1) => Some(3u32),
+            (Self::NavAltitudeHold, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(3u32),
+            (Self::AltitudeHold, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(3u32),
+            (
+                Self::HeadFree,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(4u32),
+            (Self::AltitudeHold, Betaflight4_6) => Some(4u32),
+            (Self::GpsHome, Cleanflight1) => Some(4u32),
+            (Self::HeadingHold, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(4u32),
+            (Self::Rescue, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(4u32),
+            (
+                Self::Passthru,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(5u32),
+            (Self::HeadFree, Betaflight4_6 | Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(5u32),
+            (Self::GpsHold, Cleanflight1) => Some(5u32),
+            (Self::Failsafe, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(5u32),
+            (
+                Self::Failsafe,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(6u32),
+            (Self::Chirp, Betaflight4_6) => Some(6u32),
+            (Self::HeadFree, Cleanflight1) => Some(6u32),
+            (Self::HeadAdjust, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(6u32),
+            (Self::GpsRescue, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(6u32),
+            (
+                Self::GpsRescue,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(7u32),
+            (Self::Passthru, Betaflight4_6) => Some(7u32),
+            (Self::AutoTune, Cleanflight1) => Some(7u32),
+            (Self::CamStab, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(7u32),
+            (Self::Trainer, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(7u32),
+            (
+                Self::AntiGravity,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(8u32),
+            (Self::Failsafe, Betaflight4_6) => Some(8u32),
+            (Self::Passthru, Cleanflight1) => Some(8u32),
+            (Self::NavRth, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(8u32),
+            (Self::BeeperOn, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(8u32),
+            (
+                Self::HeadAdjust,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(9u32),
+            (Self::PositionHold, Betaflight4_6) => Some(9u32),
+            (Self::Sonar, Cleanflight1) => Some(9u32),
+            (Self::NavPositionHold, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(9u32),
+            (Self::LedLow, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(9u32),
+            (
+                Self::CamStab,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(10u32),
+            (Self::GpsRescue, Betaflight4_6) => Some(10u32),
+            (Self::Manual, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(10u32),
+            (Self::Calibration, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(10u32),
             (
                 Self::BeeperOn,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4 | Inav5 | Inav6 | Inav7 | Inav8
+                | Inav9,
             ) => Some(11u32),
+            (Self::AntiGravity, Betaflight4_6) => Some(11u32),
+            (Self::Osd, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(11u32),
             (
                 Self::LedLow,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4 | Inav5 | Inav6 | Inav7 | Inav8
+                | Inav9,
             ) => Some(12u32),
-            (Self::Calibration, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(13u32)
-            }
-            (Self::Lights, Inav5 | Inav6 | Inav7 | Inav8) => Some(13u32),
-            (Self::Osd, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(14u32)
-            }
-            (Self::NavLaunch, Inav5 | Inav6 | Inav7 | Inav8) => Some(14u32),
-            (Self::Telemetry, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(15u32)
-            }
-            (Self::Osd, Inav5 | Inav6 | Inav7 | Inav8) => Some(15u32),
-            (Self::Servo1, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(16u32)
-            }
-            (Self::Telemetry, Inav5 | Inav6 | Inav7 | Inav8) => Some(16u32),
-            (Self::Servo2, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(17u32)
-            }
-            (Self::Blackbox, Inav5 | Inav6 | Inav7 | Inav8) => Some(17u32),
-            (Self::Servo3, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(18u32)
-            }
-            (Self::Failsafe, Inav5 | Inav6 | Inav7 | Inav8) => Some(18u32),
-            (Self::Blackbox, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(19u32)
-            }
-            (Self::NavWaypoint, Inav5 | Inav6 | Inav7 | Inav8) => Some(19u32),
+            (Self::HeadAdjust, Betaflight4_6) => Some(12u32),
+            (Self::Telemetry, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(12u32),
+            (
+                Self::Calibration,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(13u32),
+            (Self::CamStab, Betaflight4_6) => Some(13u32),
+            (Self::Lights, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(13u32),
+            (Self::Blackbox, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(13u32),
+            (
+                Self::Osd,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(14u32),
+            (Self::BeeperOn, Betaflight4_6) => Some(14u32),
+            (Self::NavLaunch, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(14u32),
+            (Self::BlackboxErase, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(14u32),
+            (
+                Self::Telemetry,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(15u32),
+            (Self::LedLow, Betaflight4_6) => Some(15u32),
+            (Self::Osd, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(15u32),
+            (Self::Camera1, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(15u32),
+            (
+                Self::Servo1,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(16u32),
+            (Self::Calibration, Betaflight4_6) => Some(16u32),
+            (Self::Telemetry, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(16u32),
+            (Self::Camera2, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(16u32),
+            (
+                Self::Servo2,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(17u32),
+            (Self::Osd, Betaflight4_6) => Some(17u32),
+            (Self::Blackbox, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(17u32),
+            (Self::Camera3, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(17u32),
+            (
+                Self::Servo3,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(18u32),
+            (Self::Telemetry, Betaflight4_6) => Some(18u32),
+            (Self::Failsafe, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(18u32),
+            (Self::Prearm, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(18u32),
+            (
+                Self::Blackbox,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(19u32),
+            (Self::Servo1, Betaflight4_6) => Some(19u32),
+            (Self::NavWaypoint, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(19u32),
+            (Self::BeepGpsCount, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(19u32),
             (
                 Self::Airmode,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4 | Inav5 | Inav6 | Inav7 | Inav8
+                | Inav9,
             ) => Some(20u32),
-            (Self::ThreeD, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(21u32)
-            }
-            (Self::HomeReset, Inav5 | Inav6 | Inav7 | Inav8) => Some(21u32),
-            (Self::FpvAngleMix, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(22u32)
-            }
-            (Self::GcsNav, Inav5 | Inav6 | Inav7 | Inav8) => Some(22u32),
+            (Self::Servo2, Betaflight4_6) => Some(20u32),
+            (Self::VtxPitMode, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(20u32),
+            (
+                Self::ThreeD,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(21u32),
+            (Self::Servo3, Betaflight4_6) => Some(21u32),
+            (Self::HomeReset, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(21u32),
+            (Self::Paralyze, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(21u32),
+            (
+                Self::FpvAngleMix,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(22u32),
+            (Self::Blackbox, Betaflight4_6) => Some(22u32),
+            (Self::GcsNav, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(22u32),
+            (Self::User1, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(22u32),
             (
                 Self::BlackboxErase,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
             ) => Some(23u32),
+            (Self::Airmode, Betaflight4_6) => Some(23u32),
             (Self::Killswitch, Inav5 | Inav6 | Inav7) => Some(23u32),
-            (Self::Camera1, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(24u32)
-            }
-            (Self::Surface, Inav5 | Inav6 | Inav7 | Inav8) => Some(24u32),
-            (Self::Camera2, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(25u32)
-            }
-            (Self::Flaperon, Inav5 | Inav6 | Inav7 | Inav8) => Some(25u32),
-            (Self::Camera3, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
+            (Self::User2, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(23u32),
+            (
+                Self::Camera1,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(24u32),
+            (Self::ThreeD, Betaflight4_6) => Some(24u32),
+            (Self::Surface, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(24u32),
+            (Self::User3, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(24u32),
+            (
+                Self::Camera2,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(25u32),
+            (Self::FpvAngleMix, Betaflight4_6) => Some(25u32),
+            (Self::Flaperon, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(25u32),
+            (Self::User4, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(25u32),
+            (
+                Self::Camera3,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(26u32),
+            (Self::BlackboxErase, Betaflight4_6) => Some(26u32),
+            (Self::TurnAssist, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(26u32),
+            (Self::VtxControlDisable, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => {
                 Some(26u32)
             }
-            (Self::TurnAssist, Inav5 | Inav6 | Inav7 | Inav8) => Some(26u32),
-            (Self::Turtle, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(27u32)
-            }
-            (Self::AutoTrim, Inav5 | Inav6 | Inav7 | Inav8) => Some(27u32),
-            (Self::Prearm, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
+            (
+                Self::Turtle,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(27u32),
+            (Self::Camera1, Betaflight4_6) => Some(27u32),
+            (Self::AutoTrim, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(27u32),
+            (Self::MspOverride, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(27u32),
+            (
+                Self::Prearm,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(28u32),
+            (Self::Camera2, Betaflight4_6) => Some(28u32),
+            (Self::AutoTune, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(28u32),
+            (Self::StickCommandDisable, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => {
                 Some(28u32)
             }
-            (Self::AutoTune, Inav5 | Inav6 | Inav7 | Inav8) => Some(28u32),
-            (Self::BeepGpsCount, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(29u32)
-            }
-            (Self::Camera1, Inav5 | Inav6 | Inav7 | Inav8) => Some(29u32),
-            (Self::VtxPitMode, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(30u32)
-            }
-            (Self::Camera2, Inav5 | Inav6 | Inav7 | Inav8) => Some(30u32),
-            (Self::Paralyze, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(31u32)
-            }
-            (Self::Camera3, Inav5 | Inav6 | Inav7 | Inav8) => Some(31u32),
-            (Self::User1, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(32u32)
-            }
-            (Self::OsdAlt1, Inav5 | Inav6 | Inav7 | Inav8) => Some(32u32),
-            (Self::User2, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(33u32)
-            }
-            (Self::OsdAlt2, Inav5 | Inav6 | Inav7 | Inav8) => Some(33u32),
-            (Self::User3, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(34u32)
-            }
-            (Self::OsdAlt3, Inav5 | Inav6 | Inav7 | Inav8) => Some(34u32),
-            (Self::User4, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(35u32)
-            }
-            (Self::NavCourseHold, Inav5 | Inav6 | Inav7 | Inav8) => Some(35u32),
-            (Self::PidAudio, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(36u32)
-            }
-            (Self::Braking, Inav5 | Inav6 | Inav7 | Inav8) => Some(36u32),
-            (Self::AcroTrainer, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(37u32)
-            }
-            (Self::User1, Inav5 | Inav6 | Inav7 | Inav8) => Some(37u32),
+            (
+                Self::BeepGpsCount,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(29u32),
+            (Self::Camera3, Betaflight4_6) => Some(29u32),
+            (Self::Camera1, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(29u32),
+            (Self::BeeperMute, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(29u32),
+            (
+                Self::VtxPitMode,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(30u32),
+            (Self::Turtle, Betaflight4_6) => Some(30u32),
+            (Self::Camera2, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(30u32),
+            (Self::GovernorBypass, Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5) => Some(30u32),
+            (
+                Self::Paralyze,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(31u32),
+            (Self::Prearm, Betaflight4_6) => Some(31u32),
+            (Self::Camera3, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(31u32),
+            (
+                Self::User1,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(32u32),
+            (Self::BeepGpsCount, Betaflight4_6) => Some(32u32),
+            (Self::OsdAlt1, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(32u32),
+            (
+                Self::User2,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(33u32),
+            (Self::VtxPitMode, Betaflight4_6) => Some(33u32),
+            (Self::OsdAlt2, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(33u32),
+            (
+                Self::User3,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(34u32),
+            (Self::Paralyze, Betaflight4_6) => Some(34u32),
+            (Self::OsdAlt3, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(34u32),
+            (
+                Self::User4,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(35u32),
+            (Self::User1, Betaflight4_6) => Some(35u32),
+            (Self::NavCourseHold, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(35u32),
+            (
+                Self::PidAudio,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(36u32),
+            (Self::User2, Betaflight4_6) => Some(36u32),
+            (Self::Braking, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(36u32),
+            (
+                Self::AcroTrainer,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | EmuFlight0_4,
+            ) => Some(37u32),
+            (Self::User3, Betaflight4_6) => Some(37u32),
+            (Self::User1, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(37u32),
             (
                 Self::VtxControlDisable,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5,
             ) => Some(38u32),
-            (Self::User2, Inav5 | Inav6 | Inav7 | Inav8) => Some(38u32),
+            (Self::User4, Betaflight4_6) => Some(38u32),
+            (Self::User2, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(38u32),
             (
                 Self::LaunchControl,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5,
+                Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4
+                | Betaflight4_5,
             ) => Some(39u32),
-            (Self::FpvAngleMix, Inav5 | Inav6 | Inav7 | Inav8) => Some(39u32),
-            (Self::MspOverride, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(40u32)
-            }
-            (Self::LoiterChange, Inav5 | Inav6 | Inav7 | Inav8) => Some(40u32),
+            (Self::PidAudio, Betaflight4_6) => Some(39u32),
+            (Self::FpvAngleMix, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(39u32),
+            (
+                Self::MspOverride,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5,
+            ) => Some(40u32),
+            (Self::AcroTrainer, Betaflight4_6) => Some(40u32),
+            (Self::LoiterChange, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(40u32),
             (
                 Self::StickCommandDisable,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5,
             ) => Some(41u32),
-            (Self::MspRcOverride, Inav5 | Inav6 | Inav7 | Inav8) => Some(41u32),
+            (Self::VtxControlDisable, Betaflight4_6) => Some(41u32),
+            (Self::MspRcOverride, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(41u32),
             (Self::BeeperMute, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
                 Some(42u32)
             }
-            (Self::Prearm, Inav5 | Inav6 | Inav7 | Inav8) => Some(42u32),
+            (Self::LaunchControl, Betaflight4_6) => Some(42u32),
+            (Self::Prearm, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(42u32),
             (Self::Ready, Betaflight4_5) => Some(43u32),
-            (Self::Turtle, Inav5 | Inav6 | Inav7 | Inav8) => Some(43u32),
+            (Self::MspOverride, Betaflight4_6) => Some(43u32),
+            (Self::Turtle, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(43u32),
             (Self::LapTimerReset, Betaflight4_5) => Some(44u32),
-            (Self::NavCruise, Inav5 | Inav6 | Inav7 | Inav8) => Some(44u32),
-            (Self::AutoLevel, Inav5 | Inav6 | Inav7 | Inav8) => Some(45u32),
-            (Self::PlanWpMission, Inav5 | Inav6 | Inav7 | Inav8) => Some(46u32),
-            (Self::Soaring, Inav5 | Inav6 | Inav7 | Inav8) => Some(47u32),
-            (Self::User3, Inav6 | Inav7 | Inav8) => Some(48u32),
-            (Self::User4, Inav6 | Inav7 | Inav8) => Some(49u32),
-            (Self::MissionChange, Inav6 | Inav7 | Inav8) => Some(50u32),
-            (Self::Beepermute, Inav7 | Inav8) => Some(51u32),
-            (Self::Multifunction, Inav7 | Inav8) => Some(52u32),
-            (Self::Mixerprofile, Inav7 | Inav8) => Some(53u32),
-            (Self::Mixertransition, Inav7 | Inav8) => Some(54u32),
-            (Self::AngleHold, Inav7 | Inav8) => Some(55u32),
-            (Self::Gimbaltlock, Inav8) => Some(56u32),
-            (Self::Gimbalrlock, Inav8) => Some(57u32),
-            (Self::Gimbalcenter, Inav8) => Some(58u32),
-            (Self::Gimbalhtrk, Inav8) => Some(59u32),
+            (Self::StickCommandDisable, Betaflight4_6) => Some(44u32),
+            (Self::NavCruise, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(44u32),
+            (Self::BeeperMute, Betaflight4_6) => Some(45u32),
+            (Self::AutoLevel, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(45u32),
+            (Self::Ready, Betaflight4_6) => Some(46u32),
+            (Self::PlanWpMission, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(46u32),
+            (Self::LapTimerReset, Betaflight4_6) => Some(47u32),
+            (Self::Soaring, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(47u32),
+            (Self::User3, Inav6 | Inav7 | Inav8 | Inav9) => Some(48u32),
+            (Self::User4, Inav6 | Inav7 | Inav8 | Inav9) => Some(49u32),
+            (Self::MissionChange, Inav6 | Inav7 | Inav8 | Inav9) => Some(50u32),
+            (Self::Beepermute, Inav7 | Inav8 | Inav9) => Some(51u32),
+            (Self::Multifunction, Inav7 | Inav8 | Inav9) => Some(52u32),
+            (Self::Mixerprofile, Inav7 | Inav8 | Inav9) => Some(53u32),
+            (Self::Mixertransition, Inav7 | Inav8 | Inav9) => Some(54u32),
+            (Self::AngleHold, Inav7 | Inav8 | Inav9) => Some(55u32),
+            (Self::Gimbaltlock, Inav8 | Inav9) => Some(56u32),
+            (Self::Gimbalrlock, Inav8 | Inav9) => Some(57u32),
+            (Self::Gimbalcenter, Inav8 | Inav9) => Some(58u32),
+            (Self::Gimbalhtrk, Inav8 | Inav9) => Some(59u32),
             _ => None,
         }
     }
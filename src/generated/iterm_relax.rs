@@ -0,0 +1,78 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The axes on which I-term relax is enabled.
+#[non_exhaustive]
+pub enum ItermRelax {
+    /// `OFF`
+    Off,
+    /// `RP`
+    Rp,
+    /// `RP_INC`
+    RpIncrement,
+    /// `RPY`
+    Rpy,
+    /// `RPY_INC`
+    RpyIncrement,
+}
+#[allow(unused_qualifications)]
+impl crate::units::Flag for ItermRelax {
+    fn as_name(&self) -> &'static str {
+        match self {
+            Self::Off => "OFF",
+            Self::Rp => "RP",
+            Self::RpIncrement => "RP_INC",
+            Self::Rpy => "RPY",
+            Self::RpyIncrement => "RPY_INC",
+        }
+    }
+}
+#[allow(unused_qualifications)]
+impl ::core::fmt::Display for ItermRelax {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let s = <Self as crate::units::Flag>::as_name(self);
+        f.write_str(s)
+    }
+}
+#[allow(
+    unused_qualifications,
+    clippy::enum_glob_use,
+    clippy::match_same_arms,
+    clippy::unseparated_literal_suffix
+)]
+impl ItermRelax {
+    pub(crate) fn new(raw: u32, fw: crate::headers::InternalFirmware) -> Option<Self> {
+        use crate::headers::InternalFirmware::*;
+        match (raw, fw) {
+            (
+                0u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(Self::Off),
+            (
+                1u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(Self::Rp),
+            (
+                2u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(Self::Rpy),
+            (
+                3u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(Self::RpIncrement),
+            (
+                4u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(Self::RpyIncrement),
+            _ => {
+                #[allow(clippy::redundant_closure_call)]
+                (|raw| tracing::debug!("invalid iterm_relax: {raw}"))(raw);
+                None
+            }
+        }
+    }
+}
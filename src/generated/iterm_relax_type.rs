@@ -0,0 +1,54 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// How I-term relax detects fast stick movements.
+#[non_exhaustive]
+pub enum ItermRelaxType {
+    /// `GYRO`
+    Gyro,
+    /// `SETPOINT`
+    Setpoint,
+}
+#[allow(unused_qualifications)]
+impl crate::units::Flag for ItermRelaxType {
+    fn as_name(&self) -> &'static str {
+        match self {
+            Self::Gyro => "GYRO",
+            Self::Setpoint => "SETPOINT",
+        }
+    }
+}
+#[allow(unused_qualifications)]
+impl ::core::fmt::Display for ItermRelaxType {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let s = <Self as crate::units::Flag>::as_name(self);
+        f.write_str(s)
+    }
+}
+#[allow(
+    unused_qualifications,
+    clippy::enum_glob_use,
+    clippy::match_same_arms,
+    clippy::unseparated_literal_suffix
+)]
+impl ItermRelaxType {
+    pub(crate) fn new(raw: u32, fw: crate::headers::InternalFirmware) -> Option<Self> {
+        use crate::headers::InternalFirmware::*;
+        match (raw, fw) {
+            (
+                0u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(Self::Gyro),
+            (
+                1u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4,
+            ) => Some(Self::Setpoint),
+            _ => {
+                #[allow(clippy::redundant_closure_call)]
+                (|raw| tracing::debug!("invalid iterm_relax_type: {raw}"))(raw);
+                None
+            }
+        }
+    }
+}
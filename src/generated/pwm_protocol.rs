@@ -1,5 +1,5 @@
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "_serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// An ESC communication protocol.
 #[non_exhaustive]
 pub enum PwmProtocol {
@@ -7,6 +7,8 @@ pub enum PwmProtocol {
     Brushed,
     /// `DISABLED`
     Disabled,
+    /// `DSHOT1200`
+    Dshot1200,
     /// `DSHOT150`
     Dshot150,
     /// `DSHOT300`
@@ -30,6 +32,7 @@ fn as_name(&self) -> &'static str {
         match self {
             Self::Brushed => "BRUSHED",
             Self::Disabled => "DISABLED",
+            Self::Dshot1200 => "DSHOT1200",
             Self::Dshot150 => "DSHOT150",
             Self::Dshot300 => "DSHOT300",
             Self::Dshot600 => "DSHOT600",
@@ -60,43 +63,71 @@ pub(crate) fn new(raw: u32, fw: crate::headers::InternalFirmware) -> Option<Self
         match (raw, fw) {
             (
                 0u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4
+                | Rotorflight4_5,
             ) => Some(Self::Standard),
             (
                 1u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4
+                | Rotorflight4_5,
             ) => Some(Self::Oneshot125),
-            (2u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Oneshot42)
-            }
-            (2u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Multishot),
-            (3u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Multishot)
-            }
-            (3u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Brushed),
-            (4u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Brushed)
-            }
-            (4u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Dshot150),
-            (5u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Dshot150)
-            }
-            (5u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Dshot300),
-            (6u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Dshot300)
-            }
-            (6u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Dshot600),
-            (7u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Dshot600)
-            }
-            (8u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Proshot1000)
-            }
-            (9u32, Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => {
-                Some(Self::Disabled)
-            }
+            (
+                2u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Oneshot42),
+            (2u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Multishot),
+            (
+                3u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Multishot),
+            (3u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Brushed),
+            (
+                4u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Brushed),
+            (4u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Dshot150),
+            (
+                5u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Dshot150),
+            (5u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Dshot300),
+            (
+                6u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Dshot300),
+            (6u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Dshot600),
+            (
+                7u32,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | EmuFlight0_4 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Dshot600),
+            (8u32, Betaflight3_5 | Betaflight4_0 | EmuFlight0_4) => Some(Self::Dshot1200),
+            (
+                8u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Proshot1000),
+            (9u32, Betaflight3_5 | Betaflight4_0 | EmuFlight0_4) => Some(Self::Proshot1000),
+            (
+                9u32,
+                Betaflight4_1 | Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5
+                | Betaflight4_6 | Rotorflight4_3 | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::Disabled),
             _ => {
                 #[allow(clippy::redundant_closure_call)]
                 (|raw| tracing::error!("invalid pwm protocol: {raw}"))(raw);
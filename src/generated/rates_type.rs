@@ -0,0 +1,73 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The curve used to convert stick positions into rotation rates.
+#[non_exhaustive]
+pub enum RatesType {
+    /// `ACTUAL`
+    Actual,
+    /// `BETAFLIGHT`
+    Betaflight,
+    /// `KISS`
+    Kiss,
+    /// `QUICK`
+    Quick,
+    /// `RACEFLIGHT`
+    Raceflight,
+}
+#[allow(unused_qualifications)]
+impl crate::units::Flag for RatesType {
+    fn as_name(&self) -> &'static str {
+        match self {
+            Self::Actual => "ACTUAL",
+            Self::Betaflight => "BETAFLIGHT",
+            Self::Kiss => "KISS",
+            Self::Quick => "QUICK",
+            Self::Raceflight => "RACEFLIGHT",
+        }
+    }
+}
+#[allow(unused_qualifications)]
+impl ::core::fmt::Display for RatesType {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let s = <Self as crate::units::Flag>::as_name(self);
+        f.write_str(s)
+    }
+}
+#[allow(
+    unused_qualifications,
+    clippy::enum_glob_use,
+    clippy::match_same_arms,
+    clippy::unseparated_literal_suffix
+)]
+impl RatesType {
+    pub(crate) fn new(raw: u32, fw: crate::headers::InternalFirmware) -> Option<Self> {
+        use crate::headers::InternalFirmware::*;
+        match (raw, fw) {
+            (
+                0u32,
+                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6,
+            ) => Some(Self::Betaflight),
+            (
+                1u32,
+                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6,
+            ) => Some(Self::Raceflight),
+            (
+                2u32,
+                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6,
+            ) => Some(Self::Kiss),
+            (
+                3u32,
+                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6,
+            ) => Some(Self::Actual),
+            (
+                4u32,
+                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6,
+            ) => Some(Self::Quick),
+            _ => {
+                #[allow(clippy::redundant_closure_call)]
+                (|raw| tracing::debug!("invalid rates type: {raw}"))(raw);
+                None
+            }
+        }
+    }
+}
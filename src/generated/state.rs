@@ -44,7 +44,7 @@ fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.write_str(&names.join("|"))
     }
 }
-#[cfg(feature = "_serde")]
+#[cfg(feature = "serde")]
 #[allow(clippy::cast_possible_truncation)]
 impl ::serde::Serialize for StateSet {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -59,8 +59,32 @@ fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         seq.end()
     }
 }
+/// Only the flags are serialized, so this picks a firmware that
+/// supports all of them, trying the newest versions first.
+#[cfg(feature = "serde")]
+#[allow(unused_qualifications)]
+impl<'de> ::serde::Deserialize<'de> for StateSet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let flags = <::alloc::vec::Vec<State>>::deserialize(deserializer)?;
+        crate::headers::InternalFirmware::ALL
+            .iter()
+            .rev()
+            .find_map(|&firmware| {
+                let raw = flags.iter().try_fold(0_u32, |raw, flag| {
+                    flag.to_bit(firmware).map(|bit| raw | (1 << bit))
+                })?;
+                Some(Self::new(raw, firmware))
+            })
+            .ok_or_else(|| {
+                <D::Error as serde::de::Error>::custom("no supported firmware has all flags")
+            })
+    }
+}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "_serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A flight controller state.
 ///
 /// See [`Flag`][crate::units::Flag].
@@ -86,6 +110,8 @@ pub enum State {
     CompassCalibrated,
     /// `ESC_SENSOR_ENABLED`
     EscSensorEnabled,
+    /// `FIXED_WING`
+    FixedWing,
     /// `FIXED_WING_LEGACY`
     FixedWingLegacy,
     /// `FLAPERON_AVAILABLE`
@@ -141,6 +167,7 @@ fn as_name(&self) -> &'static str {
             Self::CalibrateMag => "CALIBRATE_MAG",
             Self::CompassCalibrated => "COMPASS_CALIBRATED",
             Self::EscSensorEnabled => "ESC_SENSOR_ENABLED",
+            Self::FixedWing => "FIXED_WING",
             Self::FixedWingLegacy => "FIXED_WING_LEGACY",
             Self::FlaperonAvailable => "FLAPERON_AVAILABLE",
             Self::FwHeadingUseYaw => "FW_HEADING_USE_YAW",
@@ -183,42 +210,57 @@ const fn from_bit(bit: u32, fw: crate::headers::InternalFirmware) -> Option<Self
         match (bit, fw) {
             (
                 0u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4
+                | Rotorflight4_5,
             ) => Some(Self::GpsFixHome),
             (
                 1u32,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4
+                | Rotorflight4_5,
             ) => Some(Self::GpsFix),
-            (2u32, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(Self::GpsFixEver),
-            (2u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::CalibrateMag),
-            (3u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::SmallAngle),
-            (4u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::FixedWingLegacy),
-            (5u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::AntiWindup),
-            (6u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::FlaperonAvailable),
-            (7u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::NavMotorStopOrIdle),
-            (8u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::CompassCalibrated),
-            (9u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::AccelerometerCalibrated),
-            (10u32, Inav8) => Some(Self::GpsEstimatedFix),
-            (11u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::NavCruiseBraking),
-            (12u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::NavCruiseBrakingBoost),
-            (13u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::NavCruiseBrakingLocked),
-            (14u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::NavExtraArmingSafetyBypassed),
-            (15u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::AirMode),
-            (16u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::EscSensorEnabled),
-            (17u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Airplane),
-            (18u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Multirotor),
-            (19u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Rover),
-            (20u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::Boat),
-            (21u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::AltitudeControl),
-            (22u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::MoveForwardOnly),
-            (23u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::SetReversibleMotorsForward),
-            (24u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::FwHeadingUseYaw),
-            (25u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::AntiWindupDeactivated),
-            (26u32, Inav5 | Inav6 | Inav7 | Inav8) => Some(Self::LandingDetected),
-            (27u32, Inav7 | Inav8) => Some(Self::InFlightEmergencyRearm),
-            (28u32, Inav7 | Inav8) => Some(Self::Tailsitter),
+            (
+                2u32,
+                Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(Self::GpsFixEver),
+            (2u32, Cleanflight1 | Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => {
+                Some(Self::CalibrateMag)
+            }
+            (3u32, Cleanflight1 | Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::SmallAngle),
+            (4u32, Cleanflight1) => Some(Self::FixedWing),
+            (4u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::FixedWingLegacy),
+            (5u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::AntiWindup),
+            (6u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::FlaperonAvailable),
+            (7u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::NavMotorStopOrIdle),
+            (8u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::CompassCalibrated),
+            (9u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::AccelerometerCalibrated),
+            (10u32, Inav8 | Inav9) => Some(Self::GpsEstimatedFix),
+            (11u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::NavCruiseBraking),
+            (12u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::NavCruiseBrakingBoost),
+            (13u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::NavCruiseBrakingLocked),
+            (14u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => {
+                Some(Self::NavExtraArmingSafetyBypassed)
+            }
+            (15u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::AirMode),
+            (16u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::EscSensorEnabled),
+            (17u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Airplane),
+            (18u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Multirotor),
+            (19u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Rover),
+            (20u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::Boat),
+            (21u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::AltitudeControl),
+            (22u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::MoveForwardOnly),
+            (23u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => {
+                Some(Self::SetReversibleMotorsForward)
+            }
+            (24u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::FwHeadingUseYaw),
+            (25u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::AntiWindupDeactivated),
+            (26u32, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(Self::LandingDetected),
+            (27u32, Inav7 | Inav8 | Inav9) => Some(Self::InFlightEmergencyRearm),
+            (28u32, Inav7 | Inav8 | Inav9) => Some(Self::Tailsitter),
             _ => None,
         }
     }
@@ -228,42 +270,57 @@ const fn to_bit(self, fw: crate::headers::InternalFirmware) -> Option<u32> {
         match (self, fw) {
             (
                 Self::GpsFixHome,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4
+                | Rotorflight4_5,
             ) => Some(0u32),
             (
                 Self::GpsFix,
-                Betaflight4_2 | Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Inav5 | Inav6
-                | Inav7 | Inav8,
+                Betaflight3_5 | Betaflight4_0 | Betaflight4_1 | Betaflight4_2 | Betaflight4_3
+                | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Cleanflight1 | EmuFlight0_4
+                | Inav5 | Inav6 | Inav7 | Inav8 | Inav9 | Rotorflight4_3 | Rotorflight4_4
+                | Rotorflight4_5,
             ) => Some(1u32),
-            (Self::GpsFixEver, Betaflight4_3 | Betaflight4_4 | Betaflight4_5) => Some(2u32),
-            (Self::CalibrateMag, Inav5 | Inav6 | Inav7 | Inav8) => Some(2u32),
-            (Self::SmallAngle, Inav5 | Inav6 | Inav7 | Inav8) => Some(3u32),
-            (Self::FixedWingLegacy, Inav5 | Inav6 | Inav7 | Inav8) => Some(4u32),
-            (Self::AntiWindup, Inav5 | Inav6 | Inav7 | Inav8) => Some(5u32),
-            (Self::FlaperonAvailable, Inav5 | Inav6 | Inav7 | Inav8) => Some(6u32),
-            (Self::NavMotorStopOrIdle, Inav5 | Inav6 | Inav7 | Inav8) => Some(7u32),
-            (Self::CompassCalibrated, Inav5 | Inav6 | Inav7 | Inav8) => Some(8u32),
-            (Self::AccelerometerCalibrated, Inav5 | Inav6 | Inav7 | Inav8) => Some(9u32),
-            (Self::GpsEstimatedFix, Inav8) => Some(10u32),
-            (Self::NavCruiseBraking, Inav5 | Inav6 | Inav7 | Inav8) => Some(11u32),
-            (Self::NavCruiseBrakingBoost, Inav5 | Inav6 | Inav7 | Inav8) => Some(12u32),
-            (Self::NavCruiseBrakingLocked, Inav5 | Inav6 | Inav7 | Inav8) => Some(13u32),
-            (Self::NavExtraArmingSafetyBypassed, Inav5 | Inav6 | Inav7 | Inav8) => Some(14u32),
-            (Self::AirMode, Inav5 | Inav6 | Inav7 | Inav8) => Some(15u32),
-            (Self::EscSensorEnabled, Inav5 | Inav6 | Inav7 | Inav8) => Some(16u32),
-            (Self::Airplane, Inav5 | Inav6 | Inav7 | Inav8) => Some(17u32),
-            (Self::Multirotor, Inav5 | Inav6 | Inav7 | Inav8) => Some(18u32),
-            (Self::Rover, Inav5 | Inav6 | Inav7 | Inav8) => Some(19u32),
-            (Self::Boat, Inav5 | Inav6 | Inav7 | Inav8) => Some(20u32),
-            (Self::AltitudeControl, Inav5 | Inav6 | Inav7 | Inav8) => Some(21u32),
-            (Self::MoveForwardOnly, Inav5 | Inav6 | Inav7 | Inav8) => Some(22u32),
-            (Self::SetReversibleMotorsForward, Inav5 | Inav6 | Inav7 | Inav8) => Some(23u32),
-            (Self::FwHeadingUseYaw, Inav5 | Inav6 | Inav7 | Inav8) => Some(24u32),
-            (Self::AntiWindupDeactivated, Inav5 | Inav6 | Inav7 | Inav8) => Some(25u32),
-            (Self::LandingDetected, Inav5 | Inav6 | Inav7 | Inav8) => Some(26u32),
-            (Self::InFlightEmergencyRearm, Inav7 | Inav8) => Some(27u32),
-            (Self::Tailsitter, Inav7 | Inav8) => Some(28u32),
+            (
+                Self::GpsFixEver,
+                Betaflight4_3 | Betaflight4_4 | Betaflight4_5 | Betaflight4_6 | Rotorflight4_3
+                | Rotorflight4_4 | Rotorflight4_5,
+            ) => Some(2u32),
+            (Self::CalibrateMag, Cleanflight1 | Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => {
+                Some(2u32)
+            }
+            (Self::SmallAngle, Cleanflight1 | Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(3u32),
+            (Self::FixedWing, Cleanflight1) => Some(4u32),
+            (Self::FixedWingLegacy, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(4u32),
+            (Self::AntiWindup, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(5u32),
+            (Self::FlaperonAvailable, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(6u32),
+            (Self::NavMotorStopOrIdle, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(7u32),
+            (Self::CompassCalibrated, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(8u32),
+            (Self::AccelerometerCalibrated, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(9u32),
+            (Self::GpsEstimatedFix, Inav8 | Inav9) => Some(10u32),
+            (Self::NavCruiseBraking, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(11u32),
+            (Self::NavCruiseBrakingBoost, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(12u32),
+            (Self::NavCruiseBrakingLocked, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(13u32),
+            (Self::NavExtraArmingSafetyBypassed, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => {
+                Some(14u32)
+            }
+            (Self::AirMode, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(15u32),
+            (Self::EscSensorEnabled, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(16u32),
+            (Self::Airplane, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(17u32),
+            (Self::Multirotor, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(18u32),
+            (Self::Rover, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(19u32),
+            (Self::Boat, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(20u32),
+            (Self::AltitudeControl, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(21u32),
+            (Self::MoveForwardOnly, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(22u32),
+            (Self::SetReversibleMotorsForward, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => {
+                Some(23u32)
+            }
+            (Self::FwHeadingUseYaw, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(24u32),
+            (Self::AntiWindupDeactivated, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(25u32),
+            (Self::LandingDetected, Inav5 | Inav6 | Inav7 | Inav8 | Inav9) => Some(26u32),
+            (Self::InFlightEmergencyRearm, Inav7 | Inav8 | Inav9) => Some(27u32),
+            (Self::Tailsitter, Inav7 | Inav8 | Inav9) => Some(28u32),
             _ => None,
         }
     }
@@ -2,21 +2,25 @@
 
 use alloc::borrow::ToOwned as _;
 use alloc::string::String;
+use alloc::vec::Vec;
 use core::str::FromStr;
-use core::{cmp, fmt, str};
+use core::{cmp, fmt, iter, str};
 
 use hashbrown::HashMap;
 use time::PrimitiveDateTime;
 
+use crate::config::Config;
 use crate::frame::gps::{GpsFrameDef, GpsFrameDefBuilder};
 use crate::frame::gps_home::{GpsHomeFrameDef, GpsHomeFrameDefBuilder};
-use crate::frame::main::{MainFrameDef, MainFrameDefBuilder};
+use crate::frame::main::{MainFrameDef, MainFrameDefBuilder, MainUnit};
 use crate::frame::slow::{SlowFrameDef, SlowFrameDefBuilder};
 use crate::frame::{is_frame_def_header, parse_frame_def_header, DataFrameKind};
-use crate::parser::{InternalError, InternalResult};
+use crate::parser::{DataVersion, InternalError, InternalResult};
 use crate::predictor::Predictor;
-use crate::{DataParser, FilterSet, Reader, Unit};
+use crate::units::prelude::{microsecond, Time};
+use crate::{DataParser, FilterSet, PushParser, Reader, Unit};
 
+include_generated!("debug_fields");
 include_generated!("debug_mode");
 include_generated!("disabled_fields");
 include_generated!("features");
@@ -26,7 +30,7 @@
 
 /// A fatal error encountered while parsing the headers of a log.
 #[derive(Debug, Clone)]
-#[cfg_attr(feature = "_serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ParseError {
     /// The log uses a data format version that is unsupported or could not be
     /// parsed.
@@ -38,9 +42,8 @@ pub enum ParseError {
     UnsupportedFirmwareVersion(Firmware),
     /// Could not parse the value in header `header`.
     InvalidHeader { header: String, value: String },
-    // TODO: include header
-    /// Did not find a required header.
-    MissingHeader,
+    /// Did not find one or more required headers, listed by name.
+    MissingHeaders(Vec<String>),
     /// The file ended before the start of the data section.
     IncompleteHeaders,
     /// Definition for frame type `frame` is missing required a required field.
@@ -62,8 +65,17 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             Self::InvalidHeader { header, value } => {
                 write!(f, "invalid value for header `{header}`: `{value}`")
             }
-            Self::MissingHeader => {
-                write!(f, "one or more headers required for parsing are missing")
+            Self::MissingHeaders(headers) => {
+                f.write_str("missing required headers: ")?;
+
+                for (i, header) in headers.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "`{header}`")?;
+                }
+
+                Ok(())
             }
             Self::IncompleteHeaders => write!(f, "end of file found before data section"),
             Self::MissingField { frame, field } => {
@@ -81,6 +93,8 @@ impl core::error::Error for ParseError {}
 #[non_exhaustive]
 pub struct Headers<'data> {
     data: Reader<'data>,
+    /// The format of the data section.
+    pub(crate) data_version: DataVersion,
 
     main_frame_def: MainFrameDef<'data>,
     slow_frame_def: SlowFrameDef<'data>,
@@ -99,8 +113,16 @@ pub struct Headers<'data> {
     features: FeatureSet,
     pwm_protocol: PwmProtocol,
 
+    frame_intervals: FrameIntervals,
+    /// The flight controller loop time in microseconds.
+    looptime: Option<u32>,
+    pid_process_denom: Option<u16>,
+
     /// The battery voltage measured at arm.
     pub(crate) vbat_reference: Option<u16>,
+    /// Calibration for battery voltage ADC readings, used by Betaflight before
+    /// 4.0.
+    pub(crate) vbat_scale: Option<u8>,
     /// Calibration for the accelerometer.
     pub(crate) acceleration_1g: Option<u16>,
     /// Calibration for the gyro in radians / second.
@@ -125,12 +147,13 @@ pub(crate) fn parse(data: &'data [u8]) -> ParseResult<Self> {
         // Skip product header
         let product = data.read_line();
         debug_assert_eq!(crate::MARKER.strip_suffix(b"\n"), product);
-        let data_version = data.read_line();
-        if !matches!(data_version, Some(b"H Data version:2")) {
-            return Err(ParseError::UnsupportedDataVersion);
-        }
+        let data_version = match data.read_line() {
+            Some(b"H Data version:1") => DataVersion::V1,
+            Some(b"H Data version:2") => DataVersion::V2,
+            _ => return Err(ParseError::UnsupportedDataVersion),
+        };
 
-        let mut state = State::new();
+        let mut state = State::new(data_version);
 
         loop {
             if data.peek() != Some(b'H') {
@@ -193,11 +216,32 @@ fn validate(&self) -> ParseResult<()> {
         };
 
         let unit = |frame, field, unit| {
-            if unit == Unit::Acceleration && !has_accel {
+            let ok = match unit {
+                Unit::Acceleration => has_accel,
+                Unit::Motor => has_min_motor,
+                Unit::Amperage
+                | Unit::Stick
+                | Unit::Ratio
+                | Unit::Duration
+                | Unit::Voltage
+                | Unit::Rotation
+                | Unit::Frequency
+                | Unit::FlightMode
+                | Unit::State
+                | Unit::FailsafePhase
+                | Unit::GpsCoordinate
+                | Unit::Altitude
+                | Unit::Velocity
+                | Unit::GpsHeading
+                | Unit::Boolean
+                | Unit::Unitless => true,
+            };
+
+            if ok {
+                Ok(())
+            } else {
                 tracing::error!(field, ?unit, "bad unit");
                 Err(ParseError::MalformedFrameDef(frame))
-            } else {
-                Ok(())
             }
         };
 
@@ -217,21 +261,49 @@ fn validate(&self) -> ParseResult<()> {
 }
 
 impl<'data> Headers<'data> {
+    /// Returns a [`Reader`] positioned at the start of the data section.
+    pub(crate) fn data(&self) -> Reader<'data> {
+        self.data.clone()
+    }
+
     /// Returns a new [`DataParser`] without beginning parsing.
     pub fn data_parser<'headers>(&'headers self) -> DataParser<'data, 'headers> {
-        DataParser::new(self.data.clone(), self, &FilterSet::default())
+        DataParser::new(self.data(), self, &FilterSet::default())
     }
 
     pub fn data_parser_with_filters<'headers>(
         &'headers self,
         filters: &FilterSet,
     ) -> DataParser<'data, 'headers> {
-        DataParser::new(self.data.clone(), self, filters)
+        DataParser::new(self.data(), self, filters)
+    }
+
+    /// Returns a new [`PushParser`] that has not been fed any data.
+    ///
+    /// Unlike [`data_parser`][Self::data_parser], this ignores any data
+    /// following the headers. All of the data section must be passed to
+    /// [`PushParser::feed`].
+    pub fn push_parser<'headers>(&'headers self) -> PushParser<'data, 'headers> {
+        PushParser::new(self, &FilterSet::default())
+    }
+
+    pub fn push_parser_with_filters<'headers>(
+        &'headers self,
+        filters: &FilterSet,
+    ) -> PushParser<'data, 'headers> {
+        PushParser::new(self, filters)
     }
 }
 
 /// Getters for various log headers.
 impl<'data> Headers<'data> {
+    /// Returns the offset of the start of the data section from the start of
+    /// the log.
+    #[inline]
+    pub fn data_offset(&self) -> usize {
+        self.data.index()
+    }
+
     #[inline]
     pub fn main_frame_def(&self) -> &MainFrameDef<'data> {
         &self.main_frame_def
@@ -252,7 +324,8 @@ pub(crate) fn gps_home_frame_def(&self) -> Option<&GpsHomeFrameDef<'data>> {
         self.gps_home_frame_def.as_ref()
     }
 
-    /// The full `Firmware revision` header.
+    /// The full `Firmware revision` header, or an empty string for legacy logs
+    /// without one.
     ///
     /// Consider using the [`firmware`][Self::firmware] method instead.
     #[inline]
@@ -293,6 +366,11 @@ pub fn debug_mode(&self) -> DebugMode {
         self.debug_mode
     }
 
+    /// Returns the meaning of each `debug[n]` field in the current debug mode.
+    pub(crate) fn debug_fields(&self) -> &'static [Option<DebugField>] {
+        debug_fields(self.debug_mode, self.internal_firmware)
+    }
+
     #[inline]
     pub fn disabled_fields(&self) -> DisabledFields {
         self.disabled_fields
@@ -308,11 +386,52 @@ pub fn pwm_protocol(&self) -> PwmProtocol {
         self.pwm_protocol
     }
 
+    /// How often main frames are logged.
+    #[inline]
+    pub fn frame_intervals(&self) -> FrameIntervals {
+        self.frame_intervals
+    }
+
+    /// The average time between logged main frames, calculated from the
+    /// [`frame_intervals`][Self::frame_intervals] and the `looptime` header.
+    ///
+    /// Returns `None` if the log does not include its loop time.
+    pub fn main_frame_period(&self) -> Option<Time> {
+        let iteration = f64::from(self.looptime?) * f64::from(self.pid_process_denom.unwrap_or(1));
+        let (num, denom) = self.frame_intervals.inter();
+        let period = iteration * f64::from(denom) / f64::from(num);
+
+        Some(Time::new::<microsecond>(period))
+    }
+
     /// Any unknown headers.
     #[inline]
     pub fn unknown(&self) -> &HashMap<&'data str, &'data str> {
         &self.unknown
     }
+
+    /// Decodes the flight controller configuration from the
+    /// [`unknown`][Self::unknown] headers.
+    pub fn config(&self) -> Config<'data> {
+        Config::new(self)
+    }
+
+    /// Iterates over the names and values of every header in the order they
+    /// appear in the log, including the `Product` and `Data version` headers.
+    pub fn iter_raw(&self) -> impl Iterator<Item = (&'data str, &'data str)> + 'data {
+        let mut data = Reader::new(self.data.read_bytes());
+        iter::from_fn(move || parse_header(&mut data).ok())
+    }
+}
+
+/// The meaning of one `debug[n]` field in a [`DebugMode`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DebugField {
+    pub(crate) name: &'static str,
+    pub(crate) unit: MainUnit,
+    /// Multiplied with the raw value to get a value in degrees per second,
+    /// hertz, percent or microseconds, depending on the unit
+    pub(crate) scale: f64,
 }
 
 /// A supported firmware.
@@ -321,12 +440,28 @@ pub fn unknown(&self) -> &HashMap<&'data str, &'data str> {
 /// firmwares set that to `Cleanflight`. This is instead decoded from `Firmware
 /// revision`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "_serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Firmware {
     /// [Betaflight](https://github.com/betaflight/betaflight/)
     Betaflight(FirmwareVersion),
     /// [INAV](https://github.com/iNavFlight/inav/)
     Inav(FirmwareVersion),
+    /// [EmuFlight](https://github.com/emuflight/EmuFlight/)
+    EmuFlight(FirmwareVersion),
+    /// [Rotorflight](https://github.com/rotorflight/rotorflight-firmware/)
+    ///
+    /// Rotorflight reports the version of Betaflight it is based on, eg `4.3.0`
+    /// for Rotorflight 2.0.
+    Rotorflight(FirmwareVersion),
+    /// [Cleanflight](https://github.com/cleanflight/cleanflight/) before 2.0
+    ///
+    /// Logs using `Data version:1` often only record a git hash as their
+    /// revision. These report version `0.0.0`.
+    Cleanflight(FirmwareVersion),
+    /// [Baseflight](https://github.com/multiwii/baseflight/)
+    ///
+    /// Baseflight does not record its version, so this is always `0.0.0`.
+    Baseflight(FirmwareVersion),
 }
 
 impl Firmware {
@@ -334,11 +469,20 @@ pub const fn name(&self) -> &'static str {
         match self {
             Firmware::Betaflight(_) => "Betaflight",
             Firmware::Inav(_) => "INAV",
+            Firmware::EmuFlight(_) => "EmuFlight",
+            Firmware::Rotorflight(_) => "Rotorflight",
+            Firmware::Cleanflight(_) => "Cleanflight",
+            Firmware::Baseflight(_) => "Baseflight",
         }
     }
 
     pub const fn version(&self) -> FirmwareVersion {
-        let (Self::Betaflight(version) | Self::Inav(version)) = self;
+        let (Self::Betaflight(version)
+        | Self::Inav(version)
+        | Self::EmuFlight(version)
+        | Self::Rotorflight(version)
+        | Self::Cleanflight(version)
+        | Self::Baseflight(version)) = self;
         *version
     }
 
@@ -361,10 +505,19 @@ fn parse(firmware_revision: &str) -> Result<Self, ParseError> {
                 Firmware::Inav(version),
                 crate::INAV_SUPPORT.contains(&version),
             ),
-            Some("emuflight") => {
-                tracing::error!("EmuFlight is not supported");
-                return invalid_fw();
-            }
+            Some("emuflight") => (
+                Firmware::EmuFlight(version),
+                crate::EMUFLIGHT_SUPPORT.contains(&version),
+            ),
+            Some("rotorflight") => (
+                Firmware::Rotorflight(version),
+                crate::ROTORFLIGHT_SUPPORT.contains(&version),
+            ),
+            Some("cleanflight") => (
+                Firmware::Cleanflight(version),
+                crate::CLEANFLIGHT_SUPPORT.contains(&version),
+            ),
+            Some("baseflight") => (Firmware::Baseflight(version), true),
             _ => {
                 tracing::error!("Could not parse firmware revision");
                 return invalid_fw();
@@ -377,13 +530,31 @@ fn parse(firmware_revision: &str) -> Result<Self, ParseError> {
             Err(ParseError::UnsupportedFirmwareVersion(fw))
         }
     }
+
+    /// Identifies the firmware of a `Data version:1` log without a usable
+    /// `Firmware revision` using its `Firmware type` header instead.
+    fn legacy(kind: Option<&str>) -> Self {
+        let version = FirmwareVersion::new(0, 0, 0);
+
+        if kind.is_some_and(|kind| kind.eq_ignore_ascii_case("baseflight")) {
+            Self::Baseflight(version)
+        } else {
+            Self::Cleanflight(version)
+        }
+    }
 }
 
 impl PartialOrd for Firmware {
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
         match (self, other) {
             (Firmware::Betaflight(fw_self), Firmware::Betaflight(fw_other))
-            | (Firmware::Inav(fw_self), Firmware::Inav(fw_other)) => fw_self.partial_cmp(fw_other),
+            | (Firmware::Inav(fw_self), Firmware::Inav(fw_other))
+            | (Firmware::EmuFlight(fw_self), Firmware::EmuFlight(fw_other))
+            | (Firmware::Rotorflight(fw_self), Firmware::Rotorflight(fw_other))
+            | (Firmware::Cleanflight(fw_self), Firmware::Cleanflight(fw_other))
+            | (Firmware::Baseflight(fw_self), Firmware::Baseflight(fw_other)) => {
+                fw_self.partial_cmp(fw_other)
+            }
             _ => None,
         }
     }
@@ -405,7 +576,10 @@ pub const fn new(major: u8, minor: u8, patch: u8) -> Self {
         }
     }
 
+    /// Parses `major.minor.patch`, ignoring any pre-release suffix like `-RC1`
+    /// or `-dev`.
     fn parse(s: &str) -> Option<Self> {
+        let s = s.split_once('-').map_or(s, |(version, _suffix)| version);
         let mut components = s.splitn(3, '.').map(|s| s.parse().ok());
 
         let major = components.next()??;
@@ -426,7 +600,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
-#[cfg(feature = "_serde")]
+#[cfg(feature = "serde")]
 impl serde::Serialize for FirmwareVersion {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -437,33 +611,133 @@ fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FirmwareVersion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error as _;
+
+        let version = <alloc::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Self::parse(&version)
+            .ok_or_else(|| D::Error::custom(format_args!("invalid firmware version: {version}")))
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) enum InternalFirmware {
+    Betaflight3_5,
+    Betaflight4_0,
+    Betaflight4_1,
     Betaflight4_2,
     Betaflight4_3,
     Betaflight4_4,
     Betaflight4_5,
+    Betaflight4_6,
     Inav5,
     Inav6,
     Inav7,
     Inav8,
+    Inav9,
+    EmuFlight0_4,
+    Rotorflight4_3,
+    Rotorflight4_4,
+    Rotorflight4_5,
+    Cleanflight1,
 }
 
 impl InternalFirmware {
-    pub(crate) const fn is_betaflight(self) -> bool {
+    /// Every supported firmware, oldest first within each firmware.
+    #[cfg_attr(not(feature = "serde"), expect(dead_code))]
+    pub(crate) const ALL: [Self; 18] = [
+        Self::Betaflight3_5,
+        Self::Betaflight4_0,
+        Self::Betaflight4_1,
+        Self::Betaflight4_2,
+        Self::Betaflight4_3,
+        Self::Betaflight4_4,
+        Self::Betaflight4_5,
+        Self::Betaflight4_6,
+        Self::Inav5,
+        Self::Inav6,
+        Self::Inav7,
+        Self::Inav8,
+        Self::Inav9,
+        Self::EmuFlight0_4,
+        Self::Rotorflight4_3,
+        Self::Rotorflight4_4,
+        Self::Rotorflight4_5,
+        Self::Cleanflight1,
+    ];
+
+    /// Returns the name of the firmware, without its version.
+    const fn name(self) -> &'static str {
         match self {
-            Self::Betaflight4_2
+            Self::Betaflight3_5
+            | Self::Betaflight4_0
+            | Self::Betaflight4_1
+            | Self::Betaflight4_2
             | Self::Betaflight4_3
             | Self::Betaflight4_4
-            | Self::Betaflight4_5 => true,
-            Self::Inav5 | Self::Inav6 | Self::Inav7 | Self::Inav8 => false,
+            | Self::Betaflight4_5
+            | Self::Betaflight4_6 => "Betaflight",
+            Self::Inav5 | Self::Inav6 | Self::Inav7 | Self::Inav8 | Self::Inav9 => "INAV",
+            Self::EmuFlight0_4 => "EmuFlight",
+            Self::Rotorflight4_3 | Self::Rotorflight4_4 | Self::Rotorflight4_5 => "Rotorflight",
+            Self::Cleanflight1 => "Cleanflight",
         }
     }
 
-    #[expect(unused)]
     pub(crate) const fn is_inav(self) -> bool {
-        // Will need to be changed if any new firmwares are added
-        !self.is_betaflight()
+        matches!(
+            self,
+            Self::Inav5 | Self::Inav6 | Self::Inav7 | Self::Inav8 | Self::Inav9
+        )
+    }
+
+    pub(crate) const fn is_rotorflight(self) -> bool {
+        matches!(
+            self,
+            Self::Rotorflight4_3 | Self::Rotorflight4_4 | Self::Rotorflight4_5
+        )
+    }
+
+    /// Returns the version of Betaflight this firmware shares its headers
+    /// with, or `None` if it is not based on Betaflight.
+    pub(crate) const fn betaflight_base(self) -> Option<Self> {
+        match self {
+            Self::Betaflight3_5
+            | Self::Betaflight4_0
+            | Self::Betaflight4_1
+            | Self::Betaflight4_2
+            | Self::Betaflight4_3
+            | Self::Betaflight4_4
+            | Self::Betaflight4_5
+            | Self::Betaflight4_6 => Some(self),
+            Self::EmuFlight0_4 => Some(Self::Betaflight4_0),
+            // Rotorflight reports the version of Betaflight it is based on
+            Self::Rotorflight4_3 => Some(Self::Betaflight4_3),
+            Self::Rotorflight4_4 => Some(Self::Betaflight4_4),
+            Self::Rotorflight4_5 => Some(Self::Betaflight4_5),
+            Self::Inav5
+            | Self::Inav6
+            | Self::Inav7
+            | Self::Inav8
+            | Self::Inav9
+            | Self::Cleanflight1 => None,
+        }
+    }
+
+    /// Returns `true` if `vbatLatest` is a raw ADC reading instead of
+    /// centivolts.
+    pub(crate) const fn logs_vbat_adc(self) -> bool {
+        matches!(self, Self::Cleanflight1)
+    }
+
+    /// Returns `true` if `vbatLatest` is in decivolts instead of centivolts.
+    pub(crate) const fn logs_vbat_decivolts(self) -> bool {
+        matches!(self, Self::Betaflight3_5)
     }
 }
 
@@ -471,6 +745,15 @@ impl From<Firmware> for InternalFirmware {
     fn from(fw: Firmware) -> Self {
         #[expect(clippy::wildcard_enum_match_arm)]
         match fw {
+            Firmware::Betaflight(FirmwareVersion {
+                major: 3, minor: 5, ..
+            }) => Self::Betaflight3_5,
+            Firmware::Betaflight(FirmwareVersion {
+                major: 4, minor: 0, ..
+            }) => Self::Betaflight4_0,
+            Firmware::Betaflight(FirmwareVersion {
+                major: 4, minor: 1, ..
+            }) => Self::Betaflight4_1,
             Firmware::Betaflight(FirmwareVersion {
                 major: 4, minor: 2, ..
             }) => Self::Betaflight4_2,
@@ -483,10 +766,28 @@ fn from(fw: Firmware) -> Self {
             Firmware::Betaflight(FirmwareVersion {
                 major: 4, minor: 5, ..
             }) => Self::Betaflight4_5,
+            Firmware::Betaflight(FirmwareVersion {
+                major: 4, minor: 6, ..
+            }) => Self::Betaflight4_6,
             Firmware::Inav(FirmwareVersion { major: 5, .. }) => Self::Inav5,
             Firmware::Inav(FirmwareVersion { major: 6, .. }) => Self::Inav6,
             Firmware::Inav(FirmwareVersion { major: 7, .. }) => Self::Inav7,
             Firmware::Inav(FirmwareVersion { major: 8, .. }) => Self::Inav8,
+            Firmware::Inav(FirmwareVersion { major: 9, .. }) => Self::Inav9,
+            Firmware::EmuFlight(FirmwareVersion {
+                major: 0, minor: 4, ..
+            }) => Self::EmuFlight0_4,
+            Firmware::Rotorflight(FirmwareVersion {
+                major: 4, minor: 3, ..
+            }) => Self::Rotorflight4_3,
+            Firmware::Rotorflight(FirmwareVersion {
+                major: 4, minor: 4, ..
+            }) => Self::Rotorflight4_4,
+            Firmware::Rotorflight(FirmwareVersion {
+                major: 4, minor: 5, ..
+            }) => Self::Rotorflight4_5,
+            // Both share the same legacy log format
+            Firmware::Cleanflight(_) | Firmware::Baseflight(_) => Self::Cleanflight1,
             _ => unreachable!(),
         }
     }
@@ -494,7 +795,7 @@ fn from(fw: Firmware) -> Self {
 
 impl PartialOrd for InternalFirmware {
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        if self.is_betaflight() != other.is_betaflight() {
+        if self.name() != other.name() {
             return None;
         }
 
@@ -505,7 +806,6 @@ fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct MotorOutputRange {
     pub(crate) min: u16,
-    #[expect(dead_code)]
     pub(crate) max: u16,
 }
 
@@ -518,6 +818,66 @@ pub(crate) fn from_str(s: &str) -> Option<Self> {
     }
 }
 
+/// How often main frames are logged, decoded from the `I interval`, `P
+/// interval` and `P ratio` headers.
+///
+/// All intervals are counted in loop iterations of the flight controller, ie
+/// increments of the `loopIteration` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FrameIntervals {
+    intra: u32,
+    inter_num: u32,
+    inter_denom: u32,
+}
+
+impl FrameIntervals {
+    fn new(intra: u16, inter_num: u16, inter_denom: u16) -> Self {
+        Self {
+            intra: intra.max(1).into(),
+            inter_num: inter_num.max(1).into(),
+            inter_denom: inter_denom.max(1).into(),
+        }
+    }
+
+    /// The number of iterations from one intraframe to the next.
+    #[inline]
+    pub const fn intra(&self) -> u32 {
+        self.intra
+    }
+
+    /// Returns `(num, denom)`, where `num` out of every `denom` iterations are
+    /// logged.
+    #[inline]
+    pub const fn inter(&self) -> (u32, u32) {
+        (self.inter_num, self.inter_denom)
+    }
+
+    /// Returns `true` if a main frame is logged for `iteration`.
+    pub const fn is_logged(&self, iteration: u32) -> bool {
+        (iteration % self.intra + self.inter_num - 1) % self.inter_denom < self.inter_num
+    }
+
+    /// Counts the iterations that are intentionally not logged between
+    /// iteration `last` and the next logged iteration.
+    pub(crate) fn skipped_after(&self, last: u32) -> u32 {
+        // Every multiple of `intra` is logged, so this always terminates
+        let mut skipped = 0;
+        let mut iteration = last.wrapping_add(1);
+        while !self.is_logged(iteration) {
+            skipped += 1;
+            iteration = iteration.wrapping_add(1);
+        }
+
+        skipped
+    }
+}
+
+impl Default for FrameIntervals {
+    fn default() -> Self {
+        Self::new(32, 1, 1)
+    }
+}
+
 #[derive(Debug)]
 struct RawHeaderValue<'data, T> {
     header: &'data str,
@@ -546,6 +906,8 @@ fn parse(header: &'data str, raw: &'data str) -> Result<Self, <T as FromStr>::Er
 
 #[derive(Debug)]
 struct State<'data> {
+    data_version: DataVersion,
+
     main_frames: MainFrameDefBuilder<'data>,
     slow_frames: SlowFrameDefBuilder<'data>,
     gps_frames: GpsFrameDefBuilder<'data>,
@@ -562,19 +924,29 @@ struct State<'data> {
     features: u32,
     pwm_protocol: Option<RawHeaderValue<'data, u32>>,
 
+    intra_interval: Option<u16>,
+    inter_interval: Option<(u16, u16)>,
+    inter_ratio: Option<u16>,
+    looptime: Option<u32>,
+    pid_process_denom: Option<u16>,
+
     vbat_reference: Option<u16>,
+    vbat_scale: Option<u8>,
     acceleration_1g: Option<u16>,
     gyro_scale: Option<f32>,
 
     min_throttle: Option<u16>,
+    max_throttle: Option<u16>,
     motor_output_range: Option<MotorOutputRange>,
 
     unknown: HashMap<&'data str, &'data str>,
 }
 
 impl<'data> State<'data> {
-    fn new() -> Self {
+    fn new(data_version: DataVersion) -> Self {
         Self {
+            data_version,
+
             main_frames: MainFrameDef::builder(),
             slow_frames: SlowFrameDef::builder(),
             gps_frames: GpsFrameDef::builder(),
@@ -591,11 +963,19 @@ fn new() -> Self {
             features: 0,
             pwm_protocol: None,
 
+            intra_interval: None,
+            inter_interval: None,
+            inter_ratio: None,
+            looptime: None,
+            pid_process_denom: None,
+
             vbat_reference: None,
+            vbat_scale: None,
             acceleration_1g: None,
             gyro_scale: None,
 
             min_throttle: None,
+            max_throttle: None,
             motor_output_range: None,
 
             unknown: HashMap::new(),
@@ -624,10 +1004,31 @@ fn update(&mut self, header: &'data str, value: &'data str) -> bool {
                     self.pwm_protocol = Some(protocol);
                 }
 
+                "I interval" => self.intra_interval = Some(value.parse().map_err(|_| ())?),
+                "P interval" => {
+                    // Older firmware writes `num/denom`, newer only `denom`
+                    let interval = if let Some((num, denom)) = value.split_once('/') {
+                        (num.parse().map_err(|_| ())?, denom.parse().map_err(|_| ())?)
+                    } else {
+                        (1, value.parse().map_err(|_| ())?)
+                    };
+
+                    self.inter_interval = Some(interval);
+                }
+                "P ratio" => self.inter_ratio = Some(value.parse().map_err(|_| ())?),
+                "looptime" => self.looptime = Some(value.parse().map_err(|_| ())?),
+                "pid_process_denom" => {
+                    self.pid_process_denom = Some(value.parse().map_err(|_| ())?);
+                }
+
                 "vbatref" => {
                     let vbat_reference = value.parse().map_err(|_| ())?;
                     self.vbat_reference = Some(vbat_reference);
                 }
+                "vbatscale" => {
+                    let vbat_scale = value.parse().map_err(|_| ())?;
+                    self.vbat_scale = Some(vbat_scale);
+                }
                 "acc_1G" => {
                     let one_g = value.parse().map_err(|_| ())?;
                     self.acceleration_1g = Some(one_g);
@@ -639,13 +1040,16 @@ fn update(&mut self, header: &'data str, value: &'data str) -> bool {
                         value.parse().map_err(|_| ())?
                     };
 
-                    let scale = f32::from_bits(scale);
-                    self.gyro_scale = Some(scale.to_radians());
+                    self.gyro_scale = Some(f32::from_bits(scale));
                 }
                 "minthrottle" => {
                     let min_throttle = value.parse().map_err(|_| ())?;
                     self.min_throttle = Some(min_throttle);
                 }
+                "maxthrottle" => {
+                    let max_throttle = value.parse().map_err(|_| ())?;
+                    self.max_throttle = Some(max_throttle);
+                }
                 "motorOutput" => {
                     let range = MotorOutputRange::from_str(value).ok_or(())?;
                     self.motor_output_range = Some(range);
@@ -665,7 +1069,7 @@ fn update(&mut self, header: &'data str, value: &'data str) -> bool {
                 }
 
                 // Legacy calibration headers
-                "vbatscale" | "vbat_scale" | "currentMeter" | "currentSensor" => {}
+                "vbat_scale" | "currentMeter" | "currentSensor" => {}
 
                 header => {
                     tracing::debug!("skipping unknown header: `{header}` = `{value}`");
@@ -681,46 +1085,107 @@ fn update(&mut self, header: &'data str, value: &'data str) -> bool {
     fn finish(self, data: Reader<'data>) -> ParseResult<Headers<'data>> {
         let not_empty = |s: &&str| !s.is_empty();
 
-        let firmware_revision = self.firmware_revision.ok_or(ParseError::MissingHeader)?;
-        let firmware = Firmware::parse(firmware_revision)?;
+        // Legacy logs predate these headers and any slow frames
+        let legacy = self.data_version == DataVersion::V1;
+
+        let mut missing = Vec::new();
+        if self.firmware_revision.is_none() && !legacy {
+            missing.push("Firmware revision".to_owned());
+        }
+        if self.pwm_protocol.is_none() && !legacy {
+            missing.push("motor_pwm_protocol".to_owned());
+        }
+        self.main_frames.missing_headers(&mut missing);
+        if !(legacy && self.slow_frames.is_empty()) {
+            self.slow_frames.missing_headers(&mut missing);
+        }
+        self.gps_frames.missing_headers(&mut missing);
+        self.gps_home_frames.missing_headers(&mut missing);
+
+        if !missing.is_empty() {
+            tracing::error!(?missing, "missing required headers");
+            return Err(ParseError::MissingHeaders(missing));
+        }
+
+        let firmware = match self.firmware_revision.map(Firmware::parse) {
+            Some(Ok(firmware)) => firmware,
+            // Legacy logs may only have a git hash as their revision
+            Some(Err(ParseError::InvalidFirmware(_))) | None if legacy => {
+                Firmware::legacy(self.firmware_kind)
+            }
+            Some(Err(err)) => return Err(err),
+            None => unreachable!("`Firmware revision` is required for data version 2"),
+        };
         let internal_firmware = firmware.into();
 
+        let frame_intervals = {
+            let intra = self.intra_interval.unwrap_or(32);
+
+            // `P ratio` is only needed if `P interval` is missing, since it is
+            // always `I interval / P interval`
+            let (num, denom) = self
+                .inter_interval
+                .or_else(|| self.inter_ratio.map(|ratio| (1, intra / ratio.max(1))))
+                .unwrap_or((1, 1));
+
+            FrameIntervals::new(intra, num, denom)
+        };
+
+        let debug_mode = self.debug_mode.map_or(Ok(DebugMode::None), |raw| {
+            DebugMode::new(raw.value, internal_firmware).ok_or_else(|| raw.invalid_header_error())
+        })?;
+
         // TODO: log where each error comes from
         let headers = Headers {
             data,
+            data_version: self.data_version,
 
-            main_frame_def: self.main_frames.parse()?,
+            main_frame_def: self
+                .main_frames
+                .parse(debug_fields(debug_mode, internal_firmware))?,
             slow_frame_def: self.slow_frames.parse()?,
             gps_frame_def: self.gps_frames.parse()?,
             gps_home_frame_def: self.gps_home_frames.parse()?,
 
-            firmware_revision,
+            firmware_revision: self.firmware_revision.unwrap_or_default(),
             internal_firmware,
             firmware,
             firmware_date: self.firmware_date,
             board_info: self.board_info.map(str::trim).filter(not_empty),
             craft_name: self.craft_name.map(str::trim).filter(not_empty),
 
-            debug_mode: self.debug_mode.map_or(Ok(DebugMode::None), |raw| {
-                DebugMode::new(raw.value, internal_firmware)
-                    .ok_or_else(|| raw.invalid_header_error())
-            })?,
+            debug_mode,
             disabled_fields: DisabledFields::new(self.disabled_fields, internal_firmware),
             features: FeatureSet::new(self.features, internal_firmware),
-            pwm_protocol: self
-                .pwm_protocol
-                .ok_or(ParseError::MissingHeader)
-                .and_then(|raw| {
-                    PwmProtocol::new(raw.value, internal_firmware)
-                        .ok_or_else(|| raw.invalid_header_error())
-                })?,
+            pwm_protocol: self.pwm_protocol.map_or(Ok(PwmProtocol::Standard), |raw| {
+                PwmProtocol::new(raw.value, internal_firmware)
+                    .ok_or_else(|| raw.invalid_header_error())
+            })?,
+
+            frame_intervals,
+            looptime: self.looptime,
+            pid_process_denom: self.pid_process_denom,
 
             vbat_reference: self.vbat_reference,
+            vbat_scale: self.vbat_scale,
             acceleration_1g: self.acceleration_1g,
-            gyro_scale: self.gyro_scale,
+            // Baseflight logs radians per microsecond, everything else degrees per second
+            gyro_scale: self.gyro_scale.map(|scale| {
+                if matches!(firmware, Firmware::Baseflight(_)) {
+                    scale * 1e6
+                } else {
+                    scale.to_radians()
+                }
+            }),
 
             min_throttle: self.min_throttle,
-            motor_output_range: self.motor_output_range,
+            // Legacy logs only have `minthrottle` and `maxthrottle`
+            motor_output_range: self.motor_output_range.or_else(|| {
+                legacy.then_some(MotorOutputRange {
+                    min: self.min_throttle?,
+                    max: self.max_throttle?,
+                })
+            }),
 
             unknown: self.unknown,
         };
@@ -752,6 +1217,9 @@ fn parse_header<'data>(bytes: &mut Reader<'data>) -> InternalResult<(&'data str,
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString as _;
+    use alloc::vec::Vec;
+
     use super::*;
 
     #[test]
@@ -760,4 +1228,342 @@ fn invalid_utf8() {
         let mut b = Reader::new(b"H \xFF:\xFF\n");
         parse_header(&mut b).unwrap();
     }
+
+    #[test]
+    fn missing_headers() {
+        let mut log = crate::MARKER.to_vec();
+        log.extend_from_slice(b"H Data version:2\n");
+        log.extend_from_slice(b"H Field I name:loopIteration,time\n");
+        log.extend_from_slice(b"H Field I signed:0,0\n");
+        log.extend_from_slice(b"H Field G name:time\n");
+
+        let err = Headers::parse(&log).unwrap_err();
+        let ParseError::MissingHeaders(ref missing) = err else {
+            panic!("expected missing headers, found: {err:?}");
+        };
+
+        let expected = [
+            "Firmware revision",
+            "motor_pwm_protocol",
+            "Field I predictor",
+            "Field P predictor",
+            "Field I encoding",
+            "Field P encoding",
+            "Field S name",
+            "Field S signed",
+            "Field S predictor",
+            "Field S encoding",
+            "Field G signed",
+            "Field G predictor",
+            "Field G encoding",
+        ];
+        assert_eq!(expected, **missing);
+        assert!(err
+            .to_string()
+            .starts_with("missing required headers: `Firmware revision`, `motor_pwm_protocol`, "));
+    }
+
+    #[test]
+    fn legacy_headers() {
+        use crate::frame::FrameDef as _;
+
+        let mut log = crate::MARKER.to_vec();
+        log.extend_from_slice(b"H Data version:1\n");
+        log.extend_from_slice(b"H Field I name:loopIteration,time,motor[0]\n");
+        log.extend_from_slice(b"H Field I signed:0,0,0\n");
+        log.extend_from_slice(b"H Field I predictor:0,0,4\n");
+        log.extend_from_slice(b"H Field I encoding:1,1,1\n");
+        log.extend_from_slice(b"H Field P predictor:6,2,3\n");
+        log.extend_from_slice(b"H Field P encoding:9,0,0\n");
+        log.extend_from_slice(b"H Firmware type:Baseflight\n");
+        log.extend_from_slice(b"H minthrottle:1150\n");
+        log.extend_from_slice(b"H maxthrottle:1850\n");
+        log.extend_from_slice(b"H gyro.scale:0x3089705f\n");
+
+        let headers = Headers::parse(&log).unwrap();
+        let version = FirmwareVersion::new(0, 0, 0);
+        assert_eq!(Firmware::Baseflight(version), headers.firmware());
+        assert_eq!("", headers.firmware_revision());
+        assert_eq!(PwmProtocol::Standard, headers.pwm_protocol());
+        assert_eq!(0, headers.slow_frame_def().len());
+
+        let range = headers.motor_output_range.unwrap();
+        assert_eq!((1150, 1850), (range.min, range.max));
+
+        // Baseflight's scale is in radians per microsecond
+        let scale = headers.gyro_scale.unwrap();
+        assert!((1e-3 - scale).abs() < 1e-9);
+    }
+
+    #[test]
+    fn legacy_firmware() {
+        let version = FirmwareVersion::new(1, 14, 2);
+        assert_eq!(
+            Firmware::Cleanflight(version),
+            Firmware::parse("Cleanflight 1.14.2 (d3e4f5a) NAZE").unwrap()
+        );
+        assert_eq!(
+            Firmware::Cleanflight(FirmwareVersion::new(0, 0, 0)),
+            Firmware::legacy(Some("Cleanflight"))
+        );
+        assert!(matches!(
+            Firmware::parse("Cleanflight 2.1.0 (a1b2c3d) SPRACINGF3"),
+            Err(ParseError::UnsupportedFirmwareVersion(_))
+        ));
+    }
+
+    #[test]
+    fn betaflight_3_5() {
+        use crate::writer::{write_log, Encoding, FieldSpec, HeaderSpec, Predictor};
+
+        let mut spec = HeaderSpec::new("Betaflight 3.5.7 (8d7c1ee2c) OMNIBUSF4SD");
+        spec.slow.push(FieldSpec {
+            name: "flightModeFlags",
+            signed: false,
+            predictor: Predictor::Zero,
+            encoding: Encoding::Variable,
+        });
+        spec.other.extend([
+            ("debug_mode", "26"),
+            ("motor_pwm_protocol", "9"),
+            ("vbatscale", "105"),
+        ]);
+
+        let log = write_log(&spec, |_| {});
+        let headers = Headers::parse(&log).unwrap();
+
+        assert_eq!(
+            Firmware::Betaflight(FirmwareVersion::new(3, 5, 7)),
+            headers.firmware()
+        );
+        assert_eq!(DebugMode::DualGyroCombine, headers.debug_mode());
+        assert_eq!(PwmProtocol::Proshot1000, headers.pwm_protocol());
+        assert_eq!(Some(105), headers.vbat_scale);
+    }
+
+    #[test]
+    fn pre_release_versions() {
+        let firmware = Firmware::parse("Rotorflight 4.3.0-RC1 (19d3380) STM32G47X").unwrap();
+        assert_eq!(
+            Firmware::Rotorflight(FirmwareVersion::new(4, 3, 0)),
+            firmware
+        );
+
+        let firmware = Firmware::parse("Betaflight 4.5.0-dev (2b6b2a0d3) STM32F7X2").unwrap();
+        assert_eq!(
+            Firmware::Betaflight(FirmwareVersion::new(4, 5, 0)),
+            firmware
+        );
+
+        assert_eq!(None, FirmwareVersion::parse("4.3-RC1"));
+    }
+
+    #[test]
+    fn emuflight() {
+        let firmware = Firmware::parse("EmuFlight 0.4.1 (6a1dd4d7a) HELIOSPRING").unwrap();
+        assert_eq!(Firmware::EmuFlight(FirmwareVersion::new(0, 4, 1)), firmware);
+        assert_eq!(
+            InternalFirmware::EmuFlight0_4,
+            InternalFirmware::from(firmware)
+        );
+        assert_eq!(
+            Some(InternalFirmware::Betaflight4_0),
+            InternalFirmware::EmuFlight0_4.betaflight_base()
+        );
+
+        let firmware = Firmware::parse("EmuFlight 0.4.1 (7bac6ddd0) HELIOSPRING").unwrap();
+        assert_eq!(Firmware::EmuFlight(FirmwareVersion::new(0, 4, 1)), firmware);
+
+        let err = Firmware::parse("EmuFlight 0.3.5 (ed3c5bc3f) HELIOSPRING").unwrap_err();
+        assert!(matches!(
+            err,
+            ParseError::UnsupportedFirmwareVersion(Firmware::EmuFlight(_))
+        ));
+    }
+
+    #[test]
+    fn rotorflight() {
+        use crate::frame::main::{MainUnit, MainValue};
+        use crate::frame::{Frame as _, FrameDef as _};
+        use crate::units::prelude::hertz;
+        use crate::writer::{write_log, Encoding, FieldSpec, HeaderSpec, MainFieldSpec, Predictor};
+        use crate::ParserEvent;
+
+        let mut spec = HeaderSpec::new("Rotorflight 4.3.0 (b6b8ba2b4) STM32F7X2");
+        spec.main
+            .extend(["headspeed", "govTarget"].map(|name| MainFieldSpec {
+                name,
+                signed: false,
+                predictor_intra: Predictor::Zero,
+                predictor_inter: Predictor::Previous,
+                encoding_intra: Encoding::Variable,
+                encoding_inter: Encoding::VariableSigned,
+            }));
+        spec.slow.push(FieldSpec {
+            name: "flightModeFlags",
+            signed: false,
+            predictor: Predictor::Zero,
+            encoding: Encoding::Variable,
+        });
+        spec.other.push(("motor_pwm_protocol", "0"));
+
+        let log = write_log(&spec, |writer| {
+            writer.write_intra(0, &[0, 1800, 1860]).unwrap();
+        });
+        let headers = Headers::parse(&log).unwrap();
+
+        assert_eq!(
+            Firmware::Rotorflight(FirmwareVersion::new(4, 3, 0)),
+            headers.firmware()
+        );
+        for i in [1, 2] {
+            assert_eq!(
+                Some(MainUnit::Frequency),
+                headers.main_frame_def().get(i).map(|def| def.unit)
+            );
+        }
+        // Only versions of the same firmware can be compared
+        assert!(InternalFirmware::Rotorflight4_3
+            .partial_cmp(&InternalFirmware::Betaflight4_3)
+            .is_none());
+        assert_eq!(
+            Some(InternalFirmware::Betaflight4_3),
+            InternalFirmware::Rotorflight4_3.betaflight_base()
+        );
+        assert_eq!(None, InternalFirmware::Inav8.betaflight_base());
+
+        let mut parser = headers.data_parser();
+        let Some(ParserEvent::Main(frame)) = parser.next() else {
+            panic!("expected a main frame");
+        };
+        let Some(MainValue::Frequency(headspeed)) = frame.get(1) else {
+            panic!("expected a frequency");
+        };
+        assert!((30. - headspeed.get::<hertz>()).abs() < 1e-9);
+        let Some(MainValue::Frequency(target)) = frame.get(2) else {
+            panic!("expected a frequency");
+        };
+        assert!((31. - target.get::<hertz>()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn debug_fields() {
+        use crate::frame::main::{MainUnit, MainValue};
+        use crate::frame::{Frame as _, FrameDef as _};
+        use crate::units::prelude::{microsecond, percent};
+        use crate::writer::{write_log, Encoding, FieldSpec, HeaderSpec, MainFieldSpec, Predictor};
+        use crate::ParserEvent;
+
+        let mut spec = HeaderSpec::new("Betaflight 4.4.3 (738127e7e) STM32F7X2");
+        spec.main.extend(
+            ["debug[0]", "debug[1]", "debug[2]"].map(|name| MainFieldSpec {
+                name,
+                signed: true,
+                predictor_intra: Predictor::Zero,
+                predictor_inter: Predictor::Previous,
+                encoding_intra: Encoding::VariableSigned,
+                encoding_inter: Encoding::VariableSigned,
+            }),
+        );
+        spec.slow.push(FieldSpec {
+            name: "flightModeFlags",
+            signed: false,
+            predictor: Predictor::Zero,
+            encoding: Encoding::Variable,
+        });
+        spec.other
+            .extend([("debug_mode", "1"), ("motor_pwm_protocol", "0")]);
+
+        let log = write_log(&spec, |writer| {
+            writer
+                .write_intra(0, &[0, 125, 42, (-7_i32).cast_unsigned()])
+                .unwrap();
+        });
+        let headers = Headers::parse(&log).unwrap();
+        assert_eq!(DebugMode::Cycletime, headers.debug_mode());
+
+        let def = headers.main_frame_def();
+        let cycle_time = def.get(1).unwrap();
+        assert_eq!(Some("cycle time"), cycle_time.description);
+        assert_eq!(MainUnit::Duration, cycle_time.unit);
+        assert_eq!(MainUnit::Ratio, def.get(2).unwrap().unit);
+        // Fields without a known meaning are left as integers
+        let unknown = def.get(3).unwrap();
+        assert_eq!(None, unknown.description);
+        assert_eq!(MainUnit::Unitless, unknown.unit);
+
+        let mut parser = headers.data_parser();
+        let Some(ParserEvent::Main(frame)) = parser.next() else {
+            panic!("expected a main frame");
+        };
+
+        let Some(MainValue::Duration(cycle_time)) = frame.get(1) else {
+            panic!("expected a duration");
+        };
+        assert!((125. - cycle_time.get::<microsecond>()).abs() < 1e-9);
+        let Some(MainValue::Ratio(load)) = frame.get(2) else {
+            panic!("expected a ratio");
+        };
+        assert!((42. - load.get::<percent>()).abs() < 1e-9);
+        assert_eq!(Some(MainValue::Signed(-7)), frame.get(3));
+    }
+
+    #[test]
+    fn frame_intervals_power_of_two() {
+        // Betaflight: `I interval:256`, `P interval:16`
+        let intervals = FrameIntervals::new(256, 1, 16);
+
+        let logged = (0..64)
+            .filter(|&i| intervals.is_logged(i))
+            .collect::<Vec<_>>();
+        assert_eq!([0, 16, 32, 48], *logged);
+
+        assert_eq!(15, intervals.skipped_after(0));
+        assert_eq!(0, intervals.skipped_after(15));
+        assert_eq!(15, intervals.skipped_after(240));
+    }
+
+    #[test]
+    fn frame_intervals_fraction() {
+        // INAV: `I interval:32`, `P interval:2/3`
+        let intervals = FrameIntervals::new(32, 2, 3);
+
+        let logged = (0..10)
+            .filter(|&i| intervals.is_logged(i))
+            .collect::<Vec<_>>();
+        assert_eq!([0, 2, 3, 5, 6, 8, 9], *logged);
+
+        assert_eq!(1, intervals.skipped_after(0));
+        assert_eq!(0, intervals.skipped_after(2));
+
+        // Intraframes are always logged
+        assert_eq!(0, intervals.skipped_after(31));
+    }
+
+    #[test]
+    fn frame_intervals_unlimited() {
+        let intervals = FrameIntervals::default();
+        assert!((0..100).all(|i| intervals.is_logged(i)));
+        assert_eq!(0, intervals.skipped_after(u32::MAX));
+    }
+
+    #[test]
+    fn iter_raw() {
+        let log = include_bytes!("../tests/logs/error-recovery.bbl");
+        let headers = Headers::parse(log).unwrap();
+
+        let raw = headers.iter_raw().collect::<Vec<_>>();
+        assert_eq!(
+            Some(&(
+                "Product",
+                "Blackbox flight data recorder by Nicholas Sherlock"
+            )),
+            raw.first()
+        );
+        assert_eq!(Some(&("Data version", "2")), raw.get(1));
+        assert!(raw.contains(&("Firmware revision", headers.firmware_revision())));
+
+        let lines = log[..headers.data_offset()].iter().filter(|&&b| b == b'\n');
+        assert_eq!(lines.count(), raw.len());
+    }
 }
@@ -0,0 +1,269 @@
+//! An index of the intraframes in a log, for seeking without decoding
+//! everything before the target.
+//!
+//! Since every intraframe resets the prediction history, decoding can start at
+//! any of them. Building a [`FrameIndex`] still requires decoding the whole log
+//! once, but it can then be [saved](FrameIndex::to_bytes) alongside the log and
+//! [reloaded](FrameIndex::from_bytes) later.
+//!
+//! ```
+//! use blackbox_log::index::{FrameIndex, SeekTarget};
+//! use blackbox_log::{DataParser, FilterSet};
+//!
+//! # let file = b"...";
+//! # for headers in blackbox_log::File::new(file).iter() {
+//! #     let headers = headers.unwrap();
+//! let index = FrameIndex::new(&headers);
+//!
+//! // Start decoding 5 minutes after power on
+//! let target = SeekTarget::Time(5 * 60 * 1_000_000);
+//! let mut parser = DataParser::starting_at(&headers, &FilterSet::default(), &index, target);
+//! while let Some(event) = parser.next() {
+//!     // ...
+//! }
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::data::{InternalFrame, ParserState, Step};
+use crate::frame::GpsPosition;
+use crate::parser::{decode, encode};
+use crate::{FilterSet, Headers, Reader};
+
+/// Incremented whenever the format written by [`FrameIndex::to_bytes`]
+/// changes.
+const FORMAT_VERSION: u8 = 1;
+
+/// The location of every valid intraframe in a log.
+///
+/// This is only valid for the log it was built from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FrameIndex {
+    entries: Vec<IndexEntry>,
+    /// Offset and position of each GPS home frame, which is needed to decode
+    /// any GPS frames following an intraframe
+    gps_homes: Vec<(usize, GpsPosition)>,
+}
+
+/// An intraframe found while building a [`FrameIndex`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct IndexEntry {
+    /// The offset of the start of the frame from the start of the log.
+    pub offset: usize,
+    /// The value of `loopIteration`.
+    pub iteration: u32,
+    /// The raw microsecond counter since power on. See
+    /// [`MainFrame::time_raw`][crate::frame::MainFrame::time_raw].
+    pub time: u64,
+}
+
+/// Where to start decoding a log using [`FrameIndex::find`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeekTarget {
+    /// A raw microsecond counter since power on, as returned by
+    /// [`MainFrame::time_raw`][crate::frame::MainFrame::time_raw].
+    Time(u64),
+    /// A value of `loopIteration`.
+    Iteration(u32),
+}
+
+impl FrameIndex {
+    /// Decodes the entire data section of a log to find all its intraframes.
+    pub fn new(headers: &Headers) -> Self {
+        let mut data = headers.data();
+        let mut state = ParserState::new(headers, &FilterSet::default());
+
+        let mut entries = Vec::new();
+        let mut gps_homes = Vec::new();
+        loop {
+            match state.parse_next(&mut data, headers, true) {
+                Step::Frame(InternalFrame::GpsHome(gps_home), span) => {
+                    gps_homes.push((span.offset, gps_home.0));
+                    state.set_gps_home(gps_home);
+                }
+                Step::Frame(frame, span) => {
+                    if let InternalFrame::Main(main) = &frame {
+                        if main.intra {
+                            entries.push(IndexEntry {
+                                offset: span.offset,
+                                iteration: main.iteration,
+                                time: main.time,
+                            });
+                        }
+                    }
+
+                    state.emit(headers, frame, span);
+                }
+                Step::Incomplete | Step::Eof => break,
+            }
+        }
+
+        Self { entries, gps_homes }
+    }
+
+    /// Returns all intraframes in the order they appear in the log.
+    #[inline]
+    pub fn entries(&self) -> &[IndexEntry] {
+        &self.entries
+    }
+
+    /// Returns the last intraframe at or before `target`, or `None` if
+    /// `target` is before the first intraframe.
+    pub fn find(&self, target: SeekTarget) -> Option<&IndexEntry> {
+        let after = match target {
+            SeekTarget::Time(time) => self.entries.partition_point(|entry| entry.time <= time),
+            SeekTarget::Iteration(iteration) => self
+                .entries
+                .partition_point(|entry| entry.iteration <= iteration),
+        };
+
+        self.entries.get(after.checked_sub(1)?)
+    }
+
+    /// Returns the last GPS home position found before `offset`.
+    pub(crate) fn gps_home_before(&self, offset: usize) -> Option<GpsPosition> {
+        let after = self.gps_homes.partition_point(|&(home, _)| home < offset);
+        let (_, position) = self.gps_homes.get(after.checked_sub(1)?)?;
+        Some(*position)
+    }
+
+    /// Serializes the index into a compact binary format that can be read
+    /// using [`FrameIndex::from_bytes`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = alloc::vec![FORMAT_VERSION];
+
+        write_usize(&mut out, self.entries.len());
+        let mut last_offset = 0;
+        for entry in &self.entries {
+            write_usize(&mut out, entry.offset - last_offset);
+            encode::variable(&mut out, entry.iteration);
+            write_u64(&mut out, entry.time);
+            last_offset = entry.offset;
+        }
+
+        write_usize(&mut out, self.gps_homes.len());
+        for (offset, position) in &self.gps_homes {
+            write_usize(&mut out, *offset);
+            encode::variable_signed(&mut out, position.latitude);
+            encode::variable_signed(&mut out, position.longitude);
+        }
+
+        out
+    }
+
+    /// Reads an index written by [`FrameIndex::to_bytes`]. Returns `None` if
+    /// `bytes` is not a valid index.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut data = Reader::new(bytes);
+        if data.read_u8()? != FORMAT_VERSION {
+            return None;
+        }
+
+        let len = read_usize(&mut data)?;
+        let mut entries = Vec::with_capacity(len.min(data.remaining()));
+        let mut offset = 0_usize;
+        for _ in 0..len {
+            offset = offset.checked_add(read_usize(&mut data)?)?;
+            entries.push(IndexEntry {
+                offset,
+                iteration: decode::variable(&mut data).ok()?,
+                time: read_u64(&mut data)?,
+            });
+        }
+
+        let len = read_usize(&mut data)?;
+        let mut gps_homes = Vec::with_capacity(len.min(data.remaining()));
+        for _ in 0..len {
+            let offset = read_usize(&mut data)?;
+            let position = GpsPosition {
+                latitude: decode::variable_signed(&mut data).ok()?,
+                longitude: decode::variable_signed(&mut data).ok()?,
+            };
+            gps_homes.push((offset, position));
+        }
+
+        data.peek().is_none().then_some(Self { entries, gps_homes })
+    }
+}
+
+fn write_u64(out: &mut Vec<u8>, x: u64) {
+    #[expect(clippy::cast_possible_truncation)]
+    let (low, high) = (x as u32, (x >> 32) as u32);
+    encode::variable(out, low);
+    encode::variable(out, high);
+}
+
+fn read_u64(data: &mut Reader) -> Option<u64> {
+    let low = decode::variable(data).ok()?;
+    let high = decode::variable(data).ok()?;
+    Some((u64::from(high) << 32) | u64::from(low))
+}
+
+fn write_usize(out: &mut Vec<u8>, x: usize) {
+    write_u64(out, x as u64);
+}
+
+fn read_usize(data: &mut Reader) -> Option<usize> {
+    read_u64(data)?.try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static LOG: &[u8] = include_bytes!("../tests/logs/error-recovery.bbl");
+
+    #[test]
+    fn bytes_round_trip() {
+        let headers = Headers::parse(LOG).unwrap();
+        let mut index = FrameIndex::new(&headers);
+        assert!(!index.entries().is_empty());
+
+        index.gps_homes.push((
+            100,
+            GpsPosition {
+                latitude: -1,
+                longitude: i32::MAX,
+            },
+        ));
+
+        let bytes = index.to_bytes();
+        assert_eq!(Some(&index), FrameIndex::from_bytes(&bytes).as_ref());
+
+        assert_eq!(None, FrameIndex::from_bytes(&bytes[..bytes.len() - 1]));
+        assert_eq!(None, FrameIndex::from_bytes(&[]));
+    }
+
+    #[test]
+    fn find() {
+        let entry = |offset, iteration, time| IndexEntry {
+            offset,
+            iteration,
+            time,
+        };
+
+        let index = FrameIndex {
+            entries: alloc::vec![entry(10, 0, 1000), entry(50, 32, 2000), entry(90, 64, 3000)],
+            gps_homes: Vec::new(),
+        };
+
+        assert_eq!(None, index.find(SeekTarget::Time(999)));
+        assert_eq!(Some(&index.entries[0]), index.find(SeekTarget::Time(1000)));
+        assert_eq!(Some(&index.entries[1]), index.find(SeekTarget::Time(2999)));
+        assert_eq!(
+            Some(&index.entries[2]),
+            index.find(SeekTarget::Time(u64::MAX))
+        );
+
+        assert_eq!(
+            Some(&index.entries[0]),
+            index.find(SeekTarget::Iteration(31))
+        );
+        assert_eq!(
+            Some(&index.entries[1]),
+            index.find(SeekTarget::Iteration(32))
+        );
+    }
+}
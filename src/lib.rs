@@ -2,7 +2,8 @@
 #![warn(clippy::std_instead_of_alloc, clippy::std_instead_of_core)]
 #![warn(unreachable_pub, clippy::missing_panics_doc)]
 
-//! Ergonomic parser for Betaflight blackbox logs.
+//! Ergonomic parser for Betaflight, INAV, EmuFlight and Rotorflight blackbox
+//! logs.
 //!
 //! For details about the format of blackbox logs, see the *Blackbox Internals*
 //! development documentation from [INAV][inav-doc], [Betaflight][bf-doc]
@@ -78,7 +79,15 @@
 //!
 //! # Features
 //!
-//! - `std`: **Enabled** by default
+//! - `std`: **Enabled** by default. Required for [`stream`] and [`csv`]
+//! - `rayon`: Decode a single log using multiple threads with
+//!   [`parallel::ParallelParser`]
+//! - `arrow`: Convert logs into Apache Arrow record batches using the [`arrow`]
+//!   module
+//! - `parquet`: Write those record batches as Parquet files. Implies `arrow`
+//! - `serde`: Implement `Serialize` and `Deserialize` for events, stats,
+//!   values, units and frame definitions. Flag sets are (de)serialized as a
+//!   list of their flags
 //!
 //! [bf-doc]: https://betaflight.com/docs/development/Blackbox-Internals
 //! [inav-doc]: https://github.com/iNavFlight/inav/blob/master/docs/development/Blackbox%20Internals.md
@@ -90,21 +99,34 @@
 #[macro_use]
 mod utils;
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod columns;
+pub mod config;
+#[cfg(feature = "std")]
+pub mod csv;
 pub mod data;
 pub mod event;
 mod file;
 mod filter;
 pub mod frame;
 pub mod headers;
+pub mod index;
+#[cfg(feature = "rayon")]
+pub mod parallel;
 mod parser;
 mod predictor;
 pub mod prelude;
 mod reader;
+#[cfg(feature = "std")]
+pub mod stream;
+pub mod track;
 pub mod units;
+pub mod writer;
 
 use core::ops::Range;
 
-pub use self::data::{DataParser, ParserEvent};
+pub use self::data::{DataParser, OwnedParserEvent, ParserEvent, PushParser};
 pub use self::event::Event;
 pub use self::file::File;
 pub use self::filter::{FieldFilter, Filter, FilterSet};
@@ -117,6 +139,12 @@
 const MARKER: &[u8] = b"H Product:Blackbox flight data recorder by Nicholas Sherlock\n";
 
 const BETAFLIGHT_SUPPORT: Range<FirmwareVersion> =
-    FirmwareVersion::new(4, 2, 0)..FirmwareVersion::new(4, 6, 0);
+    FirmwareVersion::new(3, 5, 0)..FirmwareVersion::new(4, 7, 0);
 const INAV_SUPPORT: Range<FirmwareVersion> =
-    FirmwareVersion::new(5, 0, 0)..FirmwareVersion::new(9, 0, 0);
+    FirmwareVersion::new(5, 0, 0)..FirmwareVersion::new(10, 0, 0);
+const EMUFLIGHT_SUPPORT: Range<FirmwareVersion> =
+    FirmwareVersion::new(0, 4, 0)..FirmwareVersion::new(0, 5, 0);
+const ROTORFLIGHT_SUPPORT: Range<FirmwareVersion> =
+    FirmwareVersion::new(4, 3, 0)..FirmwareVersion::new(4, 6, 0);
+const CLEANFLIGHT_SUPPORT: Range<FirmwareVersion> =
+    FirmwareVersion::new(0, 0, 0)..FirmwareVersion::new(2, 0, 0);
@@ -0,0 +1,513 @@
+//! Decoding of a single log using multiple threads.
+//!
+//! Since every intraframe resets the prediction history, the data section can
+//! be split up at intraframes and each chunk decoded independently. The chunks
+//! are then checked against each other, so the result is exactly the same as
+//! from a [`DataParser`][crate::DataParser]. If a split turns out to be wrong,
+//! eg because of corrupted data, that part of the log is decoded sequentially
+//! instead.
+
+use alloc::vec::{self, Vec};
+use core::ops::Range;
+
+use rayon::prelude::*;
+
+use crate::data::{InternalFrame, MainFrameHistory, ParserState, Span, Stats, Step};
+use crate::event::Event;
+use crate::frame::main::RawMainFrame;
+use crate::frame::{self, DataFrameKind, FilteredFrameDef, FrameKind, GpsHomeFrame, GpsPosition};
+use crate::{FilterSet, Headers, OwnedParserEvent, ParserEvent, Reader};
+
+/// Chunks are never split smaller than this many bytes.
+const MIN_CHUNK_SIZE: usize = 64 * 1024;
+
+/// A [`DataParser`][crate::DataParser] that decodes the whole log up front
+/// using the [`rayon`] thread pool.
+///
+/// All frames are held in memory until they are returned by
+/// [`next`][Self::next].
+///
+/// ```
+/// use blackbox_log::parallel::ParallelParser;
+///
+/// # let file = b"...";
+/// # for headers in blackbox_log::File::new(file).iter() {
+/// #     let headers = headers.unwrap();
+/// let mut parser = ParallelParser::new(&headers, &Default::default());
+/// while let Some(event) = parser.next() {
+///     // ...
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ParallelParser<'data, 'headers> {
+    headers: &'headers Headers<'data>,
+    state: ParserState,
+    frames: vec::IntoIter<(InternalFrame, Span)>,
+    frame_count: usize,
+}
+
+impl<'data, 'headers> ParallelParser<'data, 'headers> {
+    /// Decodes the data section of a log.
+    pub fn new(headers: &'headers Headers<'data>, filters: &FilterSet) -> Self {
+        let len = headers.data().remaining();
+        let chunk_count = rayon::current_num_threads() * 4;
+        Self::with_chunk_size(headers, filters, (len / chunk_count).max(MIN_CHUNK_SIZE))
+    }
+
+    fn with_chunk_size(
+        headers: &'headers Headers<'data>,
+        filters: &FilterSet,
+        chunk_size: usize,
+    ) -> Self {
+        let start = headers.data_offset();
+        let end = start + headers.data().remaining();
+
+        let mut starts = (start.saturating_add(chunk_size)..end)
+            .step_by(chunk_size)
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .filter_map(|offset| find_intra_frame(headers, offset))
+            .collect::<Vec<_>>();
+        starts.insert(0, start);
+        starts.dedup();
+
+        let chunks = starts
+            .par_iter()
+            .enumerate()
+            .map(|(i, &start)| {
+                let end = starts.get(i + 1).copied().unwrap_or(end);
+                Chunk::decode(headers, start, end)
+            })
+            .collect::<Vec<_>>();
+
+        let mut state = ParserState::new(headers, filters);
+        let frames = merge(headers, chunks, &mut state.stats);
+
+        Self {
+            headers,
+            state,
+            frame_count: frames.len(),
+            frames: frames.into_iter(),
+        }
+    }
+
+    pub fn main_frame_def<'a>(&'a self) -> FilteredFrameDef<'a, frame::MainFrameDef<'data>> {
+        self.state.main_frame_def(self.headers)
+    }
+
+    pub fn slow_frame_def<'a>(&'a self) -> FilteredFrameDef<'a, frame::SlowFrameDef<'data>> {
+        self.state.slow_frame_def(self.headers)
+    }
+
+    pub fn gps_frame_def<'a>(&'a self) -> Option<FilteredFrameDef<'a, frame::GpsFrameDef<'data>>> {
+        self.state.gps_frame_def(self.headers)
+    }
+
+    /// Returns the current stats.
+    ///
+    /// **Note:** Since the whole log has already been decoded,
+    /// the corrupted regions in [`Stats`] are complete from the start.
+    #[inline]
+    pub fn stats(&self) -> &Stats {
+        &self.state.stats
+    }
+
+    /// Returns `true` if the parser has reached the end of the log.
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.state.done
+    }
+
+    /// Returns where the last event or frame returned by [`next`][Self::next]
+    /// was found, as a range of offsets from the start of the log.
+    ///
+    /// Use this to locate an [`Event`]. Frames and [`OwnedParserEvent`]s also
+    /// include their own location.
+    #[inline]
+    pub fn last_span(&self) -> Option<Range<usize>> {
+        self.state.last_span()
+    }
+
+    /// Returns the next [`ParserEvent`] in the same order as
+    /// [`DataParser::next`][crate::DataParser::next]. Returns `None` once the
+    /// end of the log is reached.
+    pub fn next<'parser>(&'parser mut self) -> Option<ParserEvent<'data, 'headers, 'parser>> {
+        loop {
+            let Some((frame, span)) = self.frames.next() else {
+                self.state.done = true;
+                self.state.stats.progress = 1.;
+                return None;
+            };
+
+            self.state.stats.progress =
+                1. - ((self.frames.len() as f32) / (self.frame_count as f32));
+
+            if let InternalFrame::GpsHome(gps_home) = frame {
+                self.state.set_gps_home(gps_home);
+            } else {
+                return Some(self.state.emit(self.headers, frame, span));
+            }
+        }
+    }
+
+    /// Like [`next`][Self::next], but returns an [`OwnedParserEvent`] that
+    /// does not borrow the parser.
+    pub fn next_owned(&mut self) -> Option<OwnedParserEvent> {
+        match self.next()?.into_owned_frame() {
+            Ok(frame) => Some(frame),
+            Err(event) => Some(OwnedParserEvent::Event {
+                event,
+                span: self.last_span()?,
+            }),
+        }
+    }
+}
+
+/// Part of the data section decoded starting from a possible intraframe.
+struct Chunk {
+    start: usize,
+    end: usize,
+    state: ParserState,
+    frames: Vec<(InternalFrame, Span)>,
+}
+
+impl Chunk {
+    fn decode(headers: &Headers, start: usize, end: usize) -> Self {
+        let mut chunk = Self {
+            start,
+            end,
+            state: ParserState::new(headers, &FilterSet::default()),
+            frames: Vec::new(),
+        };
+
+        chunk.state.seek(start, MainFrameHistory::default(), None);
+        chunk.decode_until(headers, end);
+        chunk
+    }
+
+    /// Continues decoding until the next frame would start at or after `end`.
+    fn decode_until(&mut self, headers: &Headers, end: usize) {
+        let mut data = reader_at(headers, self.state.offset());
+
+        while !self.state.done && self.state.offset() < end {
+            let Step::Frame(frame, span) = self.state.parse_next(&mut data, headers, true) else {
+                break;
+            };
+
+            match &frame {
+                InternalFrame::Main(main) => self.state.push_main(main.clone()),
+                InternalFrame::GpsHome(gps_home) => self.state.set_gps_home(gps_home.clone()),
+                InternalFrame::Event(Event::End { .. }) => self.state.done = true,
+                InternalFrame::Event(_) | InternalFrame::Slow(_) | InternalFrame::Gps(_) => {}
+            }
+
+            self.frames.push((frame, span));
+        }
+
+        self.end = end;
+    }
+
+    /// Checks that `self` continues on from `previous` exactly like a
+    /// sequential parser would, returning the offset to add to every time in
+    /// `self`.
+    fn verify(&self, headers: &Headers, previous: &Self, time_offset: u64) -> Option<u64> {
+        if previous.state.done || previous.state.offset() != self.start {
+            return None;
+        }
+
+        let (InternalFrame::Main(first), span) = self.frames.first()? else {
+            return None;
+        };
+        if span.offset != self.start {
+            return None;
+        }
+
+        let mut history = previous.state.main_frames().clone();
+        history.shift_time(time_offset);
+
+        let mut data = reader_at(headers, self.start + 1);
+        let kind = FrameKind::Data(DataFrameKind::Intra);
+        let expected = RawMainFrame::parse(&mut data, headers, kind, &history).ok()?;
+
+        (expected.iteration == first.iteration && expected.values == first.values)
+            .then(|| expected.time.checked_sub(first.time))
+            .flatten()
+    }
+
+    /// Applies everything that could not be known while decoding `self` in
+    /// isolation.
+    fn fix_up(
+        &mut self,
+        headers: &Headers,
+        time_offset: u64,
+        mut gps_home: Option<GpsPosition>,
+    ) -> Option<GpsPosition> {
+        let mut last_main_time = None;
+        let mut seen_home = false;
+
+        for (frame, span) in &mut self.frames {
+            match frame {
+                InternalFrame::Main(main) => {
+                    main.time += time_offset;
+                    last_main_time = Some(main.time);
+                }
+                InternalFrame::Event(Event::SyncBeep(time)) => *time += time_offset,
+                InternalFrame::Gps(gps) => {
+                    // GPS frames before the first GPS home frame in this chunk
+                    // were decoded without a home position
+                    let reparsed = gps_home.filter(|_| !seen_home).and_then(|home| {
+                        let mut data = reader_at(headers, span.offset + 1);
+                        let home = GpsHomeFrame(home);
+                        headers
+                            .gps_frame_def()?
+                            .parse(&mut data, headers, last_main_time, Some(&home))
+                            .ok()
+                    });
+
+                    if let Some(reparsed) = reparsed {
+                        *gps = reparsed;
+                    } else {
+                        gps.time += time_offset;
+                    }
+                }
+                InternalFrame::GpsHome(home) => {
+                    seen_home = true;
+                    gps_home = Some(home.0);
+                }
+                InternalFrame::Event(_) | InternalFrame::Slow(_) => {}
+            }
+        }
+
+        gps_home
+    }
+}
+
+/// Joins decoded chunks back together, re-decoding any that could not be
+/// verified. Any corrupted regions are added to `stats`.
+fn merge(headers: &Headers, chunks: Vec<Chunk>, stats: &mut Stats) -> Vec<(InternalFrame, Span)> {
+    let mut chunks = chunks.into_iter();
+    let Some(mut current) = chunks.next() else {
+        return Vec::new();
+    };
+
+    let mut frames = Vec::new();
+    let mut time_offset = 0;
+    let mut gps_home = None;
+
+    for next in chunks {
+        if let Some(next_offset) = next.verify(headers, &current, time_offset) {
+            gps_home = current.fix_up(headers, time_offset, gps_home);
+            frames.extend(current.frames);
+            stats.append_corruptions(current.state.stats);
+
+            current = next;
+            time_offset = next_offset;
+        } else if !current.state.done {
+            current.decode_until(headers, next.end);
+        }
+    }
+
+    current.fix_up(headers, time_offset, gps_home);
+    frames.extend(current.frames);
+    stats.append_corruptions(current.state.stats);
+
+    frames
+}
+
+/// Finds the first intraframe starting at or after `offset` that can be
+/// decoded, without any prior frames.
+fn find_intra_frame(headers: &Headers, offset: usize) -> Option<usize> {
+    let intra = u8::from(FrameKind::Data(DataFrameKind::Intra));
+    let mut data = reader_at(headers, offset);
+    let mut offset = offset;
+
+    loop {
+        let remaining = data.remaining();
+        if !data.skip_until_any(&[intra]) {
+            return None;
+        }
+        offset += remaining - data.remaining();
+
+        let mut state = ParserState::new(headers, &FilterSet::default());
+        state.seek(offset, MainFrameHistory::default(), None);
+        let mut trial = data.clone();
+        if let Step::Frame(InternalFrame::Main(_), span) =
+            state.parse_next(&mut trial, headers, true)
+        {
+            if span.offset == offset {
+                return Some(offset);
+            }
+        }
+
+        data.read_u8();
+        offset += 1;
+    }
+}
+
+/// Returns a [`Reader`] starting `offset` bytes from the start of the log.
+fn reader_at<'data>(headers: &Headers<'data>, offset: usize) -> Reader<'data> {
+    let mut data = headers.data();
+    data.read_n_bytes(offset - headers.data_offset());
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::frame::Frame as _;
+    use crate::writer::{write_log, Encoding, FieldSpec, HeaderSpec, MainFieldSpec, Predictor};
+    use crate::DataParser;
+
+    static LOG: &[u8] = include_bytes!("../tests/logs/error-recovery.bbl");
+
+    type Summary = (char, Range<usize>, Option<u64>, Vec<u32>);
+
+    fn summarize(event: &ParserEvent) -> (char, Option<u64>, Vec<u32>) {
+        match event {
+            ParserEvent::Event(_) => ('E', None, vec![]),
+            ParserEvent::Main(main) => ('M', Some(main.time_raw()), main.iter_raw().collect()),
+            ParserEvent::Slow(slow) => ('S', None, slow.iter_raw().collect()),
+            ParserEvent::Gps(gps) => ('G', Some(gps.time_raw()), gps.iter_raw().collect()),
+        }
+    }
+
+    fn sequential(headers: &Headers) -> (Vec<Summary>, Stats) {
+        let mut parser = DataParser::new(headers.data(), headers, &FilterSet::default());
+        let mut events = Vec::new();
+        while let Some(event) = parser.next() {
+            let (kind, time, values) = summarize(&event);
+            events.push((kind, parser.last_span().unwrap(), time, values));
+        }
+        (events, parser.stats().clone())
+    }
+
+    fn parallel(headers: &Headers, chunk_size: usize) -> (Vec<Summary>, Stats) {
+        let mut parser =
+            ParallelParser::with_chunk_size(headers, &FilterSet::default(), chunk_size);
+        let mut events = Vec::new();
+        while let Some(event) = parser.next() {
+            let (kind, time, values) = summarize(&event);
+            events.push((kind, parser.last_span().unwrap(), time, values));
+        }
+        assert!(parser.is_done());
+        (events, parser.stats().clone())
+    }
+
+    fn assert_matches(log: &[u8]) {
+        let headers = Headers::parse(log).unwrap();
+        let (expected, stats) = sequential(&headers);
+        assert!(!expected.is_empty());
+
+        for chunk_size in (1..64).chain([100, 250, 1000, usize::MAX]) {
+            let (events, parallel_stats) = parallel(&headers, chunk_size);
+            assert_eq!(expected, events, "chunk size: {chunk_size}");
+            assert_eq!(stats.counts.main, parallel_stats.counts.main);
+            assert_eq!(stats.counts.gps_home, parallel_stats.counts.gps_home);
+            assert_eq!(stats.time_rollovers, parallel_stats.time_rollovers);
+            assert_eq!(stats.corrupted_regions, parallel_stats.corrupted_regions);
+            assert_eq!(stats.corrupted_bytes, parallel_stats.corrupted_bytes);
+            assert_eq!(stats.corruptions, parallel_stats.corruptions);
+        }
+    }
+
+    #[test]
+    fn corrupted_log() {
+        assert_matches(LOG);
+    }
+
+    /// Writes a log with GPS frames after a change of the home position, which
+    /// crosses a rollover of the 32 bit time counter
+    fn gps_log() -> Vec<u8> {
+        let field = |name, predictor| FieldSpec {
+            name,
+            signed: true,
+            predictor,
+            encoding: Encoding::VariableSigned,
+        };
+
+        let mut spec = HeaderSpec::new("Betaflight 4.2.0 (8f2d21460) STM32F7X2");
+        spec.main = vec![MainFieldSpec {
+            name: "axisP[0]",
+            signed: true,
+            predictor_intra: Predictor::Zero,
+            predictor_inter: Predictor::Previous,
+            encoding_intra: Encoding::VariableSigned,
+            encoding_inter: Encoding::VariableSigned,
+        }];
+        spec.slow = vec![field("flightModeFlags", Predictor::Zero)];
+        spec.gps = Some(vec![
+            field("GPS_coord[0]", Predictor::HomeLat),
+            field("GPS_coord[1]", Predictor::HomeLon),
+        ]);
+        spec.other = vec![("I interval", "4"), ("motor_pwm_protocol", "0")];
+
+        write_log(&spec, |writer| {
+            writer.write_gps_home(100, -100).unwrap();
+
+            let mut time = u64::from(u32::MAX) - 5_000;
+            for i in 0..100 {
+                time += 125;
+                if i % 4 == 0 {
+                    writer.write_intra(time, &[i, i]).unwrap();
+                } else {
+                    writer.write_inter(time, &[i, i]).unwrap();
+                }
+
+                if i == 50 {
+                    writer.write_gps_home(200, -200).unwrap();
+                    writer.write_event(&Event::SyncBeep(time + 10)).unwrap();
+                }
+
+                if i % 3 == 0 {
+                    let coord = i.cast_signed();
+                    writer
+                        .write_gps(time + 5, &[(coord + 200).cast_unsigned(), 0])
+                        .unwrap();
+                }
+            }
+            writer
+                .write_event(&Event::End {
+                    disarm_reason: None,
+                })
+                .unwrap();
+        })
+    }
+
+    #[test]
+    fn gps_home_and_rollover() {
+        let log = gps_log();
+
+        let headers = Headers::parse(&log).unwrap();
+        let (_, stats) = sequential(&headers);
+        assert_eq!(1, stats.time_rollovers);
+        assert_eq!(2, stats.counts.gps_home);
+
+        assert_matches(&log);
+    }
+
+    #[test]
+    fn owned_event_spans() {
+        let log = gps_log();
+        let headers = Headers::parse(&log).unwrap();
+
+        let (events, _) = sequential(&headers);
+        let expected = events
+            .into_iter()
+            .filter(|(kind, ..)| *kind == 'E')
+            .map(|(_, span, ..)| span)
+            .collect::<Vec<_>>();
+        assert_eq!(2, expected.len());
+
+        let mut parser = ParallelParser::with_chunk_size(&headers, &FilterSet::default(), 16);
+        let mut spans = Vec::new();
+        while let Some(event) = parser.next_owned() {
+            if let OwnedParserEvent::Event { span, .. } = event {
+                spans.push(span);
+            }
+        }
+        assert_eq!(expected, spans);
+    }
+}
@@ -9,17 +9,19 @@
 use alloc::vec::Vec;
 
 pub(crate) use self::negative_14_bit::negative_14_bit;
-pub(crate) use self::tagged_16::tagged_16;
+pub(crate) use self::tagged_16::{tagged_16, tagged_16_v1};
 pub(crate) use self::tagged_32::tagged_32;
 pub(crate) use self::tagged_variable::tagged_variable;
 pub(crate) use self::variable::{variable, variable_signed};
-use super::InternalResult;
+use super::{DataVersion, InternalResult};
 use crate::Reader;
 
 byte_enum! {
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    /// How a field's value is stored in a frame.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
     #[repr(u8)]
-    pub(crate) enum Encoding {
+    pub enum Encoding {
         /// Signed variable byte
         VariableSigned = 0,
         /// Unsigned variable byte
@@ -37,6 +39,9 @@ pub(crate) enum Encoding {
         /// | 1   | 4                   |
         /// | 2   | 8                   |
         /// | 3   | 16                  |
+        ///
+        /// In logs with `Data version:1`, 4 bit fields are always written in
+        /// pairs sharing one byte, and 16 bit fields are little endian.
         Tagged16 = 8,
         /// Nothing is written to the log, assume value is 0
         Null = 9,
@@ -64,9 +69,24 @@ pub(crate) const fn max_chunk_size(&self) -> usize {
         }
     }
 
+    /// Writes `values`, which must be a group of up to
+    /// [`max_chunk_size`](Self::max_chunk_size) fields using this encoding.
+    ///
+    /// Returns the index of the first value that cannot be represented if
+    /// encoding fails.
+    pub(crate) fn encode_into(
+        &self,
+        out: &mut Vec<u8>,
+        version: DataVersion,
+        values: &[u32],
+    ) -> Result<(), usize> {
+        super::encode::encode_into(*self, out, version, values)
+    }
+
     pub(crate) fn decode_into(
         &self,
         data: &mut Reader,
+        version: DataVersion,
         extra: usize,
         into: &mut Vec<u32>,
     ) -> InternalResult<()> {
@@ -86,9 +106,12 @@ pub(crate) fn decode_into(
                 into.extend_from_slice(&tagged_32(data)?.map(i32::cast_unsigned)[range]);
             }
             Self::Tagged16 => {
-                into.extend_from_slice(
-                    &tagged_16(data)?.map(|x| i32::from(x).cast_unsigned())[range],
-                );
+                let values = match version {
+                    DataVersion::V1 => tagged_16_v1(data)?,
+                    DataVersion::V2 => tagged_16(data)?,
+                };
+
+                into.extend_from_slice(&values.map(|x| i32::from(x).cast_unsigned())[range]);
             }
 
             Self::Null => into.push(0),
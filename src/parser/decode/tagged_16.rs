@@ -57,6 +57,39 @@
     Ok(result)
 }
 
+/// Decodes the layout used by data version 1.
+///
+/// A 4 bit tag always reads a byte containing two fields, low nibble first, and
+/// the tag of the second field is ignored.
+pub(crate) fn tagged_16_v1(data: &mut Reader) -> InternalResult<[i16; COUNT]> {
+    let tags = data.read_u8().ok_or(InternalError::Eof)?;
+
+    let mut result = [0; COUNT];
+    let mut i = 0;
+
+    while i < COUNT {
+        match (tags >> (i * 2)) & 3 {
+            0 => {}
+            1 => {
+                let byte = data.read_u8().ok_or(InternalError::Eof)?;
+                result[i] = i4_to_i16(byte & 0xF);
+
+                // The second field of a pair in the last slot is discarded
+                i += 1;
+                if let Some(next) = result.get_mut(i) {
+                    *next = i4_to_i16(byte >> 4);
+                }
+            }
+            2 => result[i] = data.read_i8().ok_or(InternalError::Eof)?.into(),
+            3.. => result[i] = data.read_i16().ok_or(InternalError::Eof)?,
+        }
+
+        i += 1;
+    }
+
+    Ok(result)
+}
+
 #[inline]
 fn i4_to_i16(nibble: u8) -> i16 {
     sign_extend::<4>(nibble.into()) as i16
@@ -116,6 +149,32 @@ fn tag_order_v2() {
         assert_eq!([0, 1, 2, 3], tagged_16(&mut bits).unwrap());
     }
 
+    #[test]
+    fn tag_order_v1() {
+        let bytes: &[u8] = &[0b1110_0001, 0x21, 0xFD, 0x34, 0x12];
+        let mut bits = Reader::new(bytes);
+
+        assert_eq!([1, 2, -3, 0x1234], tagged_16_v1(&mut bits).unwrap());
+    }
+
+    #[test]
+    fn nibble_pair_v1() {
+        // The tag of the second field in a pair is not used
+        let bytes: &[u8] = &[0b0000_1101, 0xF7, 0x10];
+        let mut bits = Reader::new(bytes);
+
+        assert_eq!([7, -1, 0, 0], tagged_16_v1(&mut bits).unwrap());
+        assert_eq!(Some(0x10), bits.read_u8());
+    }
+
+    #[test]
+    fn last_nibble_v1() {
+        let bytes: &[u8] = &[0b0100_0000, 0x7E];
+        let mut bits = Reader::new(bytes);
+
+        assert_eq!([0, 0, 0, -2], tagged_16_v1(&mut bits).unwrap());
+    }
+
     #[case( &[0x30, 181, 61] => [0, 0, -19139, 0] ; "16 bit high byte first")]
     fn regressions(bytes: &[u8]) -> [i16; 4] {
         let mut bits = Reader::new(bytes);
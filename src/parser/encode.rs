@@ -0,0 +1,441 @@
+//! The inverse of [`decode`](super::decode), for writing logs.
+//!
+//! Where an encoding allows more than one representation of a value, the same
+//! one is chosen as by the flight controller firmware, so that rewriting a log
+//! reproduces the original bytes.
+
+#![allow(clippy::cast_possible_truncation)]
+
+use alloc::vec::Vec;
+
+use super::{DataVersion, Encoding};
+
+/// See [`Encoding::encode_into`].
+pub(super) fn encode_into(
+    encoding: Encoding,
+    out: &mut Vec<u8>,
+    version: DataVersion,
+    values: &[u32],
+) -> Result<(), usize> {
+    debug_assert!(!values.is_empty() && values.len() <= encoding.max_chunk_size());
+
+    match encoding {
+        Encoding::VariableSigned => variable_signed(out, values[0].cast_signed()),
+        Encoding::Variable => variable(out, values[0]),
+
+        Encoding::Negative14Bit => {
+            negative_14_bit(out, values[0].cast_signed()).ok_or(0_usize)?;
+        }
+
+        Encoding::TaggedVariable => {
+            let mut chunk = [0; 8];
+            for (x, value) in chunk.iter_mut().zip(values) {
+                *x = value.cast_signed();
+            }
+
+            tagged_variable(out, &chunk[..values.len()]);
+        }
+        Encoding::Tagged32 => {
+            let mut chunk = [0; 3];
+            for (x, value) in chunk.iter_mut().zip(values) {
+                *x = value.cast_signed();
+            }
+
+            tagged_32(out, chunk);
+        }
+        Encoding::Tagged16 => {
+            let mut chunk = [0; 4];
+            for (i, (x, value)) in chunk.iter_mut().zip(values).enumerate() {
+                *x = i16::try_from(value.cast_signed()).map_err(|_| i)?;
+            }
+
+            match version {
+                DataVersion::V1 => tagged_16_v1(out, chunk),
+                DataVersion::V2 => tagged_16(out, chunk),
+            }
+        }
+
+        Encoding::Null => {
+            if values[0] != 0 {
+                return Err(0);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn variable(out: &mut Vec<u8>, mut value: u32) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+
+    out.push(value as u8);
+}
+
+pub(crate) fn variable_signed(out: &mut Vec<u8>, value: i32) {
+    variable(out, zig_zag_encode(value));
+}
+
+/// Returns `None` if `value` does not fit in 14 bits after negation.
+fn negative_14_bit(out: &mut Vec<u8>, value: i32) -> Option<()> {
+    if !(-0x1FFF..=0x2000).contains(&value) {
+        return None;
+    }
+
+    variable(out, value.wrapping_neg().cast_unsigned() & 0x3FFF);
+    Some(())
+}
+
+fn tagged_variable(out: &mut Vec<u8>, values: &[i32]) {
+    debug_assert!(values.len() <= 8);
+
+    if let [value] = values {
+        variable_signed(out, *value);
+        return;
+    }
+
+    let header = values
+        .iter()
+        .rev()
+        .fold(0, |header, &value| (header << 1) | u8::from(value != 0));
+    out.push(header);
+
+    for &value in values.iter().filter(|&&value| value != 0) {
+        variable_signed(out, value);
+    }
+}
+
+fn tagged_32(out: &mut Vec<u8>, values: [i32; 3]) {
+    const BITS_2: u8 = 0;
+    const BITS_4: u8 = 1;
+    const BITS_6: u8 = 2;
+    const BITS_32: u8 = 3;
+
+    let fits = |value: i32, bits: u32| {
+        let max = 1 << (bits - 1);
+        (-max..max).contains(&value)
+    };
+
+    let selector = values.iter().fold(BITS_2, |selector, &value| {
+        let needed = if fits(value, 2) {
+            BITS_2
+        } else if fits(value, 4) {
+            BITS_4
+        } else if fits(value, 6) {
+            BITS_6
+        } else {
+            BITS_32
+        };
+
+        selector.max(needed)
+    });
+
+    let [a, b, c] = values.map(|x| x.to_le_bytes()[0]);
+    match selector {
+        BITS_2 => out.push((selector << 6) | ((a & 3) << 4) | ((b & 3) << 2) | (c & 3)),
+        BITS_4 => out.extend_from_slice(&[(selector << 6) | (a & 0x0F), (b << 4) | (c & 0x0F)]),
+        BITS_6 => out.extend_from_slice(&[(selector << 6) | (a & 0x3F), b, c]),
+        _ => {
+            // The tag for each value is stored in reverse order, so the first
+            // value ends up in the lowest bits
+            let widths = values.map(|value| {
+                if fits(value, 8) {
+                    1
+                } else if fits(value, 16) {
+                    2
+                } else if fits(value, 24) {
+                    3
+                } else {
+                    4
+                }
+            });
+
+            let tags = widths
+                .iter()
+                .rev()
+                .fold(0, |tags, &width| (tags << 2) | (width - 1) as u8);
+            out.push((selector << 6) | tags);
+
+            for (value, width) in values.iter().zip(widths) {
+                out.extend_from_slice(&value.to_le_bytes()[..width]);
+            }
+        }
+    }
+}
+
+fn tagged_16(out: &mut Vec<u8>, values: [i16; 4]) {
+    const ZERO: u8 = 0;
+    const BITS_4: u8 = 1;
+    const BITS_8: u8 = 2;
+    const BITS_16: u8 = 3;
+
+    let tag = |value: i16| match value {
+        0 => ZERO,
+        -8..8 => BITS_4,
+        -128..128 => BITS_8,
+        _ => BITS_16,
+    };
+
+    let tags = values
+        .iter()
+        .rev()
+        .fold(0, |tags, &value| (tags << 2) | tag(value));
+    out.push(tags);
+
+    // Values are packed as nibbles, starting with the high bits of each byte
+    let mut buffer: Option<u8> = None;
+    for value in values {
+        let [high, low] = value.to_be_bytes();
+
+        match (tag(value), buffer) {
+            (ZERO, _) => {}
+            (BITS_4, None) => buffer = Some(low << 4),
+            (BITS_4, Some(upper)) => {
+                out.push(upper | (low & 0x0F));
+                buffer = None;
+            }
+            (BITS_8, None) => out.push(low),
+            (BITS_8, Some(upper)) => {
+                out.push(upper | (low >> 4));
+                buffer = Some(low << 4);
+            }
+            (_, None) => out.extend_from_slice(&[high, low]),
+            (_, Some(upper)) => {
+                out.push(upper | (high >> 4));
+                out.push((high << 4) | (low >> 4));
+                buffer = Some(low << 4);
+            }
+        }
+    }
+
+    if let Some(upper) = buffer {
+        out.push(upper);
+    }
+}
+
+/// Unlike data version 2, 4 bit fields are only used when the next field also
+/// fits, since they are always read in pairs.
+fn tagged_16_v1(out: &mut Vec<u8>, values: [i16; 4]) {
+    const ZERO: u8 = 0;
+    const BITS_4: u8 = 1;
+    const BITS_8: u8 = 2;
+    const BITS_16: u8 = 3;
+
+    let is_4_bit = |value: i16| (-8..8).contains(&value);
+
+    let mut tags = 0;
+    let mut bytes = Vec::with_capacity(8);
+    let mut i = 0;
+
+    while i < values.len() {
+        let value = values[i];
+        let next = values.get(i + 1).copied();
+
+        let tag = if value == 0 {
+            ZERO
+        } else if is_4_bit(value) && next.is_none_or(is_4_bit) {
+            let low = (value.cast_unsigned() as u8) & 0x0F;
+            if let Some(next) = next {
+                bytes.push(low | ((next.cast_unsigned() as u8) << 4));
+
+                // The second field of the pair shares the tag
+                tags |= BITS_4 << ((i + 1) * 2);
+            } else {
+                bytes.push(low);
+            }
+
+            BITS_4
+        } else if let Ok(byte) = i8::try_from(value) {
+            bytes.push(byte.cast_unsigned());
+            BITS_8
+        } else {
+            bytes.extend_from_slice(&value.to_le_bytes());
+            BITS_16
+        };
+
+        tags |= tag << (i * 2);
+        i += if tag == BITS_4 { 2 } else { 1 };
+    }
+
+    out.push(tags);
+    out.extend_from_slice(&bytes);
+}
+
+#[inline]
+const fn zig_zag_encode(value: i32) -> u32 {
+    ((value << 1) ^ (value >> 31)).cast_unsigned()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::Reader;
+
+    fn round_trip(encoding: Encoding, values: &[u32]) -> Vec<u8> {
+        round_trip_version(encoding, DataVersion::V2, values)
+    }
+
+    fn round_trip_version(encoding: Encoding, version: DataVersion, values: &[u32]) -> Vec<u8> {
+        let mut out = Vec::new();
+        encoding.encode_into(&mut out, version, values).unwrap();
+
+        let mut data = Reader::new(&out);
+        let mut decoded = Vec::new();
+        encoding
+            .decode_into(&mut data, version, values.len() - 1, &mut decoded)
+            .unwrap();
+
+        assert_eq!(values, decoded, "{encoding:?}: {out:x?}");
+        assert!(data.is_empty(), "{encoding:?}: {out:x?}");
+        out
+    }
+
+    fn signed(values: &[i32]) -> Vec<u32> {
+        values.iter().map(|x| x.cast_unsigned()).collect()
+    }
+
+    #[test]
+    fn zig_zag() {
+        for x in [0, 1, -1, 2, -2, i32::MAX, i32::MIN] {
+            round_trip(Encoding::VariableSigned, &signed(&[x]));
+        }
+
+        assert_eq!([3], *round_trip(Encoding::VariableSigned, &signed(&[-2])));
+    }
+
+    #[test]
+    fn variable() {
+        for x in [0, 1, 0x7F, 0x80, 0x3FFF, 0x4000, u32::MAX] {
+            round_trip(Encoding::Variable, &[x]);
+            round_trip(Encoding::VariableSigned, &[x]);
+        }
+
+        assert_eq!([0xFF, 0x01], *round_trip(Encoding::Variable, &[0xFF]));
+    }
+
+    #[test]
+    fn negative_14_bit() {
+        for x in [0, 1, -1, 0x2000, -0x1FFF] {
+            round_trip(Encoding::Negative14Bit, &signed(&[x]));
+        }
+
+        let mut out = Vec::new();
+        assert_eq!(
+            Err(0),
+            Encoding::Negative14Bit.encode_into(&mut out, DataVersion::V2, &signed(&[-0x2000]))
+        );
+        assert_eq!(
+            Err(0),
+            Encoding::Negative14Bit.encode_into(&mut out, DataVersion::V2, &[0x2001])
+        );
+    }
+
+    #[test]
+    fn tagged_variable() {
+        round_trip(Encoding::TaggedVariable, &signed(&[-5]));
+        round_trip(Encoding::TaggedVariable, &signed(&[0, 0]));
+        round_trip(
+            Encoding::TaggedVariable,
+            &signed(&[1, 0, -1, 0, 1000, 0, 0, i32::MIN]),
+        );
+
+        assert_eq!(
+            [0b0000_0010, 2],
+            *round_trip(Encoding::TaggedVariable, &signed(&[0, 1]))
+        );
+    }
+
+    #[test]
+    fn tagged_32() {
+        for values in [
+            [0, -1, 1],
+            [-2, 1, 0],
+            [7, -8, 0],
+            [31, -32, 1],
+            [32, -1, 0],
+            [-129, 0x7FFF, -0x80_0000],
+            [i32::MAX, i32::MIN, 0x80_0000],
+        ] {
+            round_trip(Encoding::Tagged32, &signed(&values));
+        }
+
+        // Fewer values than the chunk size
+        round_trip(Encoding::Tagged32, &signed(&[5]));
+        round_trip(Encoding::Tagged32, &signed(&[100, -100]));
+
+        assert_eq!(
+            [0x0D],
+            *round_trip(Encoding::Tagged32, &signed(&[0, -1, 1]))
+        );
+        assert_eq!(
+            [0x81, 0x02, 0x08],
+            *round_trip(Encoding::Tagged32, &[1, 2, 8])
+        );
+    }
+
+    #[test]
+    fn tagged_16() {
+        for values in [
+            [0, 0, 0, 0],
+            [1, -8, 7, 0],
+            [1, 100, -100, 3],
+            [0x7FFF, -0x8000, 5, -129],
+            [3, 0x1234, 2, -2],
+        ] {
+            round_trip(Encoding::Tagged16, &signed(&values));
+        }
+
+        round_trip(Encoding::Tagged16, &signed(&[-3, 200]));
+
+        assert_eq!(
+            [0b1101_0100, 0x12, 0x03, 0x00],
+            *round_trip(Encoding::Tagged16, &[0, 1, 2, 0x300])
+        );
+
+        let mut out = Vec::new();
+        assert_eq!(
+            Err(1),
+            Encoding::Tagged16.encode_into(&mut out, DataVersion::V2, &signed(&[0, 0x8000]))
+        );
+    }
+
+    #[test]
+    fn tagged_16_v1() {
+        let round_trip =
+            |values: &[u32]| round_trip_version(Encoding::Tagged16, DataVersion::V1, values);
+
+        for values in [
+            [0, 0, 0, 0],
+            [1, -8, 7, 0],
+            [1, 100, -100, 3],
+            [0x7FFF, -0x8000, 5, -129],
+            [3, 0x1234, 2, -2],
+            [0, 5, 0, 0],
+        ] {
+            round_trip(&signed(&values));
+        }
+
+        round_trip(&signed(&[-3, 200]));
+        round_trip(&signed(&[4]));
+
+        assert_eq!(
+            [0b1110_0101, 0x21, 0xFD, 0x34, 0x12],
+            *round_trip(&signed(&[1, 2, -3, 0x1234]))
+        );
+    }
+
+    #[test]
+    fn null() {
+        assert!(round_trip(Encoding::Null, &[0]).is_empty());
+
+        let mut out = vec![];
+        assert_eq!(
+            Err(0),
+            Encoding::Null.encode_into(&mut out, DataVersion::V2, &[1])
+        );
+    }
+}
@@ -1,6 +1,17 @@
 pub(crate) mod decode;
+pub(crate) mod encode;
 
-pub(crate) use self::decode::Encoding;
+pub use self::decode::Encoding;
+
+/// The format of the data section, from the `Data version` header.
+///
+/// Only the layout of [`Encoding::Tagged16`] differs between versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum DataVersion {
+    /// Written by older versions of Cleanflight and Baseflight
+    V1,
+    V2,
+}
 
 pub(crate) type InternalResult<T> = Result<T, InternalError>;
 
@@ -1,23 +1,38 @@
 use core::ops::{Add, Sub};
 
-use super::frame::GpsPosition;
+use super::frame::{self, GpsPosition};
 use crate::Headers;
 
+/// How a field's value is predicted from earlier data, so that only the
+/// difference needs to be stored.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 #[repr(u16)]
-pub(crate) enum Predictor {
+pub enum Predictor {
     Zero = 0,
+    /// The value of the field in the previous frame
     Previous,
+    /// Extrapolated from the previous two frames
     StraightLine,
+    /// The average of the previous two frames
     Average2,
+    /// The `minthrottle` header
     MinThrottle,
+    /// The value of `motor[0]` in the same frame
     Motor0,
+    /// One more than the previous frame, accounting for skipped frames
     Increment,
+    /// The latitude of the GPS home. Written to the headers as the same number
+    /// as [`HomeLon`](Self::HomeLon)
     HomeLat,
     FifteenHundred,
+    /// The `vbatref` header
     VBatReference,
+    /// The time of the previous main frame
     LastMainFrameTime,
+    /// The minimum of the `motorOutput` header
     MinMotor,
+    /// The longitude of the GPS home
     HomeLon = 256,
 }
 
@@ -50,7 +65,33 @@ pub(crate) fn apply(
         };
         let _span = _span.enter();
 
-        let diff = match self {
+        let diff = self.predict(signed, current, ctx);
+
+        if signed {
+            let signed = value.cast_signed().wrapping_add(diff.cast_signed());
+            tracing::trace!(return = signed);
+            signed.cast_unsigned()
+        } else {
+            let x = value.wrapping_add(diff);
+            tracing::trace!(return = x);
+            x
+        }
+    }
+
+    /// Reverses [`Predictor::apply`], returning the raw value that will decode
+    /// back to `value`.
+    pub(crate) fn unapply(
+        self,
+        value: u32,
+        signed: bool,
+        current: Option<&[u32]>,
+        ctx: &PredictorContext,
+    ) -> u32 {
+        value.wrapping_sub(self.predict(signed, current, ctx))
+    }
+
+    fn predict(self, signed: bool, current: Option<&[u32]>, ctx: &PredictorContext) -> u32 {
+        match self {
             Self::Zero => 0,
             Self::Previous => ctx.last.unwrap_or(0),
             Self::StraightLine => {
@@ -114,20 +155,54 @@ pub(crate) fn apply(
             Self::FifteenHundred => 1500,
             Self::VBatReference => ctx.headers.vbat_reference.unwrap().into(),
             Self::LastMainFrameTime => {
-                tracing::debug!("found unhandled {self:?}");
-                0
+                // Only the low 32 bits of the time fit in a field value, see
+                // `apply_time` for the full time
+                #[expect(clippy::cast_possible_truncation)]
+                let time = ctx.last_main_time.map_or(0, |time| time as u32);
+                time
             }
             Self::MinMotor => ctx.headers.motor_output_range.unwrap().min.into(),
+        }
+    }
+
+    /// Applies the predictor to a field holding a microsecond timestamp, which
+    /// could have rolled over the 32 bit limit of normal field values.
+    pub(crate) fn apply_time(self, value: u32, ctx: &PredictorContext) -> u64 {
+        if self == Self::LastMainFrameTime {
+            let time = ctx.last_main_time.unwrap_or(0).saturating_add(value.into());
+            tracing::trace!(
+                ?self,
+                value,
+                last_main_time = ctx.last_main_time,
+                return = time
+            );
+            time
+        } else {
+            let time = self.apply(value, false, None, ctx);
+            frame::unwrap_time(time, ctx.last_main_time)
+        }
+    }
+
+    /// Reverses [`Predictor::apply_time`], returning `None` if `time` cannot
+    /// be represented.
+    pub(crate) fn unapply_time(self, time: u64, ctx: &PredictorContext) -> Option<u32> {
+        let raw = if self == Self::LastMainFrameTime {
+            u32::try_from(time.checked_sub(ctx.last_main_time.unwrap_or(0))?).ok()?
+        } else {
+            #[expect(clippy::cast_possible_truncation)]
+            let time = time as u32;
+            self.unapply(time, false, None, ctx)
         };
 
-        if signed {
-            let signed = value.cast_signed().wrapping_add(diff.cast_signed());
-            tracing::trace!(return = signed);
-            signed.cast_unsigned()
+        (self.apply_time(raw, ctx) == time).then_some(raw)
+    }
+
+    /// Returns the number used for this predictor in the log headers.
+    pub(crate) const fn to_num(self) -> u16 {
+        if matches!(self, Self::HomeLon) {
+            Self::HomeLat as u16
         } else {
-            let x = value.wrapping_add(diff);
-            tracing::trace!(return = x);
-            x
+            self as u16
         }
     }
 
@@ -157,32 +232,40 @@ pub(crate) struct PredictorContext<'a, 'data> {
     last_last: Option<u32>,
     skipped_frames: u32,
     gps_home: Option<GpsPosition>,
+    last_main_time: Option<u64>,
 }
 
 impl<'a, 'data> PredictorContext<'a, 'data> {
-    pub(crate) const fn new(headers: &'a Headers<'data>) -> Self {
+    pub(crate) const fn new(headers: &'a Headers<'data>, last_main_time: Option<u64>) -> Self {
         Self {
             headers,
             last: None,
             last_last: None,
             skipped_frames: 0,
             gps_home: None,
+            last_main_time,
         }
     }
 
-    pub(crate) const fn with_skipped(headers: &'a Headers<'data>, skipped_frames: u32) -> Self {
+    pub(crate) const fn with_skipped(
+        headers: &'a Headers<'data>,
+        skipped_frames: u32,
+        last_main_time: Option<u64>,
+    ) -> Self {
         Self {
             headers,
             last: None,
             last_last: None,
             skipped_frames,
             gps_home: None,
+            last_main_time,
         }
     }
 
     pub(crate) const fn with_home(
         headers: &'a Headers<'data>,
         gps_home: Option<GpsPosition>,
+        last_main_time: Option<u64>,
     ) -> Self {
         Self {
             headers,
@@ -190,6 +273,7 @@ pub(crate) const fn with_home(
             last_last: None,
             skipped_frames: 0,
             gps_home,
+            last_main_time,
         }
     }
 
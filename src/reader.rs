@@ -29,6 +29,13 @@ pub(crate) const fn new(data: &'data [u8]) -> Self {
         Self { index: 0, data }
     }
 
+    /// Returns the number of bytes that have been read from the start of the
+    /// underlying buffer.
+    #[must_use]
+    pub(crate) const fn index(&self) -> usize {
+        self.index
+    }
+
     /// Returns a value that can be passed to [`Reader::restore`] to rewind to
     /// the current index.
     pub(crate) const fn get_restore_point(&self) -> RestorePoint {
@@ -66,6 +73,12 @@ pub(crate) const fn remaining(&self) -> usize {
         self.data.len() - self.index
     }
 
+    /// Returns all bytes that have already been read.
+    #[must_use]
+    pub(crate) fn read_bytes(&self) -> &'data [u8] {
+        &self.data[..self.index]
+    }
+
     /// Returns true if the [`Reader`] has reached the end of the underlying
     /// buffer.
     #[must_use]
@@ -0,0 +1,434 @@
+//! Parsing logs incrementally from a [`Read`] implementation.
+//!
+//! Unlike [`File`][crate::File], which needs the complete log in memory, a
+//! [`LogReader`] only keeps a small sliding window of the input buffered at a
+//! time. This makes it possible to decode very large flash dumps with bounded
+//! memory use:
+//!
+//! ```no_run
+//! use std::fs;
+//! use std::io::BufReader;
+//!
+//! use blackbox_log::prelude::*;
+//! use blackbox_log::stream::LogReader;
+//!
+//! let file = fs::File::open("LOG00001.BFL")?;
+//! let mut reader = LogReader::new(BufReader::new(file));
+//!
+//! while let Some(headers) = reader.next_headers()? {
+//!     let headers = headers.parse().expect("valid log headers");
+//!
+//!     let mut parser = reader.data_parser(&headers);
+//!     while let Some(event) = parser.next()? {
+//!         if let ParserEvent::Main(main) = event {
+//!             println!("{:?}", main.time());
+//!         }
+//!     }
+//! }
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+use std::io::{self, Read};
+
+use memchr::memmem;
+
+use crate::data::{InternalFrame, ParserState, Stats, Step};
+use crate::frame::{self, FilteredFrameDef};
+use crate::headers::ParseResult;
+use crate::{FilterSet, Headers, OwnedParserEvent, ParserEvent, Reader};
+
+/// The minimum number of bytes requested from the underlying reader at once.
+const READ_SIZE: usize = 16 * 1024;
+
+/// Reads logs from a [`Read`] implementation, buffering only as much as is
+/// needed to parse the current header or frame.
+pub struct LogReader<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    /// Index of the next unread byte in `buffer`
+    position: usize,
+    /// Set once `reader` has reported the end of its data
+    eof: bool,
+}
+
+impl<R: Read> LogReader<R> {
+    /// Creates a new `LogReader` starting at the current position of `reader`.
+    ///
+    /// `reader` does not need to be buffered, since `LogReader` keeps its own
+    /// buffer.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: Vec::with_capacity(READ_SIZE),
+            position: 0,
+            eof: false,
+        }
+    }
+
+    /// Consumes the `LogReader`, returning the underlying reader.
+    ///
+    /// **Note:** Any data that has been read into the internal buffer, but not
+    /// yet parsed, is lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Skips ahead to the start of the next log and reads its header section.
+    ///
+    /// Returns `None` if there are no more logs.
+    pub fn next_headers(&mut self) -> io::Result<Option<RawHeaders>> {
+        let finder = memmem::Finder::new(crate::MARKER);
+
+        loop {
+            if let Some(start) = finder.find(self.unread()) {
+                self.position += start;
+                break;
+            }
+
+            if self.eof {
+                self.position = self.buffer.len();
+                return Ok(None);
+            }
+
+            // Keep any partial marker at the end of the buffer
+            let keep = (crate::MARKER.len() - 1).min(self.unread().len());
+            self.position = self.buffer.len() - keep;
+            self.fill(READ_SIZE)?;
+        }
+
+        let mut headers = Vec::new();
+        loop {
+            let line = loop {
+                let unread = self.unread();
+                if let Some(end) = memchr::memchr(b'\n', unread) {
+                    break Some(end + 1);
+                }
+
+                if self.eof {
+                    break (!unread.is_empty()).then_some(unread.len());
+                }
+
+                self.fill(READ_SIZE)?;
+            };
+
+            let Some(len) = line else {
+                break;
+            };
+
+            let line = &self.unread()[..len];
+            if !is_header_line(line) {
+                break;
+            }
+
+            headers.extend_from_slice(line);
+            self.position += len;
+        }
+
+        Ok(Some(RawHeaders(headers)))
+    }
+
+    /// Returns a new [`StreamDataParser`] for the data section following the
+    /// last headers returned by [`LogReader::next_headers`].
+    pub fn data_parser<'data, 'headers, 'reader>(
+        &'reader mut self,
+        headers: &'headers Headers<'data>,
+    ) -> StreamDataParser<'data, 'headers, 'reader, R> {
+        self.data_parser_with_filters(headers, &FilterSet::default())
+    }
+
+    pub fn data_parser_with_filters<'data, 'headers, 'reader>(
+        &'reader mut self,
+        headers: &'headers Headers<'data>,
+        filters: &FilterSet,
+    ) -> StreamDataParser<'data, 'headers, 'reader, R> {
+        StreamDataParser {
+            headers,
+            state: ParserState::new(headers, filters),
+            reader: self,
+        }
+    }
+
+    fn unread(&self) -> &[u8] {
+        &self.buffer[self.position..]
+    }
+
+    /// Reads at least `additional` more bytes into the buffer, unless the end
+    /// of the reader is found first.
+    fn fill(&mut self, additional: usize) -> io::Result<()> {
+        // Slide the window forward by dropping everything already parsed
+        if self.position > 0 {
+            self.buffer.drain(..self.position);
+            self.position = 0;
+        }
+
+        let target = self.buffer.len() + additional.max(READ_SIZE);
+        while !self.eof && self.buffer.len() < target {
+            let start = self.buffer.len();
+            self.buffer.resize(target, 0);
+
+            match self.reader.read(&mut self.buffer[start..]) {
+                Ok(0) => {
+                    self.buffer.truncate(start);
+                    self.eof = true;
+                }
+                Ok(read) => self.buffer.truncate(start + read),
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {
+                    self.buffer.truncate(start);
+                }
+                Err(err) => {
+                    self.buffer.truncate(start);
+                    return Err(err);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R> fmt::Debug for LogReader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogReader")
+            .field("buffered", &(self.buffer.len() - self.position))
+            .field("eof", &self.eof)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The unparsed header section of a log read by [`LogReader::next_headers`].
+#[derive(Debug, Clone)]
+pub struct RawHeaders(Vec<u8>);
+
+impl RawHeaders {
+    /// Parses the headers.
+    pub fn parse(&self) -> ParseResult<Headers<'_>> {
+        Headers::parse(&self.0)
+    }
+
+    /// Returns the raw bytes of the header section.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// A [`DataParser`][crate::DataParser] that reads its input from a
+/// [`LogReader`].
+///
+/// The data frames it returns only borrow from the [`Headers`] and the parser
+/// itself, never from the input, so the same [`ParserEvent`]s are produced as
+/// when parsing from a byte slice.
+#[derive(Debug)]
+pub struct StreamDataParser<'data, 'headers, 'reader, R> {
+    headers: &'headers Headers<'data>,
+    state: ParserState,
+    reader: &'reader mut LogReader<R>,
+}
+
+impl<'data, 'headers, R: Read> StreamDataParser<'data, 'headers, '_, R> {
+    pub fn main_frame_def<'a>(&'a self) -> FilteredFrameDef<'a, frame::MainFrameDef<'data>> {
+        self.state.main_frame_def(self.headers)
+    }
+
+    pub fn slow_frame_def<'a>(&'a self) -> FilteredFrameDef<'a, frame::SlowFrameDef<'data>> {
+        self.state.slow_frame_def(self.headers)
+    }
+
+    pub fn gps_frame_def<'a>(&'a self) -> Option<FilteredFrameDef<'a, frame::GpsFrameDef<'data>>> {
+        self.state.gps_frame_def(self.headers)
+    }
+
+    /// Returns the current stats.
+    ///
+    /// **Note:** Since the total length of the input is unknown,
+    /// [`Stats::progress`] is only updated once the end of the log is found.
+    #[inline]
+    pub fn stats(&self) -> &Stats {
+        &self.state.stats
+    }
+
+    /// Returns `true` if the parser has reached the end of the log.
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.state.done
+    }
+
+    /// Returns where the last event or frame returned by [`next`][Self::next]
+    /// was found, as a range of offsets from the start of the log.
+    ///
+    /// Use this to locate an [`Event`][crate::Event]. Frames and
+    /// [`OwnedParserEvent`]s also include their own location.
+    #[inline]
+    pub fn last_span(&self) -> Option<Range<usize>> {
+        self.state.last_span()
+    }
+
+    /// Continues parsing until the next [`ParserEvent`] can be returned.
+    /// Returns `None` if the parser finds the end of the log, or the start of
+    /// the next one.
+    pub fn next<'parser>(
+        &'parser mut self,
+    ) -> io::Result<Option<ParserEvent<'data, 'headers, 'parser>>> {
+        loop {
+            let reader = &mut *self.reader;
+            let unread = reader.unread();
+
+            // Stop before the start of the next log, leaving it for `next_headers`
+            let marker_len = crate::MARKER.len().min(unread.len());
+            if marker_len > 0 && crate::MARKER.starts_with(&unread[..marker_len]) {
+                if marker_len == crate::MARKER.len() {
+                    self.state.done = true;
+                    return Ok(None);
+                } else if !reader.eof {
+                    reader.fill(crate::MARKER.len())?;
+                    continue;
+                }
+            }
+
+            let len = unread.len();
+            let mut data = Reader::new(unread);
+            let step = self.state.parse_next(&mut data, self.headers, reader.eof);
+            reader.position += len - data.remaining();
+
+            match step {
+                Step::Frame(InternalFrame::GpsHome(gps_home), _) => {
+                    self.state.set_gps_home(gps_home);
+                }
+                Step::Frame(frame, span) => {
+                    return Ok(Some(self.state.emit(self.headers, frame, span)));
+                }
+                Step::Incomplete => {
+                    let buffered = reader.unread().len();
+                    reader.fill(buffered)?;
+                }
+                Step::Eof => {
+                    self.state.done = true;
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    /// Like [`next`][Self::next], but returns an [`OwnedParserEvent`] that
+    /// does not borrow the parser.
+    pub fn next_owned(&mut self) -> io::Result<Option<OwnedParserEvent>> {
+        let Some(event) = self.next()? else {
+            return Ok(None);
+        };
+
+        Ok(match event.into_owned_frame() {
+            Ok(frame) => Some(frame),
+            Err(event) => self
+                .last_span()
+                .map(|span| OwnedParserEvent::Event { event, span }),
+        })
+    }
+}
+
+/// Returns `true` if `line` could be parsed as a header.
+fn is_header_line(line: &[u8]) -> bool {
+    line.first() == Some(&b'H') && line.contains(&b':')
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::frame::Frame as _;
+
+    static LOG: &[u8] = include_bytes!("../tests/logs/error-recovery.bbl");
+
+    /// Returns at most `chunk` bytes for each call to `read`.
+    struct Chunked<'a> {
+        data: &'a [u8],
+        chunk: usize,
+    }
+
+    impl Read for Chunked<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = self.chunk.min(buf.len()).min(self.data.len());
+            buf[..len].copy_from_slice(&self.data[..len]);
+            self.data = &self.data[len..];
+            Ok(len)
+        }
+    }
+
+    type Summary = (char, Range<usize>, Option<u64>, Vec<u32>);
+
+    fn summarize(event: ParserEvent) -> (char, Option<u64>, Vec<u32>) {
+        match event {
+            ParserEvent::Event(_) => ('E', None, vec![]),
+            ParserEvent::Main(main) => ('M', Some(main.time_raw()), main.iter_raw().collect()),
+            ParserEvent::Slow(slow) => ('S', None, slow.iter_raw().collect()),
+            ParserEvent::Gps(gps) => ('G', Some(gps.time_raw()), gps.iter_raw().collect()),
+        }
+    }
+
+    fn expected(data: &[u8]) -> Vec<Vec<Summary>> {
+        crate::File::new(data)
+            .iter()
+            .map(|headers| {
+                let headers = headers.unwrap();
+                let mut parser = headers.data_parser();
+                let mut events = Vec::new();
+                while let Some(event) = parser.next() {
+                    let (kind, time, values) = summarize(event);
+                    events.push((kind, parser.last_span().unwrap(), time, values));
+                }
+                events
+            })
+            .collect()
+    }
+
+    fn streamed(data: &[u8], chunk: usize) -> Vec<Vec<Summary>> {
+        let mut reader = LogReader::new(Chunked { data, chunk });
+        let mut logs = Vec::new();
+
+        while let Some(headers) = reader.next_headers().unwrap() {
+            let headers = headers.parse().unwrap();
+            let mut parser = reader.data_parser(&headers);
+            let mut events = Vec::new();
+            while let Some(event) = parser.next().unwrap() {
+                let (kind, time, values) = summarize(event);
+                events.push((kind, parser.last_span().unwrap(), time, values));
+            }
+            logs.push(events);
+        }
+
+        logs
+    }
+
+    #[test]
+    fn matches_slice_parser() {
+        let expected = expected(LOG);
+        assert!(!expected[0].is_empty());
+
+        for chunk in [1, 7, 64, 4096] {
+            assert_eq!(expected, streamed(LOG, chunk), "chunk size: {chunk}");
+        }
+    }
+
+    #[test]
+    fn multiple_logs() {
+        let mut data = b"garbage".to_vec();
+        data.extend_from_slice(LOG);
+        data.extend_from_slice(LOG);
+
+        let expected = expected(&data);
+        assert_eq!(2, expected.len());
+        assert_eq!(expected, streamed(&data, 13));
+    }
+
+    #[test]
+    fn no_logs() {
+        let mut reader = LogReader::new(Chunked {
+            data: b"H Product:Blackbox",
+            chunk: 5,
+        });
+        assert!(reader.next_headers().unwrap().is_none());
+    }
+}
@@ -0,0 +1,589 @@
+//! Export of GPS tracks for mapping tools.
+//!
+//! A [`Track`] collects the GPS frames of a log along with the GPS home
+//! position, and can be written as a [GPX](Track::write_gpx) track, a
+//! [KML](Track::write_kml) `LineString` or a [GeoJSON](Track::write_geojson)
+//! `FeatureCollection`.
+//!
+//! ```
+//! use blackbox_log::track::Track;
+//!
+//! # let file = b"...";
+//! # for headers in blackbox_log::File::new(file).iter() {
+//! #     let headers = headers.unwrap();
+//! let track = Track::new(&headers);
+//!
+//! let mut gpx = String::new();
+//! track.write_gpx(&mut gpx).unwrap();
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use time::format_description::well_known::Rfc3339;
+use time::{Duration, OffsetDateTime, UtcOffset};
+
+use crate::data::{InternalFrame, ParserState, Step};
+use crate::frame::{Frame as _, FrameDef as _, GpsFrame, GpsValue};
+use crate::units::prelude::*;
+use crate::{units, FilterSet, Headers, ParserEvent};
+
+/// The GPS frames of a log.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Track<'data> {
+    /// The `Craft name` header, if set.
+    pub name: Option<&'data str>,
+    /// The first GPS home position found in the log.
+    pub home: Option<Position>,
+    pub points: Vec<TrackPoint>,
+    /// The `Log start datetime` header, if the flight controller knew the time
+    /// when the log started.
+    pub start: Option<OffsetDateTime>,
+    /// The raw time of the first main frame, which is assumed to match
+    /// [`start`](Self::start).
+    start_time: Option<u64>,
+}
+
+/// A position in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Position {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// A single GPS frame.
+///
+/// Each field is `None` if the log does not include it.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TrackPoint {
+    /// The raw microsecond counter since power on. See
+    /// [`GpsFrame::time_raw`].
+    pub time: u64,
+    pub position: Position,
+    pub altitude: Option<Length>,
+    pub speed: Option<Velocity>,
+    /// The ground course in degrees.
+    pub heading: Option<f64>,
+    pub satellites: Option<u32>,
+}
+
+/// The indices of the GPS fields used in a [`TrackPoint`].
+#[derive(Debug, Default)]
+struct Fields {
+    latitude: Option<usize>,
+    longitude: Option<usize>,
+    altitude: Option<usize>,
+    speed: Option<usize>,
+    heading: Option<usize>,
+    satellites: Option<usize>,
+}
+
+impl<'data> Track<'data> {
+    /// Decodes the whole log to collect its GPS frames. The track is empty if
+    /// the log cannot contain GPS frames.
+    pub fn new(headers: &Headers<'data>) -> Self {
+        let mut track = Self {
+            name: headers.craft_name(),
+            home: None,
+            points: Vec::new(),
+            start: headers
+                .unknown()
+                .get("Log start datetime")
+                .and_then(|datetime| OffsetDateTime::parse(datetime, &Rfc3339).ok())
+                // Logged when the flight controller does not know the time
+                .filter(|datetime| datetime.year() > 0),
+            start_time: None,
+        };
+
+        let Some(fields) = Fields::new(headers) else {
+            return track;
+        };
+
+        let mut data = headers.data();
+        let mut state = ParserState::new(headers, &FilterSet::default());
+        loop {
+            match state.parse_next(&mut data, headers, true) {
+                Step::Frame(InternalFrame::GpsHome(home), _) => {
+                    if track.home.is_none() {
+                        track.home = Some(Position {
+                            latitude: coordinate(home.0.latitude),
+                            longitude: coordinate(home.0.longitude),
+                        });
+                    }
+
+                    state.set_gps_home(home);
+                }
+                Step::Frame(frame, span) => match state.emit(headers, frame, span) {
+                    ParserEvent::Main(main) => {
+                        track.start_time.get_or_insert(main.time_raw());
+                    }
+                    ParserEvent::Gps(gps) => {
+                        if let Some(point) = fields.point(&gps) {
+                            track.points.push(point);
+                        }
+                    }
+                    ParserEvent::Event(_) | ParserEvent::Slow(_) => {}
+                },
+                Step::Incomplete | Step::Eof => break,
+            }
+        }
+
+        track
+    }
+
+    /// Returns the date and time of a point, if the start of the log is known.
+    pub fn datetime(&self, point: &TrackPoint) -> Option<OffsetDateTime> {
+        let start = self.start?;
+        let elapsed = point.time.checked_sub(self.start_time?)?;
+        start.checked_add(Duration::microseconds(elapsed.try_into().ok()?))
+    }
+
+    fn title(&self) -> &str {
+        self.name
+            .filter(|name| !name.is_empty())
+            .unwrap_or("Flight")
+    }
+
+    /// Writes the track as a GPX 1.1 document, with the home position as a
+    /// waypoint.
+    ///
+    /// Speed and heading are not part of GPX 1.1 track points, so they are
+    /// written as `<speed>` and `<course>` extensions.
+    pub fn write_gpx(&self, out: &mut impl Write) -> fmt::Result {
+        out.write_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        out.write_str(
+            "\n<gpx version=\"1.1\" creator=\"blackbox-log\" \
+             xmlns=\"http://www.topografix.com/GPX/1/1\">\n",
+        )?;
+
+        if let Some(home) = self.home {
+            writeln!(
+                out,
+                r#"  <wpt lat="{:.7}" lon="{:.7}"><name>Home</name></wpt>"#,
+                home.latitude, home.longitude
+            )?;
+        }
+
+        writeln!(
+            out,
+            "  <trk>\n    <name>{}</name>\n    <trkseg>",
+            Xml(self.title())
+        )?;
+        for point in &self.points {
+            let Position {
+                latitude,
+                longitude,
+            } = point.position;
+            write!(
+                out,
+                r#"      <trkpt lat="{latitude:.7}" lon="{longitude:.7}">"#
+            )?;
+
+            if let Some(altitude) = point.altitude {
+                write!(out, "<ele>{:.2}</ele>", altitude.get::<meter>())?;
+            }
+            if let Some(datetime) = self.datetime(point) {
+                write!(out, "<time>{}</time>", Iso8601(datetime))?;
+            }
+            if let Some(satellites) = point.satellites {
+                write!(out, "<sat>{satellites}</sat>")?;
+            }
+
+            if point.speed.is_some() || point.heading.is_some() {
+                out.write_str("<extensions>")?;
+                if let Some(speed) = point.speed {
+                    write!(out, "<speed>{:.2}</speed>", speed.get::<meter_per_second>())?;
+                }
+                if let Some(heading) = point.heading {
+                    write!(out, "<course>{heading:.1}</course>")?;
+                }
+                out.write_str("</extensions>")?;
+            }
+
+            out.write_str("</trkpt>\n")?;
+        }
+
+        out.write_str("    </trkseg>\n  </trk>\n</gpx>\n")
+    }
+
+    /// Writes the track as a KML document containing a `LineString` with
+    /// absolute altitudes, and the home position as a `Point`.
+    pub fn write_kml(&self, out: &mut impl Write) -> fmt::Result {
+        out.write_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        out.write_str("\n<kml xmlns=\"http://www.opengis.net/kml/2.2\">\n  <Document>\n")?;
+        writeln!(out, "    <name>{}</name>", Xml(self.title()))?;
+
+        if let Some(home) = self.home {
+            writeln!(
+                out,
+                "    <Placemark><name>Home</name><Point><coordinates>{:.7},{:.7}</coordinates></\
+                 Point></Placemark>",
+                home.longitude, home.latitude
+            )?;
+        }
+
+        writeln!(
+            out,
+            "    <Placemark>\n      <name>{}</name>",
+            Xml(self.title())
+        )?;
+        out.write_str("      <LineString>\n        <altitudeMode>absolute</altitudeMode>\n")?;
+        out.write_str("        <coordinates>\n")?;
+        for point in &self.points {
+            let altitude = point
+                .altitude
+                .map_or(0., |altitude| altitude.get::<meter>());
+            writeln!(
+                out,
+                "          {:.7},{:.7},{altitude:.2}",
+                point.position.longitude, point.position.latitude
+            )?;
+        }
+        out.write_str("        </coordinates>\n      </LineString>\n    </Placemark>\n")?;
+
+        out.write_str("  </Document>\n</kml>\n")
+    }
+
+    /// Writes the track as a GeoJSON `FeatureCollection`.
+    ///
+    /// The track is a `LineString` feature whose `coordinateProperties` hold
+    /// the time since power on in seconds, speed in meters per second, heading
+    /// and satellite count of each point, plus the date and time of each point
+    /// under `times` if the start of the log is known. The home position is a
+    /// separate `Point` feature.
+    pub fn write_geojson(&self, out: &mut impl Write) -> fmt::Result {
+        out.write_str(r#"{"type":"FeatureCollection","features":["#)?;
+
+        if let Some(home) = self.home {
+            write!(
+                out,
+                r#"{{"type":"Feature","properties":{{"name":"Home"}},"geometry":{{"type":"Point","coordinates":[{:.7},{:.7}]}}}},"#,
+                home.longitude, home.latitude
+            )?;
+        }
+
+        write!(
+            out,
+            r#"{{"type":"Feature","properties":{{"name":"{}","coordinateProperties":{{"#,
+            Json(self.title())
+        )?;
+
+        write_json_array(out, "time", &self.points, |out, point| {
+            write!(out, "{}", units::new::time(point.time).get::<second>())
+        })?;
+        out.write_char(',')?;
+        write_json_array(out, "speed", &self.points, |out, point| match point.speed {
+            Some(speed) => write!(out, "{:.2}", speed.get::<meter_per_second>()),
+            None => out.write_str("null"),
+        })?;
+        out.write_char(',')?;
+        write_json_array(out, "heading", &self.points, |out, point| {
+            match point.heading {
+                Some(heading) => write!(out, "{heading:.1}"),
+                None => out.write_str("null"),
+            }
+        })?;
+        out.write_char(',')?;
+        write_json_array(out, "satellites", &self.points, |out, point| {
+            match point.satellites {
+                Some(satellites) => write!(out, "{satellites}"),
+                None => out.write_str("null"),
+            }
+        })?;
+        if self.start.is_some() {
+            out.write_char(',')?;
+            write_json_array(out, "times", &self.points, |out, point| {
+                match self.datetime(point) {
+                    Some(datetime) => write!(out, "\"{}\"", Iso8601(datetime)),
+                    None => out.write_str("null"),
+                }
+            })?;
+        }
+
+        out.write_str(r#"}},"geometry":{"type":"LineString","coordinates":["#)?;
+        for (i, point) in self.points.iter().enumerate() {
+            if i > 0 {
+                out.write_char(',')?;
+            }
+
+            write!(
+                out,
+                "[{:.7},{:.7}",
+                point.position.longitude, point.position.latitude
+            )?;
+            if let Some(altitude) = point.altitude {
+                write!(out, ",{:.2}", altitude.get::<meter>())?;
+            }
+            out.write_char(']')?;
+        }
+
+        out.write_str("]}}]}\n")
+    }
+}
+
+impl Fields {
+    fn new(headers: &Headers) -> Option<Self> {
+        let def = headers.gps_frame_def()?;
+
+        let mut fields = Self::default();
+        for (i, field) in def.iter().enumerate() {
+            let index = match field.name {
+                "GPS_coord[0]" => &mut fields.latitude,
+                "GPS_coord[1]" => &mut fields.longitude,
+                "GPS_altitude" => &mut fields.altitude,
+                "GPS_speed" => &mut fields.speed,
+                "GPS_ground_course" => &mut fields.heading,
+                "GPS_numSat" => &mut fields.satellites,
+                _ => continue,
+            };
+
+            *index = Some(i);
+        }
+
+        (fields.latitude.is_some() && fields.longitude.is_some()).then_some(fields)
+    }
+
+    fn point(&self, frame: &GpsFrame) -> Option<TrackPoint> {
+        let get = |index: Option<usize>| frame.get(index?);
+
+        let (Some(GpsValue::Coordinate(latitude)), Some(GpsValue::Coordinate(longitude))) =
+            (get(self.latitude), get(self.longitude))
+        else {
+            return None;
+        };
+
+        Some(TrackPoint {
+            time: frame.time_raw(),
+            position: Position {
+                latitude,
+                longitude,
+            },
+            altitude: if let Some(GpsValue::Altitude(altitude)) = get(self.altitude) {
+                Some(altitude)
+            } else {
+                None
+            },
+            speed: if let Some(GpsValue::Velocity(speed)) = get(self.speed) {
+                Some(speed)
+            } else {
+                None
+            },
+            heading: if let Some(GpsValue::Heading(heading)) = get(self.heading) {
+                Some(heading)
+            } else {
+                None
+            },
+            satellites: if let Some(GpsValue::Unsigned(satellites)) = get(self.satellites) {
+                Some(satellites)
+            } else {
+                None
+            },
+        })
+    }
+}
+
+fn coordinate(raw: i32) -> f64 {
+    f64::from(raw) / 10000000.
+}
+
+fn write_json_array<W: Write>(
+    out: &mut W,
+    name: &str,
+    points: &[TrackPoint],
+    mut write_value: impl FnMut(&mut W, &TrackPoint) -> fmt::Result,
+) -> fmt::Result {
+    write!(out, "\"{name}\":[")?;
+    for (i, point) in points.iter().enumerate() {
+        if i > 0 {
+            out.write_char(',')?;
+        }
+        write_value(out, point)?;
+    }
+    out.write_char(']')
+}
+
+/// Formats a date and time in UTC, eg `2024-01-31T12:34:56.789Z`.
+struct Iso8601(OffsetDateTime);
+
+impl fmt::Display for Iso8601 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let datetime = self.0.to_offset(UtcOffset::UTC);
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+            datetime.year(),
+            u8::from(datetime.month()),
+            datetime.day(),
+            datetime.hour(),
+            datetime.minute(),
+            datetime.second(),
+            datetime.millisecond()
+        )
+    }
+}
+
+/// Escapes text for use in XML.
+struct Xml<'a>(&'a str);
+
+impl fmt::Display for Xml<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                '\'' => f.write_str("&apos;")?,
+                c => f.write_char(c)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Escapes text for use in a JSON string.
+struct Json<'a>(&'a str);
+
+impl fmt::Display for Json<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                c if c.is_control() => write!(f, "\\u{:04x}", u32::from(c))?,
+                c => f.write_char(c)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec;
+
+    use super::*;
+    use crate::writer::{write_log, Encoding, FieldSpec, HeaderSpec, MainFieldSpec, Predictor};
+
+    fn log() -> Vec<u8> {
+        let field = |name, signed, predictor| FieldSpec {
+            name,
+            signed,
+            predictor,
+            encoding: if signed {
+                Encoding::VariableSigned
+            } else {
+                Encoding::Variable
+            },
+        };
+
+        let mut spec = HeaderSpec::new("Betaflight 4.2.0 (8f2d21460) STM32F7X2");
+        spec.main = vec![MainFieldSpec {
+            name: "axisP[0]",
+            signed: true,
+            predictor_intra: Predictor::Zero,
+            predictor_inter: Predictor::Previous,
+            encoding_intra: Encoding::VariableSigned,
+            encoding_inter: Encoding::VariableSigned,
+        }];
+        spec.slow = vec![field("flightModeFlags", false, Predictor::Zero)];
+        spec.gps = Some(vec![
+            field("GPS_numSat", false, Predictor::Zero),
+            field("GPS_coord[0]", true, Predictor::HomeLat),
+            field("GPS_coord[1]", true, Predictor::HomeLon),
+            field("GPS_altitude", true, Predictor::Zero),
+            field("GPS_speed", false, Predictor::Zero),
+            field("GPS_ground_course", false, Predictor::Zero),
+        ]);
+        spec.other = vec![
+            ("motor_pwm_protocol", "0"),
+            ("Craft name", "Q&A"),
+            ("Log start datetime", "2024-01-31T12:00:00.000+01:00"),
+        ];
+
+        write_log(&spec, |writer| {
+            writer.write_intra(1_000_000, &[0, 0]).unwrap();
+            writer.write_gps_home(470_000_000, 85_000_000).unwrap();
+            writer
+                .write_gps(1_500_000, &[7, 470_000_010, 84_999_980, 400, 250, 900])
+                .unwrap();
+            writer.write_inter(2_000_000, &[1, 0]).unwrap();
+            writer
+                .write_gps(2_500_000, &[8, 470_000_020, 85_000_000, 410, 300, 1800])
+                .unwrap();
+        })
+    }
+
+    #[test]
+    fn track() {
+        let log = log();
+        let headers = Headers::parse(&log).unwrap();
+        let track = Track::new(&headers);
+
+        assert_eq!(Some("Q&A"), track.name);
+        assert_eq!(
+            Some(Position {
+                latitude: 47.,
+                longitude: 8.5,
+            }),
+            track.home
+        );
+
+        assert_eq!(2, track.points.len());
+        let point = &track.points[0];
+        assert_eq!(1_500_000, point.time);
+        assert_eq!(47.000001, point.position.latitude);
+        assert_eq!(8.499998, point.position.longitude);
+        assert_eq!(Some(400.), point.altitude.map(|a| a.get::<meter>()));
+        assert_eq!(Some(2.5), point.speed.map(|s| s.get::<meter_per_second>()));
+        assert_eq!(Some(90.), point.heading);
+        assert_eq!(Some(7), point.satellites);
+
+        let datetime = track.datetime(point).unwrap();
+        assert_eq!(
+            "2024-01-31T11:00:00.500Z",
+            alloc::format!("{}", Iso8601(datetime))
+        );
+    }
+
+    #[test]
+    fn formats() {
+        let log = log();
+        let headers = Headers::parse(&log).unwrap();
+        let track = Track::new(&headers);
+
+        let mut gpx = String::new();
+        track.write_gpx(&mut gpx).unwrap();
+        assert!(gpx.contains(r#"<wpt lat="47.0000000" lon="8.5000000"><name>Home</name></wpt>"#));
+        assert!(gpx.contains("<name>Q&amp;A</name>"));
+        assert!(gpx.contains(
+            r#"<trkpt lat="47.0000010" lon="8.4999980"><ele>400.00</ele><time>2024-01-31T11:00:00.500Z</time><sat>7</sat><extensions><speed>2.50</speed><course>90.0</course></extensions></trkpt>"#
+        ));
+        assert_eq!(2, gpx.matches("<trkpt ").count());
+
+        let mut kml = String::new();
+        track.write_kml(&mut kml).unwrap();
+        assert!(kml.contains("<coordinates>8.5000000,47.0000000</coordinates>"));
+        assert!(kml.contains("8.4999980,47.0000010,400.00\n"));
+        assert!(kml.contains("8.5000000,47.0000020,410.00\n"));
+
+        let mut geojson = String::new();
+        track.write_geojson(&mut geojson).unwrap();
+        assert!(geojson.contains(r#""coordinates":[8.5000000,47.0000000]"#));
+        assert!(geojson.contains(r#""time":[1.5,2.5]"#));
+        assert!(geojson.contains(r#""satellites":[7,8]"#));
+        assert!(
+            geojson.contains(r#""times":["2024-01-31T11:00:00.500Z","2024-01-31T11:00:01.500Z"]"#)
+        );
+        assert!(geojson.contains(
+            r#""coordinates":[[8.4999980,47.0000010,400.00],[8.5000000,47.0000020,410.00]]"#
+        ));
+        assert_eq!(geojson.matches('{').count(), geojson.matches('}').count());
+    }
+}
@@ -2,25 +2,229 @@
 
 pub use uom::si;
 pub use uom::si::f64::{
-    Acceleration, AngularVelocity, ElectricCurrent, ElectricPotential, Length, Time, Velocity,
+    Acceleration, AngularVelocity, ElectricCurrent, ElectricPotential, Frequency, Length, Ratio,
+    Time, Velocity,
 };
 
+use crate::config::RatesType;
+use crate::headers::{InternalFirmware, PwmProtocol};
 use crate::Headers;
 
 #[allow(unreachable_pub, unused_imports)]
 pub(crate) mod prelude {
     pub use super::si::acceleration::{meter_per_second_squared as mps2, standard_gravity};
-    pub use super::si::angular_velocity::degree_per_second;
+    pub use super::si::angular_velocity::{degree_per_second, radian_per_second};
     pub use super::si::electric_current::{ampere, milliampere};
     pub use super::si::electric_potential::{millivolt, volt};
+    pub use super::si::frequency::{cycle_per_minute, hertz};
     pub use super::si::length::meter;
+    pub use super::si::ratio::{percent, ratio};
     pub use super::si::time::{microsecond, second};
     pub use super::si::velocity::meter_per_second;
     pub use super::{
-        Acceleration, AngularVelocity, ElectricCurrent, ElectricPotential, Length, Time, Velocity,
+        Acceleration, AngularVelocity, ElectricCurrent, ElectricPotential, Frequency, Length,
+        Ratio, Time, Velocity,
     };
 }
 
+/// The values from the headers needed to convert raw field values into units.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct UnitContext {
+    pub(crate) firmware: InternalFirmware,
+    acceleration_1g: Option<u16>,
+    gyro_scale: Option<f32>,
+    vbat_scale: Option<u8>,
+    pub(crate) motor_output: Option<MotorOutput>,
+    rate_curve: Option<RateCurve>,
+}
+
+impl UnitContext {
+    pub(crate) fn new(headers: &Headers) -> Self {
+        Self {
+            firmware: headers.internal_firmware,
+            acceleration_1g: headers.acceleration_1g,
+            gyro_scale: headers.gyro_scale,
+            vbat_scale: headers.vbat_scale,
+            motor_output: MotorOutput::new(headers),
+            rate_curve: RateCurve::new(headers),
+        }
+    }
+}
+
+/// The raw motor outputs at zero and full throttle.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MotorOutput {
+    zero: f64,
+    full: f64,
+}
+
+impl MotorOutput {
+    /// The lowest DShot throttle value. Anything below is a command.
+    const DSHOT_MIN_THROTTLE: u16 = 48;
+
+    fn new(headers: &Headers) -> Option<Self> {
+        let range = headers.motor_output_range?;
+
+        // The minimum of `motorOutput` includes motor idle. Betaflight logs the DShot
+        // value when using a digital protocol, while INAV always logs a pulse width
+        let digital = is_digital(headers.pwm_protocol()) && !headers.internal_firmware.is_inav();
+        let zero = if digital {
+            Self::DSHOT_MIN_THROTTLE
+        } else {
+            range.min
+        };
+
+        Some(Self {
+            zero: zero.into(),
+            full: range.max.into(),
+        })
+    }
+
+    /// Returns the fraction of full throttle, clamped to `0..=1`.
+    pub(crate) fn fraction(self, raw: f64) -> f64 {
+        ((raw - self.zero) / (self.full - self.zero)).clamp(0., 1.)
+    }
+}
+
+/// A stick position decoded from `rcCommand`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stick {
+    /// Deflection from center as a fraction of full travel, from `-1` to `1`.
+    /// Throttle is from `0` to `1`.
+    pub deflection: f64,
+    /// The rotation rate requested by this stick position, using the rates
+    /// from the headers.
+    ///
+    /// `None` for throttle, or if the rates are missing or unsupported.
+    pub rate: Option<AngularVelocity>,
+}
+
+/// The rate settings used to convert stick deflection into a rotation rate.
+/// Arrays are ordered roll, pitch, yaw.
+#[derive(Debug, Clone, Copy)]
+enum RateCurve {
+    Betaflight {
+        kind: RatesType,
+        rc_rate: [u16; 3],
+        expo: [u16; 3],
+        rate: [u16; 3],
+        limit: [u16; 3],
+    },
+    /// INAV applies expo before logging `rcCommand`, so only the maximum rate
+    /// in tens of degrees per second is needed.
+    Inav { rate: [u16; 3] },
+}
+
+impl RateCurve {
+    /// The default `rate_limits`, in degrees per second.
+    const BETAFLIGHT_LIMIT: u16 = 1998;
+
+    fn new(headers: &Headers) -> Option<Self> {
+        let firmware = headers.internal_firmware;
+        let rates = headers.config().rates;
+
+        if firmware.is_inav() {
+            return rates.rate.map(|rate| Self::Inav { rate });
+        }
+
+        // Rotorflight numbers its rates types differently and is not supported
+        if firmware.is_rotorflight() {
+            return None;
+        }
+
+        Some(Self::Betaflight {
+            // Betaflight rates were the only option before 4.2
+            kind: rates.kind.unwrap_or(RatesType::Betaflight),
+            rc_rate: rates.rc_rate?,
+            expo: rates.expo?,
+            rate: rates.rate?,
+            limit: rates.rate_limit.unwrap_or([Self::BETAFLIGHT_LIMIT; 3]),
+        })
+    }
+
+    /// Returns the rotation rate in degrees per second for a deflection from
+    /// `-1` to `1`.
+    fn degrees_per_second(self, axis: usize, deflection: f64) -> f64 {
+        let (kind, rc_rate, expo, rate, limit) = match self {
+            Self::Betaflight {
+                kind,
+                rc_rate,
+                expo,
+                rate,
+                limit,
+            } => (kind, rc_rate[axis], expo[axis], rate[axis], limit[axis]),
+            Self::Inav { rate } => return deflection * f64::from(rate[axis]) * 10.,
+        };
+
+        let x = deflection;
+        let abs = x.abs();
+        let rc_rate = f64::from(rc_rate);
+        let expo = f64::from(expo) / 100.;
+        let rate = f64::from(rate);
+        let limit = f64::from(limit);
+
+        // Mirrors the `apply*Rates` functions in Betaflight's `rc.c`
+        let degrees = match kind {
+            RatesType::Betaflight => {
+                let x = x * abs * abs * abs * expo + x * (1. - expo);
+
+                let mut rc_rate = rc_rate / 100.;
+                if rc_rate > 2. {
+                    rc_rate += 14.54 * (rc_rate - 2.);
+                }
+
+                let super_factor = 1. / (1. - abs * rate / 100.).clamp(0.01, 1.);
+                200. * rc_rate * x * super_factor
+            }
+            RatesType::Raceflight => {
+                let x = (1. + expo * (x * x - 1.)) * x;
+                10. * rc_rate * x * (1. + abs * rate / 100.)
+            }
+            RatesType::Kiss => {
+                let super_factor = 1. / (1. - abs * rate / 100.).clamp(0.01, 1.);
+                let x = (x * x * x * expo + x * (1. - expo)) * rc_rate / 1000.;
+                2000. * super_factor * x
+            }
+            RatesType::Actual => {
+                let center = rc_rate * 10.;
+                let stick_movement = (rate * 10. - center).max(0.);
+                let expo = abs * (x * x * x * x * x * expo + x * (1. - expo));
+                x * center + stick_movement * expo
+            }
+            RatesType::Quick if rc_rate == 0. => 0.,
+            RatesType::Quick => {
+                let rc_rate = rc_rate * 2.;
+                let max = (rate * 10.).max(rc_rate);
+                let super_factor_config = (max / rc_rate - 1.) / (max / rc_rate);
+
+                let curve = abs * abs * abs * expo + abs * (1. - expo);
+                let super_factor = 1. / (1. - curve * super_factor_config).clamp(0.01, 1.);
+                x * rc_rate * super_factor
+            }
+        };
+
+        degrees.clamp(-limit, limit)
+    }
+}
+
+/// Returns `true` for the DShot and ProShot protocols.
+const fn is_digital(protocol: PwmProtocol) -> bool {
+    match protocol {
+        PwmProtocol::Dshot150
+        | PwmProtocol::Dshot300
+        | PwmProtocol::Dshot600
+        | PwmProtocol::Dshot1200
+        | PwmProtocol::Proshot1000 => true,
+        PwmProtocol::Brushed
+        | PwmProtocol::Disabled
+        | PwmProtocol::Multishot
+        | PwmProtocol::Oneshot125
+        | PwmProtocol::Oneshot42
+        | PwmProtocol::Standard => false,
+    }
+}
+
 include_generated!("failsafe_phase");
 include_generated!("flight_mode");
 include_generated!("state");
@@ -32,28 +236,76 @@ pub(crate) fn time(raw: u64) -> Time {
         Time::new::<prelude::microsecond>(raw as f64)
     }
 
-    pub(crate) fn acceleration(raw: i32, headers: &Headers) -> Acceleration {
-        let gs = f64::from(raw) / f64::from(headers.acceleration_1g.unwrap());
+    pub(crate) fn acceleration(raw: i32, context: &UnitContext) -> Acceleration {
+        let gs = f64::from(raw) / f64::from(context.acceleration_1g.unwrap());
         Acceleration::new::<prelude::standard_gravity>(gs)
     }
 
-    pub(crate) fn angular_velocity(raw: i32, headers: &Headers) -> AngularVelocity {
-        let scale = headers.gyro_scale.unwrap();
+    pub(crate) fn angular_velocity(raw: i32, context: &UnitContext) -> AngularVelocity {
+        let scale = context.gyro_scale.unwrap();
         let rad = f64::from(scale) * f64::from(raw);
 
         AngularVelocity::new::<si::angular_velocity::radian_per_second>(rad)
     }
 
     pub(crate) fn current(raw: i32) -> ElectricCurrent {
-        // Correct from BF 3.1.7 (3.1.0?), INAV 2.0.0
+        // Correct from BF 3.1.7 (3.1.0?), INAV 2.0.0, so for all supported versions
         ElectricCurrent::new::<si::electric_current::centiampere>(raw.into())
     }
 
-    pub(crate) fn vbat(raw: u32) -> ElectricPotential {
+    pub(crate) fn vbat(raw: u32, context: &UnitContext) -> ElectricPotential {
+        if context.firmware.logs_vbat_adc() {
+            // Raw 12 bit ADC reading with a 3.3V reference. `vbatscale` is premultiplied
+            // by 100 and defaults to 110
+            let scale = context.vbat_scale.unwrap_or(110);
+            let millivolts = f64::from(raw) * 330. * f64::from(scale) / f64::from(0xFFF);
+            return ElectricPotential::new::<si::electric_potential::millivolt>(millivolts);
+        }
+
+        if context.firmware.logs_vbat_decivolts() {
+            // From BF 3.1 until 4.0
+            return ElectricPotential::new::<si::electric_potential::decivolt>(raw.into());
+        }
+
         // Correct from BF 4.0.0, INAV 3.0.0?
         ElectricPotential::new::<si::electric_potential::centivolt>(raw.into())
     }
 
+    pub(crate) fn frequency(raw: u32) -> Frequency {
+        // Rotorflight logs head and tail speeds in rpm
+        Frequency::new::<prelude::cycle_per_minute>(raw.into())
+    }
+
+    pub(crate) fn motor(raw: f64, context: &UnitContext) -> Ratio {
+        let fraction = context.motor_output.unwrap().fraction(raw);
+        Ratio::new::<prelude::ratio>(fraction)
+    }
+
+    /// `rcCommand` is `-500` to `500` for roll, pitch and yaw, and `1000` to
+    /// `2000` for throttle.
+    pub(crate) fn stick_deflection(raw: f64, axis: usize) -> f64 {
+        if axis == 3 {
+            ((raw - 1000.) / 1000.).clamp(0., 1.)
+        } else {
+            (raw / 500.).clamp(-1., 1.)
+        }
+    }
+
+    pub(crate) fn stick(raw: i32, axis: usize, context: &UnitContext) -> Stick {
+        let deflection = stick_deflection(raw.into(), axis);
+        let rate = context
+            .rate_curve
+            .filter(|_| axis < 3)
+            .map(|curve| curve.degrees_per_second(axis, deflection))
+            .map(AngularVelocity::new::<prelude::degree_per_second>);
+
+        Stick { deflection, rate }
+    }
+
+    pub(crate) fn setpoint(raw: i32) -> AngularVelocity {
+        AngularVelocity::new::<prelude::degree_per_second>(raw.into())
+    }
+
     pub(crate) fn velocity(raw: u32) -> Velocity {
         Velocity::new::<si::velocity::centimeter_per_second>(raw.into())
     }
@@ -96,9 +348,107 @@ fn electric_current() {
         float_eq!(1.39, new::current(139).get::<prelude::ampere>());
     }
 
+    fn unit_context(firmware: InternalFirmware, vbat_scale: Option<u8>) -> UnitContext {
+        UnitContext {
+            firmware,
+            acceleration_1g: None,
+            gyro_scale: None,
+            vbat_scale,
+            motor_output: None,
+            rate_curve: None,
+        }
+    }
+
     #[test]
     fn electric_potential() {
-        float_eq!(16.32, new::vbat(1632).get::<prelude::volt>());
+        let context = unit_context(InternalFirmware::Betaflight4_2, Some(110));
+        float_eq!(16.32, new::vbat(1632, &context).get::<prelude::volt>());
+    }
+
+    #[test]
+    fn electric_potential_adc() {
+        let context = unit_context(InternalFirmware::Cleanflight1, Some(110));
+        float_eq!(16.3195, new::vbat(1841, &context).get::<prelude::volt>());
+
+        let context = unit_context(InternalFirmware::Inav8, Some(110));
+        float_eq!(18.41, new::vbat(1841, &context).get::<prelude::volt>());
+    }
+
+    #[test]
+    fn electric_potential_decivolts() {
+        let context = unit_context(InternalFirmware::Betaflight3_5, Some(110));
+        float_eq!(16.3, new::vbat(163, &context).get::<prelude::volt>());
+    }
+
+    #[test]
+    fn motor() {
+        let mut context = unit_context(InternalFirmware::Betaflight4_5, None);
+        context.motor_output = Some(MotorOutput {
+            zero: 48.,
+            full: 2047.,
+        });
+
+        let percent = |raw| new::motor(raw, &context).get::<prelude::percent>();
+        float_eq!(0., percent(48.));
+        float_eq!(50., percent(1047.5));
+        float_eq!(100., percent(2047.));
+        // Motor stop is below zero throttle
+        float_eq!(0., percent(0.));
+    }
+
+    fn rates(kind: RatesType, rc_rate: u16, expo: u16, rate: u16) -> RateCurve {
+        RateCurve::Betaflight {
+            kind,
+            rc_rate: [rc_rate; 3],
+            expo: [expo; 3],
+            rate: [rate; 3],
+            limit: [RateCurve::BETAFLIGHT_LIMIT; 3],
+        }
+    }
+
+    #[test]
+    fn betaflight_rates() {
+        let curve = rates(RatesType::Betaflight, 100, 0, 70);
+        float_eq!(0., curve.degrees_per_second(0, 0.));
+        float_eq!(153.8462, curve.degrees_per_second(0, 0.5));
+        float_eq!(-666.6667, curve.degrees_per_second(1, -1.));
+    }
+
+    #[test]
+    fn actual_rates() {
+        let curve = rates(RatesType::Actual, 7, 0, 67);
+        float_eq!(185., curve.degrees_per_second(0, 0.5));
+        float_eq!(670., curve.degrees_per_second(2, 1.));
+    }
+
+    #[test]
+    fn quick_rates() {
+        let curve = rates(RatesType::Quick, 67, 0, 67);
+        float_eq!(670., curve.degrees_per_second(0, 1.));
+        float_eq!(-670., curve.degrees_per_second(0, -1.));
+    }
+
+    #[test]
+    fn rate_limit() {
+        let curve = rates(RatesType::Betaflight, 255, 0, 100);
+        float_eq!(1998., curve.degrees_per_second(0, 1.));
+    }
+
+    #[test]
+    fn stick() {
+        let mut context = unit_context(InternalFirmware::Inav9, None);
+        context.rate_curve = Some(RateCurve::Inav { rate: [20, 15, 9] });
+
+        let stick = new::stick(-250, 1, &context);
+        float_eq!(-0.5, stick.deflection);
+        float_eq!(
+            -75.,
+            stick.rate.unwrap().get::<prelude::degree_per_second>()
+        );
+
+        let throttle = new::stick(1500, 3, &context);
+        float_eq!(0.5, throttle.deflection);
+        assert_eq!(None, throttle.rate);
     }
 
     mod resolution {
@@ -2,6 +2,12 @@ pub(crate) fn to_base_field(field: &str) -> &str {
     field.split_once('[').map_or(field, |(base, _)| base)
 }
 
+/// Returns the index of an array field, eg `1` for `rcCommand[1]`.
+pub(crate) fn field_index(field: &str) -> Option<usize> {
+    let (_, index) = field.split_once('[')?;
+    index.strip_suffix(']')?.parse().ok()
+}
+
 macro_rules! include_generated {
     ($file:literal) => {
         include!(concat!(
@@ -0,0 +1,953 @@
+//! Writing blackbox logs.
+//!
+//! A log is written in two parts. First, the header section is generated from
+//! a [`HeaderSpec`]. Once those headers have been parsed back, a
+//! [`DataWriter`] encodes frames and events using the field definitions they
+//! contain, so anything written will decode to the exact same values:
+//!
+//! ```
+//! use blackbox_log::prelude::*;
+//! use blackbox_log::writer::{
+//!     DataWriter, Encoding, FieldSpec, HeaderSpec, MainFieldSpec, Predictor,
+//! };
+//! use blackbox_log::Event;
+//!
+//! let mut spec = HeaderSpec::new("Betaflight 4.5.0 (77d01ba3b) STM32F7X2");
+//! spec.main.push(MainFieldSpec {
+//!     name: "rcCommand[3]",
+//!     signed: false,
+//!     predictor_intra: Predictor::Zero,
+//!     predictor_inter: Predictor::Previous,
+//!     encoding_intra: Encoding::Variable,
+//!     encoding_inter: Encoding::VariableSigned,
+//! });
+//! spec.slow.push(FieldSpec {
+//!     name: "flightModeFlags",
+//!     signed: false,
+//!     predictor: Predictor::Zero,
+//!     encoding: Encoding::Variable,
+//! });
+//! spec.other.push(("motor_pwm_protocol", "0"));
+//!
+//! let mut log = Vec::new();
+//! spec.write(&mut log);
+//!
+//! let data = {
+//!     let file = blackbox_log::File::new(&log);
+//!     let headers = file.parse(0).unwrap()?;
+//!
+//!     // Values start with `loopIteration`, like `MainFrame::iter_raw`
+//!     let mut writer = DataWriter::new(&headers);
+//!     writer.write_intra(0, &[0, 1000])?;
+//!     writer.write_slow(&[0])?;
+//!     writer.write_inter(125, &[1, 1100])?;
+//!     writer.write_event(&Event::End {
+//!         disarm_reason: None,
+//!     })?;
+//!     writer.into_bytes()
+//! };
+//! log.extend_from_slice(&data);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use alloc::borrow::ToOwned as _;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt;
+
+use crate::data::MainFrameHistory;
+use crate::frame::gps::RawGpsFrame;
+use crate::frame::main::RawMainFrame;
+use crate::frame::slow::RawSlowFrame;
+use crate::frame::{DataFrameKind, FrameDef as _, FrameKind, GpsHomeFrame, GpsPosition};
+pub use crate::parser::Encoding;
+pub use crate::predictor::Predictor;
+use crate::{Event, Headers, ParserEvent};
+
+/// An error encountered while encoding a frame.
+///
+/// Nothing is written if encoding fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EncodeError {
+    /// The headers do not include a definition for frame type `frame`.
+    MissingFrameDef(DataFrameKind),
+    /// Expected `expected` values for a frame of type `frame`, but found
+    /// `found`.
+    FieldCount {
+        frame: DataFrameKind,
+        expected: usize,
+        found: usize,
+    },
+    /// The value of `field` cannot be represented with its predictor and
+    /// encoding, or does not match what will be predicted from earlier frames.
+    InvalidValue { frame: FrameKind, field: String },
+}
+
+impl EncodeError {
+    pub(crate) fn invalid(frame: impl Into<FrameKind>, field: &str) -> Self {
+        Self::InvalidValue {
+            frame: frame.into(),
+            field: field.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingFrameDef(frame) => write!(f, "missing {frame} frame definition"),
+            Self::FieldCount {
+                frame,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected {expected} values for {frame} frame, found {found}"
+            ),
+            Self::InvalidValue { frame, field } => {
+                write!(f, "cannot encode value of field `{field}` in {frame} frame")
+            }
+        }
+    }
+}
+
+impl core::error::Error for EncodeError {}
+
+/// The definition of one field in a slow or GPS frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldSpec<'a> {
+    pub name: &'a str,
+    pub signed: bool,
+    pub predictor: Predictor,
+    pub encoding: Encoding,
+}
+
+/// The definition of one field in the main frames, which use separate
+/// predictors and encodings for intra (`I`) and inter (`P`) frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MainFieldSpec<'a> {
+    pub name: &'a str,
+    pub signed: bool,
+    pub predictor_intra: Predictor,
+    pub predictor_inter: Predictor,
+    pub encoding_intra: Encoding,
+    pub encoding_inter: Encoding,
+}
+
+/// A description of the header section of a log.
+#[derive(Debug, Clone)]
+pub struct HeaderSpec<'a> {
+    /// The `Firmware revision` header, which must be from a supported
+    /// firmware.
+    pub firmware_revision: &'a str,
+
+    /// Main frame fields, not including `loopIteration` and `time`, which are
+    /// always written first.
+    pub main: Vec<MainFieldSpec<'a>>,
+
+    /// Slow frame fields. This must not be empty.
+    pub slow: Vec<FieldSpec<'a>>,
+
+    /// GPS frame fields, not including `time`, which is always written first.
+    ///
+    /// If this is `Some`, a GPS home frame definition with `GPS_home[0]` and
+    /// `GPS_home[1]` is also written.
+    pub gps: Option<Vec<FieldSpec<'a>>>,
+
+    /// Any other headers, written in order after the frame definitions.
+    ///
+    /// `motor_pwm_protocol` is required, along with any headers used by the
+    /// chosen predictors, eg `minthrottle` for [`Predictor::MinThrottle`].
+    pub other: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> HeaderSpec<'a> {
+    pub fn new(firmware_revision: &'a str) -> Self {
+        Self {
+            firmware_revision,
+            main: Vec::new(),
+            slow: Vec::new(),
+            gps: None,
+            other: Vec::new(),
+        }
+    }
+
+    /// Writes the complete header section, ready to be followed by the output
+    /// of a [`DataWriter`].
+    pub fn write(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(crate::MARKER);
+        write_header(out, "Data version", "2");
+
+        let main = [
+            MainFieldSpec {
+                name: "loopIteration",
+                signed: false,
+                predictor_intra: Predictor::Zero,
+                predictor_inter: Predictor::Increment,
+                encoding_intra: Encoding::Variable,
+                encoding_inter: Encoding::Null,
+            },
+            MainFieldSpec {
+                name: "time",
+                signed: false,
+                predictor_intra: Predictor::Zero,
+                predictor_inter: Predictor::StraightLine,
+                encoding_intra: Encoding::Variable,
+                encoding_inter: Encoding::VariableSigned,
+            },
+        ];
+        let main = main.iter().chain(&self.main);
+
+        write_list(out, "Field I name", main.clone().map(|f| f.name));
+        write_list(
+            out,
+            "Field I signed",
+            main.clone().map(|f| u8::from(f.signed)),
+        );
+        write_list(
+            out,
+            "Field I predictor",
+            main.clone().map(|f| f.predictor_intra.to_num()),
+        );
+        write_list(
+            out,
+            "Field I encoding",
+            main.clone().map(|f| u8::from(f.encoding_intra)),
+        );
+        write_list(
+            out,
+            "Field P predictor",
+            main.clone().map(|f| f.predictor_inter.to_num()),
+        );
+        write_list(
+            out,
+            "Field P encoding",
+            main.map(|f| u8::from(f.encoding_inter)),
+        );
+
+        write_frame_def(out, DataFrameKind::Slow, &self.slow);
+
+        if let Some(gps) = &self.gps {
+            let time = FieldSpec {
+                name: "time",
+                signed: false,
+                predictor: Predictor::LastMainFrameTime,
+                encoding: Encoding::Variable,
+            };
+            write_frame_def(out, DataFrameKind::Gps, &[vec![time], gps.clone()].concat());
+
+            let home = ["GPS_home[0]", "GPS_home[1]"].map(|name| FieldSpec {
+                name,
+                signed: true,
+                predictor: Predictor::Zero,
+                encoding: Encoding::VariableSigned,
+            });
+            write_frame_def(out, DataFrameKind::GpsHome, &home);
+        }
+
+        write_header(out, "Firmware revision", self.firmware_revision);
+
+        for (name, value) in &self.other {
+            write_header(out, name, value);
+        }
+    }
+}
+
+fn write_header(out: &mut Vec<u8>, name: &str, value: &str) {
+    out.extend_from_slice(format!("H {name}:{value}\n").as_bytes());
+}
+
+fn write_list<T: ToString>(out: &mut Vec<u8>, name: &str, values: impl Iterator<Item = T>) {
+    let values = values.map(|x| x.to_string()).collect::<Vec<_>>();
+    write_header(out, name, &values.join(","));
+}
+
+fn write_frame_def(out: &mut Vec<u8>, kind: DataFrameKind, fields: &[FieldSpec]) {
+    let kind = char::from(kind);
+
+    let header = |property| format!("Field {kind} {property}");
+    write_list(out, &header("name"), fields.iter().map(|f| f.name));
+    write_list(
+        out,
+        &header("signed"),
+        fields.iter().map(|f| u8::from(f.signed)),
+    );
+    write_list(
+        out,
+        &header("predictor"),
+        fields.iter().map(|f| f.predictor.to_num()),
+    );
+    write_list(
+        out,
+        &header("encoding"),
+        fields.iter().map(|f| u8::from(f.encoding)),
+    );
+}
+
+/// Encodes the data section of a log.
+///
+/// All values are raw, as returned by [`Frame::get_raw`][crate::frame::Frame]
+/// from an unfiltered parser.
+#[derive(Debug)]
+pub struct DataWriter<'data, 'headers> {
+    headers: &'headers Headers<'data>,
+    out: Vec<u8>,
+    main_frames: MainFrameHistory,
+    gps_home: Option<GpsHomeFrame>,
+}
+
+impl<'data, 'headers> DataWriter<'data, 'headers> {
+    pub fn new(headers: &'headers Headers<'data>) -> Self {
+        Self {
+            headers,
+            out: Vec::new(),
+            main_frames: MainFrameHistory::default(),
+            gps_home: None,
+        }
+    }
+
+    /// Writes an intra (`I`) frame. `values` starts with `loopIteration`.
+    pub fn write_intra(&mut self, time: u64, values: &[u32]) -> Result<(), EncodeError> {
+        self.write_main(true, time, values)
+    }
+
+    /// Writes an inter (`P`) frame. `values` starts with `loopIteration`, which
+    /// must be the next iteration logged after the previous main frame.
+    pub fn write_inter(&mut self, time: u64, values: &[u32]) -> Result<(), EncodeError> {
+        self.write_main(false, time, values)
+    }
+
+    fn write_main(&mut self, intra: bool, time: u64, values: &[u32]) -> Result<(), EncodeError> {
+        let kind = if intra {
+            DataFrameKind::Intra
+        } else {
+            DataFrameKind::Inter
+        };
+        check_field_count(kind, self.headers.main_frame_def().len(), values)?;
+
+        self.write_raw_main(RawMainFrame {
+            intra,
+            iteration: values[0],
+            time,
+            values: values[1..].to_vec(),
+        })
+    }
+
+    fn write_raw_main(&mut self, frame: RawMainFrame) -> Result<(), EncodeError> {
+        let kind = if frame.intra {
+            DataFrameKind::Intra
+        } else {
+            DataFrameKind::Inter
+        };
+
+        encode_frame(&mut self.out, kind, |out| {
+            frame.encode(out, self.headers, &self.main_frames)
+        })?;
+
+        self.main_frames.push(frame);
+        Ok(())
+    }
+
+    /// Writes a slow (`S`) frame.
+    pub fn write_slow(&mut self, values: &[u32]) -> Result<(), EncodeError> {
+        let def = self.headers.slow_frame_def();
+        check_field_count(DataFrameKind::Slow, def.len(), values)?;
+
+        let frame = RawSlowFrame(values.to_vec());
+        let last_main_time = self.main_frames.last_time();
+        encode_frame(&mut self.out, DataFrameKind::Slow, |out| {
+            def.encode(out, self.headers, &frame, last_main_time)
+        })
+    }
+
+    /// Writes a GPS (`G`) frame.
+    pub fn write_gps(&mut self, time: u64, values: &[u32]) -> Result<(), EncodeError> {
+        let frame = RawGpsFrame {
+            time,
+            values: values.to_vec(),
+        };
+
+        self.write_raw_gps(&frame)
+    }
+
+    fn write_raw_gps(&mut self, frame: &RawGpsFrame) -> Result<(), EncodeError> {
+        let kind = DataFrameKind::Gps;
+        let def = self
+            .headers
+            .gps_frame_def()
+            .ok_or(EncodeError::MissingFrameDef(kind))?;
+        check_field_count(kind, def.len(), &frame.values)?;
+
+        let last_main_time = self.main_frames.last_time();
+        encode_frame(&mut self.out, kind, |out| {
+            def.encode(
+                out,
+                self.headers,
+                frame,
+                last_main_time,
+                self.gps_home.as_ref(),
+            )
+        })
+    }
+
+    /// Writes a GPS home (`H`) frame, which is used to predict the coordinates
+    /// in any following GPS frames.
+    pub fn write_gps_home(&mut self, latitude: i32, longitude: i32) -> Result<(), EncodeError> {
+        let kind = DataFrameKind::GpsHome;
+        let def = self
+            .headers
+            .gps_home_frame_def()
+            .ok_or(EncodeError::MissingFrameDef(kind))?;
+
+        let frame = GpsHomeFrame(GpsPosition {
+            latitude,
+            longitude,
+        });
+        let last_main_time = self.main_frames.last_time();
+        encode_frame(&mut self.out, kind, |out| {
+            def.encode(out, self.headers, &frame, last_main_time)
+        })?;
+
+        self.gps_home = Some(frame);
+        Ok(())
+    }
+
+    /// Writes an event (`E`) frame.
+    ///
+    /// **Note:** Anything written after [`Event::End`] will be ignored by the
+    /// parser.
+    pub fn write_event(&mut self, event: &Event) -> Result<(), EncodeError> {
+        let last_main_time = self.main_frames.last_time();
+
+        encode_frame(&mut self.out, FrameKind::Event, |out| {
+            event.encode(out, last_main_time)
+        })
+    }
+
+    /// Writes a frame or event returned by a parser.
+    ///
+    /// Since GPS home frames are not returned by the parser, they must be
+    /// written separately using [`DataWriter::write_gps_home`].
+    pub fn write(&mut self, event: &ParserEvent) -> Result<(), EncodeError> {
+        match event {
+            ParserEvent::Event(event) => self.write_event(event),
+            ParserEvent::Main(main) => self.write_raw_main(main.raw().clone()),
+            ParserEvent::Slow(slow) => {
+                let frame = slow.raw();
+                let last_main_time = self.main_frames.last_time();
+                encode_frame(&mut self.out, DataFrameKind::Slow, |out| {
+                    self.headers
+                        .slow_frame_def()
+                        .encode(out, self.headers, frame, last_main_time)
+                })
+            }
+            ParserEvent::Gps(gps) => self.write_raw_gps(gps.raw()),
+        }
+    }
+
+    /// Returns the data written so far.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.out
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.out
+    }
+}
+
+/// Writes the headers from `spec`, then parses them back to write the data
+/// section using `write_data`.
+#[cfg(test)]
+pub(crate) fn write_log(
+    spec: &HeaderSpec,
+    write_data: impl FnOnce(&mut DataWriter<'_, '_>),
+) -> Vec<u8> {
+    let mut log = Vec::new();
+    spec.write(&mut log);
+
+    let data = {
+        let headers = Headers::parse(&log).unwrap();
+        let mut writer = DataWriter::new(&headers);
+        write_data(&mut writer);
+        writer.into_bytes()
+    };
+
+    log.extend_from_slice(&data);
+    log
+}
+
+fn check_field_count(
+    frame: DataFrameKind,
+    expected: usize,
+    values: &[u32],
+) -> Result<(), EncodeError> {
+    if values.len() == expected {
+        Ok(())
+    } else {
+        Err(EncodeError::FieldCount {
+            frame,
+            expected,
+            found: values.len(),
+        })
+    }
+}
+
+/// Writes the frame kind byte followed by the output of `encode`, leaving `out`
+/// unchanged if encoding fails.
+fn encode_frame(
+    out: &mut Vec<u8>,
+    kind: impl Into<FrameKind>,
+    encode: impl FnOnce(&mut Vec<u8>) -> Result<(), EncodeError>,
+) -> Result<(), EncodeError> {
+    let start = out.len();
+    out.push(kind.into().into());
+
+    let result = encode(out);
+    if result.is_err() {
+        out.truncate(start);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::event::AdjustedValue;
+    use crate::frame::Frame as _;
+    use crate::File;
+
+    /// A minimal deterministic generator, so tests do not depend on `rand`
+    struct Lcg(u32);
+
+    impl Lcg {
+        fn next(&mut self, range: core::ops::Range<i32>) -> u32 {
+            self.0 = self.0.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let width = range.end.abs_diff(range.start);
+            range
+                .start
+                .wrapping_add_unsigned(self.0 % width)
+                .cast_unsigned()
+        }
+    }
+
+    fn field(name: &str, signed: bool, predictor: Predictor, encoding: Encoding) -> FieldSpec<'_> {
+        FieldSpec {
+            name,
+            signed,
+            predictor,
+            encoding,
+        }
+    }
+
+    fn main_field(
+        name: &str,
+        signed: bool,
+        intra: (Predictor, Encoding),
+        inter: (Predictor, Encoding),
+    ) -> MainFieldSpec<'_> {
+        MainFieldSpec {
+            name,
+            signed,
+            predictor_intra: intra.0,
+            predictor_inter: inter.0,
+            encoding_intra: intra.1,
+            encoding_inter: inter.1,
+        }
+    }
+
+    /// Uses every predictor and encoding
+    fn spec() -> HeaderSpec<'static> {
+        use Encoding as E;
+        use Predictor as P;
+
+        let mut spec = HeaderSpec::new("Betaflight 4.2.0 (8f2d21460) STM32F7X2");
+
+        spec.main = vec![
+            main_field(
+                "axisP[0]",
+                true,
+                (P::Zero, E::VariableSigned),
+                (P::Previous, E::Tagged32),
+            ),
+            main_field(
+                "axisP[1]",
+                true,
+                (P::Zero, E::VariableSigned),
+                (P::Previous, E::Tagged32),
+            ),
+            main_field(
+                "axisP[2]",
+                true,
+                (P::Zero, E::VariableSigned),
+                (P::Previous, E::Tagged32),
+            ),
+            main_field(
+                "axisI[0]",
+                true,
+                (P::Zero, E::Tagged16),
+                (P::Average2, E::Tagged16),
+            ),
+            main_field(
+                "axisI[1]",
+                true,
+                (P::Zero, E::Tagged16),
+                (P::Average2, E::Tagged16),
+            ),
+            main_field(
+                "axisI[2]",
+                true,
+                (P::Zero, E::Tagged16),
+                (P::Average2, E::Tagged16),
+            ),
+            main_field(
+                "vbatLatest",
+                false,
+                (P::VBatReference, E::Negative14Bit),
+                (P::Previous, E::TaggedVariable),
+            ),
+            main_field(
+                "debug[0]",
+                true,
+                (P::Zero, E::TaggedVariable),
+                (P::StraightLine, E::TaggedVariable),
+            ),
+            main_field(
+                "debug[1]",
+                false,
+                (P::FifteenHundred, E::VariableSigned),
+                (P::Previous, E::Null),
+            ),
+            main_field(
+                "debug[2]",
+                false,
+                (P::Zero, E::Variable),
+                (P::Increment, E::VariableSigned),
+            ),
+            main_field(
+                "motor[0]",
+                false,
+                (P::MinThrottle, E::Variable),
+                (P::StraightLine, E::VariableSigned),
+            ),
+            main_field(
+                "motor[1]",
+                false,
+                (P::Motor0, E::VariableSigned),
+                (P::MinMotor, E::VariableSigned),
+            ),
+        ];
+
+        spec.slow = vec![
+            field("flightModeFlags", false, P::Zero, E::Variable),
+            field("stateFlags", false, P::Zero, E::Tagged32),
+            field("failsafePhase", false, P::Zero, E::Tagged32),
+        ];
+
+        spec.gps = Some(vec![
+            field("GPS_numSat", false, P::Zero, E::Variable),
+            field("GPS_coord[0]", true, P::HomeLat, E::VariableSigned),
+            field("GPS_coord[1]", true, P::HomeLon, E::VariableSigned),
+            field("GPS_altitude", false, P::Zero, E::Variable),
+        ]);
+
+        spec.other = vec![
+            ("I interval", "4"),
+            ("P interval", "2"),
+            ("motor_pwm_protocol", "0"),
+            ("minthrottle", "1070"),
+            ("motorOutput", "158,2047"),
+            ("vbatref", "2466"),
+        ];
+
+        spec
+    }
+
+    type Summary = (char, Option<u64>, Vec<u32>);
+
+    fn summarize(event: &ParserEvent) -> Summary {
+        match event {
+            ParserEvent::Event(_) => ('E', None, vec![]),
+            ParserEvent::Main(main) => {
+                let kind = if main.raw().intra { 'I' } else { 'P' };
+                (kind, Some(main.time_raw()), main.iter_raw().collect())
+            }
+            ParserEvent::Slow(slow) => ('S', None, slow.iter_raw().collect()),
+            ParserEvent::Gps(gps) => ('G', Some(gps.time_raw()), gps.iter_raw().collect()),
+        }
+    }
+
+    /// Writes a log crossing a rollover of the 32 bit time counter
+    fn write_frames(writer: &mut DataWriter) -> Vec<Summary> {
+        let mut rng = Lcg(0x1234_5678);
+        let mut written = Vec::new();
+
+        writer.write_gps_home(-338_688_000, 1_512_093_000).unwrap();
+        writer.write_event(&Event::SyncBeep(0xFFFF_0000)).unwrap();
+        written.push(('E', None, vec![]));
+
+        let mut time = 0xFFFF_0000;
+        for i in 0..64 {
+            let iteration = 2 * i;
+            time += u64::from(rng.next(1000..1100));
+
+            let intra = iteration % 4 == 0;
+            let mut values = vec![iteration];
+            values.extend([(); 3].map(|()| rng.next(-1000..1000)));
+            values.extend([(); 3].map(|()| rng.next(-100..100)));
+            values.push(rng.next(2000..2500));
+            values.push(rng.next(-100_000..100_000));
+            values.push(1500);
+            values.push(i);
+            values.push(rng.next(1100..2000));
+            values.push(rng.next(158..2047));
+
+            if intra {
+                writer.write_intra(time, &values).unwrap();
+            } else {
+                writer.write_inter(time, &values).unwrap();
+            }
+            written.push((if intra { 'I' } else { 'P' }, Some(time), values));
+
+            if i % 8 == 0 {
+                let values = vec![rng.next(0..64), rng.next(0..4), rng.next(0..8)];
+                writer.write_slow(&values).unwrap();
+                written.push(('S', None, values));
+            }
+
+            if i % 5 == 0 {
+                let gps_time = time + u64::from(rng.next(0..100));
+                let values = vec![
+                    rng.next(4..20),
+                    rng.next(-338_700_000..-338_600_000),
+                    rng.next(1_512_000_000..1_512_100_000),
+                    rng.next(0..1000),
+                ];
+                writer.write_gps(gps_time, &values).unwrap();
+                written.push(('G', Some(gps_time), values));
+            }
+        }
+
+        let events = [
+            Event::InflightAdjustment {
+                function: 3,
+                new_value: AdjustedValue::Float(0.5),
+            },
+            Event::InflightAdjustment {
+                function: 127,
+                new_value: AdjustedValue::Int(-42),
+            },
+            Event::Resume {
+                log_iteration: 100,
+                time: 12_345,
+            },
+            Event::Disarm(4),
+            Event::FlightMode {
+                flags: 0b101,
+                last_flags: 1,
+            },
+            Event::ImuFailure { error: 2 },
+            Event::End {
+                disarm_reason: Some(1),
+            },
+        ];
+
+        for event in &events {
+            writer.write_event(event).unwrap();
+            written.push(('E', None, vec![]));
+        }
+
+        written
+    }
+
+    fn parse_all(log: &[u8]) -> (Vec<Summary>, Vec<Event>) {
+        let headers = File::new(log).parse(0).unwrap().unwrap();
+        let mut parser = headers.data_parser();
+
+        let mut frames = Vec::new();
+        let mut events = Vec::new();
+        while let Some(event) = parser.next() {
+            if let ParserEvent::Event(event) = &event {
+                events.push(event.clone());
+            }
+
+            frames.push(summarize(&event));
+        }
+
+        (frames, events)
+    }
+
+    #[test]
+    fn header_round_trip() {
+        let mut log = Vec::new();
+        spec().write(&mut log);
+
+        let headers = File::new(&log).parse(0).unwrap().unwrap();
+
+        assert_eq!(
+            headers
+                .main_frame_def()
+                .iter()
+                .map(|f| f.name)
+                .collect::<Vec<_>>(),
+            [
+                "loopIteration",
+                "axisP[0]",
+                "axisP[1]",
+                "axisP[2]",
+                "axisI[0]",
+                "axisI[1]",
+                "axisI[2]",
+                "vbatLatest",
+                "debug[0]",
+                "debug[1]",
+                "debug[2]",
+                "motor[0]",
+                "motor[1]",
+            ]
+        );
+        assert_eq!(3, headers.slow_frame_def().len());
+        assert_eq!(4, headers.gps_frame_def().unwrap().len());
+        assert!(headers.gps_home_frame_def().is_some());
+        assert_eq!(4, headers.frame_intervals().intra());
+        assert!(headers.unknown().is_empty());
+    }
+
+    #[test]
+    fn data_round_trip() {
+        let mut written = Vec::new();
+        let log = write_log(&spec(), |writer| written = write_frames(writer));
+
+        let rollover = written
+            .iter()
+            .any(|(_, time, _)| time.is_some_and(|time| time > u64::from(u32::MAX)));
+        assert!(rollover);
+
+        let (parsed, events) = parse_all(&log);
+        assert_eq!(written, parsed);
+        assert_eq!(Some(&Event::SyncBeep(0xFFFF_0000)), events.first());
+        assert_eq!(
+            Some(&Event::End {
+                disarm_reason: Some(1)
+            }),
+            events.last()
+        );
+
+        // Writing everything that was parsed reproduces the original bytes
+        let headers = File::new(&log).parse(0).unwrap().unwrap();
+        let data = &log[headers.data_offset()..];
+        let mut parser = headers.data_parser();
+        let mut writer = DataWriter::new(&headers);
+        writer.write_gps_home(-338_688_000, 1_512_093_000).unwrap();
+        while let Some(event) = parser.next() {
+            writer.write(&event).unwrap();
+        }
+
+        assert_eq!(data, writer.into_bytes());
+    }
+
+    #[test]
+    fn rewrite_log() {
+        let log = include_bytes!("../tests/logs/error-recovery.bbl");
+        let headers = File::new(log).parse(0).unwrap().unwrap();
+
+        let mut parser = headers.data_parser();
+        let mut writer = DataWriter::new(&headers);
+        let mut original = Vec::new();
+        while let Some(event) = parser.next() {
+            writer.write(&event).unwrap();
+            original.push(summarize(&event));
+        }
+        let data = writer.into_bytes();
+
+        let mut parser = headers.push_parser();
+        parser.feed(&data);
+        parser.finish();
+
+        let mut rewritten = Vec::new();
+        let mut writer = DataWriter::new(&headers);
+        while let Some(event) = parser.next() {
+            writer.write(&event).unwrap();
+            rewritten.push(summarize(&event));
+        }
+
+        assert!(!original.is_empty());
+        assert_eq!(original, rewritten);
+        assert_eq!(data, writer.into_bytes());
+    }
+
+    #[test]
+    fn invalid_values() {
+        let mut log = Vec::new();
+        spec().write(&mut log);
+        let headers = File::new(&log).parse(0).unwrap().unwrap();
+        let mut writer = DataWriter::new(&headers);
+
+        let mut values = vec![0, 0, 0, 0, 0, 0, 0, 2466, 0, 1500, 0, 1070, 1070];
+        writer.write_intra(0, &values).unwrap();
+        let len = writer.as_bytes().len();
+
+        assert_eq!(
+            Err(EncodeError::FieldCount {
+                frame: DataFrameKind::Inter,
+                expected: 13,
+                found: 2,
+            }),
+            writer.write_inter(0, &[2, 0])
+        );
+
+        values[0] = 3;
+        assert_eq!(
+            Err(EncodeError::invalid(DataFrameKind::Inter, "loopIteration")),
+            writer.write_inter(100, &values)
+        );
+
+        values[0] = 2;
+        values[9] = 1501;
+        assert_eq!(
+            Err(EncodeError::invalid(DataFrameKind::Inter, "debug[1]")),
+            writer.write_inter(100, &values)
+        );
+
+        values[9] = 1500;
+        values[4] = 0x8000;
+        assert_eq!(
+            Err(EncodeError::invalid(DataFrameKind::Inter, "axisI[0]")),
+            writer.write_inter(100, &values)
+        );
+
+        assert_eq!(
+            Err(EncodeError::invalid(FrameKind::Event, "disarm_reason")),
+            writer.write_event(&Event::End {
+                disarm_reason: Some(256)
+            })
+        );
+
+        assert_eq!(len, writer.as_bytes().len());
+    }
+
+    #[test]
+    fn missing_gps() {
+        let mut spec = spec();
+        spec.gps = None;
+
+        let mut log = Vec::new();
+        spec.write(&mut log);
+        let headers = File::new(&log).parse(0).unwrap().unwrap();
+        let mut writer = DataWriter::new(&headers);
+
+        assert_eq!(
+            Err(EncodeError::MissingFrameDef(DataFrameKind::Gps)),
+            writer.write_gps(0, &[])
+        );
+        assert_eq!(
+            Err(EncodeError::MissingFrameDef(DataFrameKind::GpsHome)),
+            writer.write_gps_home(0, 0)
+        );
+        assert!(writer.as_bytes().is_empty());
+    }
+}
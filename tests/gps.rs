@@ -0,0 +1,74 @@
+use std::fs;
+
+use blackbox_log::prelude::*;
+
+/// Checks that every GPS frame is timed between the main frames around it,
+/// since its time is predicted from the last main frame. Returns the number of
+/// GPS frames checked.
+fn check_gps_times(path: &std::path::Path) -> usize {
+    let data = fs::read(path).unwrap();
+    let file = blackbox_log::File::new(&data);
+    let mut checked = 0;
+
+    for headers in file.iter() {
+        let Ok(headers) = headers else {
+            continue;
+        };
+
+        let mut parser = headers.data_parser();
+        let mut last_main = None;
+        let mut pending = Vec::new();
+
+        while let Some(event) = parser.next() {
+            match event {
+                ParserEvent::Main(main) => {
+                    let time = main.time_raw();
+                    for gps in pending.drain(..) {
+                        assert!(
+                            gps <= time,
+                            "GPS frame at {gps} is after the next main frame at {time}"
+                        );
+                    }
+                    last_main = Some(time);
+                }
+                ParserEvent::Gps(gps) => {
+                    let time = gps.time_raw();
+                    if let Some(last) = last_main {
+                        assert!(
+                            last <= time,
+                            "GPS frame at {time} is before the last main frame at {last}"
+                        );
+                        pending.push(time);
+                        checked += 1;
+                    }
+                }
+                ParserEvent::Event(_) | ParserEvent::Slow(_) => {}
+            }
+        }
+    }
+
+    checked
+}
+
+#[test]
+fn own() {
+    let mut checked = 0;
+    insta::glob!("logs/*.bbl", |path| checked += check_gps_times(path));
+    assert_ne!(0, checked);
+}
+
+#[test]
+#[ignore]
+fn fc_blackbox() {
+    insta::glob!("logs/fc-blackbox/*", |path| {
+        check_gps_times(path);
+    });
+}
+
+#[test]
+#[ignore]
+fn gimbal_ghost() {
+    insta::glob!("logs/gimbal-ghost/*", |path| {
+        check_gps_times(path);
+    });
+}
@@ -0,0 +1,127 @@
+use blackbox_log::frame::{FieldDef, GpsUnit, MainUnit, MainValue, SlowUnit, SlowValue};
+use blackbox_log::headers::{Firmware, FirmwareVersion};
+use blackbox_log::prelude::*;
+use blackbox_log::units::si::electric_potential::volt;
+use blackbox_log::units::ElectricPotential;
+use blackbox_log::{Event, Headers, Value};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+static LOG: &[u8] = include_bytes!("logs/error-recovery.bbl");
+
+fn parse() -> Headers<'static> {
+    blackbox_log::File::new(LOG).parse(0).unwrap().unwrap()
+}
+
+#[track_caller]
+fn round_trip<T>(value: &T) -> T
+where
+    T: Serialize + DeserializeOwned,
+{
+    let json = serde_json::to_string(value).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn headers() {
+    let headers = parse();
+
+    let firmware = headers.firmware();
+    assert_eq!(firmware, round_trip(&firmware));
+    assert_eq!(
+        "{\"Betaflight\":\"4.2.0\"}",
+        serde_json::to_string(&Firmware::Betaflight(FirmwareVersion::new(4, 2, 0))).unwrap()
+    );
+    assert!(serde_json::from_str::<FirmwareVersion>("\"4.2\"").is_err());
+
+    assert_eq!(headers.debug_mode(), round_trip(&headers.debug_mode()));
+    assert_eq!(headers.pwm_protocol(), round_trip(&headers.pwm_protocol()));
+
+    let features = headers.features();
+    assert_eq!(features.as_names(), round_trip(&features).as_names());
+    let disabled = headers.disabled_fields();
+    assert_eq!(disabled.as_names(), round_trip(&disabled).as_names());
+}
+
+#[test]
+fn field_defs() {
+    let headers = parse();
+
+    let main = headers.main_frame_def().iter().collect::<Vec<_>>();
+    let json = serde_json::to_string(&main).unwrap();
+    assert_eq!(
+        main,
+        serde_json::from_str::<Vec<FieldDef<MainUnit>>>(&json).unwrap()
+    );
+
+    let slow = headers.slow_frame_def().iter().collect::<Vec<_>>();
+    let json = serde_json::to_string(&slow).unwrap();
+    assert_eq!(
+        slow,
+        serde_json::from_str::<Vec<FieldDef<SlowUnit>>>(&json).unwrap()
+    );
+
+    if let Some(gps) = headers.gps_frame_def() {
+        let gps = gps.iter().collect::<Vec<_>>();
+        let json = serde_json::to_string(&gps).unwrap();
+        assert_eq!(
+            gps,
+            serde_json::from_str::<Vec<FieldDef<GpsUnit>>>(&json).unwrap()
+        );
+    }
+}
+
+#[test]
+fn data() {
+    let headers = parse();
+    let mut parser = headers.data_parser();
+
+    let mut events = Vec::<Event>::new();
+    while let Some(event) = parser.next() {
+        match event {
+            ParserEvent::Event(event) => events.push(event),
+            ParserEvent::Main(frame) => {
+                for value in frame.iter() {
+                    assert_eq!(value, round_trip(&value));
+
+                    let value = Value::from(value);
+                    assert_eq!(value, round_trip(&value));
+                }
+            }
+            ParserEvent::Slow(frame) => {
+                for value in frame.iter() {
+                    match value {
+                        SlowValue::FlightMode(modes) => {
+                            assert_eq!(modes.as_names(), round_trip(&modes).as_names());
+                        }
+                        SlowValue::State(state) => {
+                            assert_eq!(state.as_names(), round_trip(&state).as_names());
+                        }
+                        SlowValue::FailsafePhase(_)
+                        | SlowValue::Boolean(_)
+                        | SlowValue::Unsigned(_)
+                        | SlowValue::Signed(_) => assert_eq!(value, round_trip(&value)),
+                    }
+                }
+            }
+            ParserEvent::Gps(frame) => {
+                for value in frame.iter() {
+                    assert_eq!(value, round_trip(&value));
+                }
+            }
+        }
+    }
+
+    assert!(!events.is_empty());
+    assert_eq!(events, round_trip(&events));
+
+    let stats = parser.stats();
+    let json = serde_json::to_string(stats).unwrap();
+    assert_eq!(json, serde_json::to_string(&round_trip(stats)).unwrap());
+
+    let voltage = MainValue::Voltage(ElectricPotential::new::<volt>(3.7));
+    assert_eq!(
+        "{\"Voltage\":3.7}",
+        serde_json::to_string(&voltage).unwrap()
+    );
+}
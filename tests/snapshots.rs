@@ -295,6 +295,11 @@ fn new(name: String, unit: Unit) -> Self {
                 | Unit::Voltage
                 | Unit::Acceleration
                 | Unit::Rotation
+                | Unit::Frequency
+                | Unit::Motor
+                | Unit::Stick
+                | Unit::Ratio
+                | Unit::Duration
                 | Unit::GpsCoordinate
                 | Unit::Altitude
                 | Unit::Velocity
@@ -320,6 +325,11 @@ fn update(&mut self, value: Value) {
                 Value::Rotation(r) => {
                     r.get::<si::angular_velocity::degree_per_second>().round() as i128
                 }
+                Value::Frequency(f) => f.get::<si::frequency::cycle_per_minute>().round() as i128,
+                Value::Motor(m) => (m.get::<si::ratio::percent>() * 10.).round() as i128,
+                Value::Stick(s) => (s.deflection * 1000.).round() as i128,
+                Value::Ratio(r) => (r.get::<si::ratio::percent>() * 10.).round() as i128,
+                Value::Duration(d) => d.get::<si::time::microsecond>().round() as i128,
                 Value::GpsCoordinate(c) => (c * 10000000.).round() as i128,
                 Value::Altitude(a) => a.get::<si::length::meter>().round() as i128,
                 Value::Velocity(v) => {